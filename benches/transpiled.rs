@@ -0,0 +1,116 @@
+// Times the checked in transpiler output against the interpreter, run with
+//   cargo bench --bench transpiled
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+mod day_09 {
+    include!("../tests/transpiled/day_09.rs");
+}
+mod day_11 {
+    include!("../tests/transpiled/day_11.rs");
+}
+
+const ROUNDS: usize = 10;
+
+// Both kinds of machine are driven through the same calls, only run differs
+trait Machine {
+    fn run(&mut self) -> Result<IntcodeState, IntcodeError>;
+    fn intcode(&mut self) -> &mut Intcode;
+}
+
+impl Machine for Intcode {
+    fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        Intcode::run(self)
+    }
+    fn intcode(&mut self) -> &mut Intcode {
+        self
+    }
+}
+
+impl Machine for day_11::Day11 {
+    fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        day_11::Day11::run(self)
+    }
+    fn intcode(&mut self) -> &mut Intcode {
+        self
+    }
+}
+
+// Runs until the machine halts, answering each wait for input from its outputs
+fn drive(machine: &mut impl Machine, mut respond: impl FnMut(&[i64]) -> i64) {
+    let mut outputs = Vec::new();
+    while IntcodeState::WaitingForInput == machine.run().unwrap() {
+        let intcode = machine.intcode();
+        outputs.extend(intcode.drain_outputs());
+        intcode.push_input(respond(&outputs));
+    }
+}
+
+// The day 11 painting robot, answering with the colour of the panel it stands on
+fn robot() -> impl FnMut(&[i64]) -> i64 {
+    let mut panels = HashMap::new();
+    let (mut x, mut y, mut dx, mut dy) = (0i64, 0i64, 0i64, 1i64);
+    let mut seen = 0;
+    move |outputs| {
+        for pair in outputs[seen..].chunks(2) {
+            panels.insert((x, y), pair[0]);
+            let (turned_x, turned_y) = if 0 == pair[1] { (-dy, dx) } else { (dy, -dx) };
+            dx = turned_x;
+            dy = turned_y;
+            x += dx;
+            y += dy;
+        }
+        seen = outputs.len();
+        *panels.get(&(x, y)).unwrap_or(&0)
+    }
+}
+
+// Best of several rounds, so one noisy round does not skew the comparison
+fn best(mut round: impl FnMut()) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            round();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(day: &str, interpreted: Duration, native: Duration) {
+    println!(
+        "{}: interpreted {:?}, native {:?} ({:.2}x)",
+        day,
+        interpreted,
+        native,
+        interpreted.as_secs_f64() / native.as_secs_f64()
+    );
+}
+
+fn main() {
+    let day_09 = helpers::read_file_delim_ints("inputs/day_09.txt", ",").unwrap();
+    let day_11 = helpers::read_file_delim_ints("inputs/day_11.txt", ",").unwrap();
+    // Day 09 runs flat out, the boost is one long run
+    let interpreted = best(|| {
+        let mut intcode = Intcode::new();
+        intcode.load_program(day_09.clone());
+        intcode.push_input(2);
+        intcode.run().unwrap();
+    });
+    let native = best(|| {
+        let mut native = day_09::Day09::new();
+        native.push_input(2);
+        native.run().unwrap();
+    });
+    report("day 09", interpreted, native);
+    // Day 11 stops for input thousands of times, each a fresh call to run
+    let interpreted = best(|| {
+        let mut intcode = Intcode::new();
+        intcode.load_program(day_11.clone());
+        drive(&mut intcode, robot());
+    });
+    let native = best(|| drive(&mut day_11::Day11::new(), robot()));
+    report("day 11", interpreted, native);
+}
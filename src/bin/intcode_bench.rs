@@ -0,0 +1,187 @@
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::intcode::Intcode;
+use std::process;
+use std::time::{Duration, Instant};
+
+// The interpreter as it was before the decode cache and everything after it,
+// kept only as the baseline: a flat Vec padded by 4000 cells, each
+// instruction decoded by powers of ten and cells fetched by walking iterators
+#[allow(clippy::iter_nth)]
+mod original {
+    pub struct Intcode {
+        memory: Vec<i64>,
+        ip: usize,
+        relative_base: i64,
+        pub steps: u64,
+    }
+
+    impl Intcode {
+        pub fn new(program: &[i64]) -> Intcode {
+            let mut memory = program.to_vec();
+            memory.extend(vec![0; 4000]);
+            Intcode {
+                memory,
+                ip: 0,
+                relative_base: 0,
+                steps: 0,
+            }
+        }
+        fn mode(value: i64, k: u32) -> i64 {
+            value / 10i64.pow(k) % 10
+        }
+        fn read(&self, addr: usize, mode: i64) -> i64 {
+            let value = *self.memory.iter().nth(addr).unwrap();
+            match mode {
+                0 => self.memory[value as usize],
+                1 => value,
+                _ => self.memory[(value + self.relative_base) as usize],
+            }
+        }
+        fn write(&mut self, addr: usize, value: i64, mode: i64) {
+            let target = self.memory[addr];
+            match mode {
+                2 => self.memory[(target + self.relative_base) as usize] = value,
+                _ => self.memory[target as usize] = value,
+            }
+        }
+        // Runs to the end, false if it stopped for input it was not given
+        pub fn run(&mut self, inputs: &mut impl Iterator<Item = i64>) -> bool {
+            loop {
+                let value = *self.memory.iter().nth(self.ip).unwrap();
+                let opcode = Intcode::mode(value, 1) * 10 + Intcode::mode(value, 0);
+                let (m1, m2, m3) = (
+                    Intcode::mode(value, 2),
+                    Intcode::mode(value, 3),
+                    Intcode::mode(value, 4),
+                );
+                let ip = self.ip;
+                self.steps += 1;
+                match opcode {
+                    1 | 2 | 7 | 8 => {
+                        let (a, b) = (self.read(ip + 1, m1), self.read(ip + 2, m2));
+                        let result = match opcode {
+                            1 => a.wrapping_add(b),
+                            2 => a.wrapping_mul(b),
+                            7 => (a < b) as i64,
+                            _ => (a == b) as i64,
+                        };
+                        self.write(ip + 3, result, m3);
+                        self.ip += 4;
+                    }
+                    3 => match inputs.next() {
+                        Some(input) => {
+                            self.write(ip + 1, input, m1);
+                            self.ip += 2;
+                        }
+                        None => return false,
+                    },
+                    4 => {
+                        self.read(ip + 1, m1);
+                        self.ip += 2;
+                    }
+                    5 | 6 => {
+                        let (a, b) = (self.read(ip + 1, m1), self.read(ip + 2, m2));
+                        if (5 == opcode) == (0 != a) {
+                            self.ip = b as usize;
+                        } else {
+                            self.ip += 3;
+                        }
+                    }
+                    9 => {
+                        self.relative_base += self.read(ip + 1, m1);
+                        self.ip += 2;
+                    }
+                    _ => return true,
+                }
+            }
+        }
+    }
+}
+
+const ROUNDS: usize = 5;
+const LOOPS: i64 = 1_000_000;
+
+// Sums a countdown from [51], five instructions per pass of the loop
+fn builtin_program() -> Vec<i64> {
+    let mut program = vec![
+        1001, 51, -1, 51, 1, 50, 51, 50, 1002, 50, 1, 50, 1007, 51, 1, 52, 1006, 52, 0, 4, 50, 99,
+    ];
+    program.resize(53, 0);
+    program[51] = LOOPS;
+    program
+}
+
+// Best of several runs, so one noisy round does not skew the comparison
+fn measure_original(program: &[i64], inputs: &[i64]) -> (u64, Duration) {
+    let mut best = Duration::MAX;
+    let mut steps = 0;
+    for _ in 0..ROUNDS {
+        let mut intcode = original::Intcode::new(program);
+        let start = Instant::now();
+        if !intcode.run(&mut inputs.iter().copied()) {
+            eprintln!("fault: ran out of input");
+            process::exit(1);
+        }
+        best = best.min(start.elapsed());
+        steps = intcode.steps;
+    }
+    (steps, best)
+}
+
+fn measure(program: &[i64], inputs: &[i64], cached: bool) -> (u64, Duration) {
+    let mut best = Duration::MAX;
+    let mut steps = 0;
+    for _ in 0..ROUNDS {
+        let mut intcode = Intcode::new();
+        intcode.set_decode_cache(cached);
+        intcode.load_program(program.to_vec());
+        intcode.push_inputs(inputs.iter().copied());
+        let start = Instant::now();
+        if let Err(e) = intcode.run() {
+            eprintln!("fault: {}", e);
+            process::exit(1);
+        }
+        best = best.min(start.elapsed());
+        steps = intcode.steps_executed();
+    }
+    (steps, best)
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (program, inputs) = match args.split_first() {
+        None => (builtin_program(), Vec::new()),
+        Some((path, inputs)) => {
+            let program = match helpers::read_file_delim_ints(path, ",") {
+                Ok(program) => program,
+                Err(e) => {
+                    eprintln!("Unable to read {}: {}", path, e);
+                    process::exit(1);
+                }
+            };
+            let inputs = inputs.iter().map(|i| i.parse::<i64>()).collect();
+            match inputs {
+                Ok(inputs) => (program, inputs),
+                Err(_) => {
+                    eprintln!("Usage: intcode_bench [program.txt [inputs...]]");
+                    process::exit(2);
+                }
+            }
+        }
+    };
+    let mut baseline = None;
+    let runs = [
+        ("original", measure_original(&program, &inputs)),
+        ("decoder", measure(&program, &inputs, false)),
+        ("cache", measure(&program, &inputs, true)),
+    ];
+    for (name, (steps, elapsed)) in runs {
+        let rate = steps as f64 / elapsed.as_secs_f64() / 1_000_000.0;
+        let speedup = baseline.map_or(1.0, |base| rate / base);
+        baseline.get_or_insert(rate);
+        println!(
+            "{:>8}: {} steps in {:?}, {:.1}M steps/s ({:.2}x)",
+            name, steps, elapsed, rate, speedup
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::debugger::Debugger;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+fn usage() -> ! {
+    eprintln!("Usage: intcode_debugger <program.txt> [--script <commands.txt>]");
+    process::exit(2);
+}
+
+fn main() -> io::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (path, script) = match args.as_slice() {
+        [path] => (path, None),
+        [path, flag, script] if "--script" == flag => (path, Some(script)),
+        _ => usage(),
+    };
+    let program = match helpers::read_file_delim_ints(path, ",") {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("Unable to read {}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    let mut debugger = Debugger::new(program);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    // Scripted sessions run to the end of the file and exit
+    if let Some(script) = script {
+        debugger.execute_script(&fs::read_to_string(script)?, &mut out)?;
+        return Ok(());
+    }
+    writeln!(out, "Intcode debugger, type help for commands")?;
+    let stdin = io::stdin();
+    loop {
+        write!(out, "(icdb) ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if 0 == stdin.lock().read_line(&mut line)? || !debugger.execute(&line, &mut out)? {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::cfg::ControlFlowGraph;
+use advent_of_code_2019::intcode::decompiler;
+use advent_of_code_2019::intcode::disassembler;
+use std::process;
+
+fn usage() -> ! {
+    eprintln!("Usage: intcode_disasm <program.txt> [--dot | --pseudo]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| usage());
+    // --dot prints the control flow graph for Graphviz, --pseudo decompiles
+    let format = args.next();
+    let program = match helpers::read_file_delim_ints(&path, ",") {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("Unable to read {}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    match format.as_deref() {
+        None => print!("{}", disassembler::listing(&program)),
+        Some("--dot") => print!("{}", ControlFlowGraph::explore(&program).to_dot()),
+        Some("--pseudo") => print!("{}", decompiler::decompile(&program)),
+        Some(_) => usage(),
+    }
+}
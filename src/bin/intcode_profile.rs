@@ -0,0 +1,49 @@
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::intcode::Intcode;
+use advent_of_code_2019::intcode::profile::Profiler;
+use std::process;
+
+fn usage() -> ! {
+    eprintln!("Usage: intcode_profile <program.txt> [--dump] [inputs...]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| usage());
+    let mut dump = false;
+    let mut inputs = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--dump" => dump = true,
+            _ => inputs.push(arg.parse::<i64>().unwrap_or_else(|_| usage())),
+        }
+    }
+    let program = match helpers::read_file_delim_ints(&path, ",") {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("Unable to read {}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    let mut intcode = Intcode::new();
+    intcode.load_program(program);
+    intcode.push_inputs(inputs);
+    intcode.set_profiler(Some(Profiler::new()));
+    // Day 9's BOOST program, for example, takes its mode as the only input
+    let result = intcode.run();
+    let profiler = intcode.take_profiler().unwrap();
+    if dump {
+        print!("{}", profiler.dump());
+    } else {
+        println!("outputs: {:?}", intcode.take_outputs());
+        print!("{}", profiler.report());
+    }
+    match result {
+        Ok(state) => eprintln!("stopped: {:?}", state),
+        Err(e) => {
+            eprintln!("fault: {}", e);
+            process::exit(1);
+        }
+    }
+}
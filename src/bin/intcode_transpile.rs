@@ -0,0 +1,26 @@
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::transpiler;
+use std::process;
+
+fn usage() -> ! {
+    eprintln!("Usage: intcode_transpile <program.txt> <TypeName>");
+    process::exit(2);
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| usage());
+    // The generated type is named by the caller, e.g. Day09
+    let name = args.next().unwrap_or_else(|| usage());
+    if args.next().is_some() {
+        usage();
+    }
+    let program = match helpers::read_file_delim_ints(&path, ",") {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("Unable to read {}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    print!("{}", transpiler::transpile(&program, &name));
+}
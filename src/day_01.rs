@@ -3,12 +3,9 @@ fn calc_module_fuel(mass: i64) -> i64 {
 }
 
 fn calc_all_fuel(mass: i64, total_mass: i64) -> i64 {
-    loop {
-        match calc_module_fuel(mass) {
-            f if f < 0 => return total_mass,
-            f if f >= 0 => return calc_all_fuel(f, total_mass + f),
-            _ => return 0,
-        }
+    match calc_module_fuel(mass) {
+        f if f < 0 => total_mass,
+        f => calc_all_fuel(f, total_mass + f),
     }
 }
 
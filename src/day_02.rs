@@ -1,27 +1,28 @@
 use crate::intcode::intcode::Intcode;
 
-pub fn part_1(program: Vec<isize>) -> String {
+pub fn part_1(program: Vec<i64>) -> String {
     let mut intcode = Intcode::new();
     intcode.load_program(program);
     // Fix Crash
-    intcode.poke(1, 12);
-    intcode.poke(2, 2);
-    intcode.run();
-    format!("Part 1: {}", intcode.peek(0)).to_string()
+    intcode.poke(1, 12).unwrap();
+    intcode.poke(2, 2).unwrap();
+    intcode.run().unwrap();
+    format!("Part 1: {}", intcode.peek(0).unwrap()).to_string()
 }
 
-pub fn part_2(program: Vec<isize>) -> String {
+pub fn part_2(program: Vec<i64>) -> String {
     let mut x: i16 = 0;
     let mut y: i16 = 0;
+    let mut pristine = Intcode::new();
+    pristine.load_program(program);
     for i in 0..100 {
         for j in 0..100 {
-            let clone = program.clone();
-            let mut intcode = Intcode::new();
-            intcode.load_program(clone);
-            intcode.poke(1, i); // Noun
-            intcode.poke(2, j); // Verb
-            intcode.run();
-            if intcode.peek(0) == 19_690_720 {
+            // Only the page holding the noun and verb gets copied
+            let mut intcode = pristine.fork();
+            intcode.poke(1, i).unwrap(); // Noun
+            intcode.poke(2, j).unwrap(); // Verb
+            intcode.run().unwrap();
+            if intcode.peek(0).unwrap() == 19_690_720 {
                 x = i as i16;
                 y = j as i16;
             }
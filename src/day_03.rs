@@ -3,7 +3,7 @@ mod wiring {
 
     // Why is this not part of the stdlib?
     fn crop_letters(s: &str, pos: usize) -> &str {
-        match s.char_indices().skip(pos).next() {
+        match s.char_indices().nth(pos) {
             Some((pos, _)) => &s[pos..],
             None => "",
         }
@@ -30,8 +30,8 @@ mod wiring {
                 traces: vec![Point { x: 0, y: 0 }],
             }
         }
-        pub fn add_trace(&mut self, movement: &String) {
-            let direction: char = movement.chars().nth(0).unwrap();
+        pub fn add_trace(&mut self, movement: &str) {
+            let direction: char = movement.chars().next().unwrap();
             let distance: i32 = crop_letters(movement, 1).parse().unwrap();
             let last_x = self.traces.last().unwrap().x;
             let last_y = self.traces.last().unwrap().y;
@@ -47,25 +47,25 @@ mod wiring {
 
         fn walk_x_right(&mut self, last_x: i32, last_y: i32, distance: i32) {
             for x in (last_x + 1)..(last_x + distance + 1) {
-                self.traces.push(Point { x: x, y: last_y });
+                self.traces.push(Point { x, y: last_y });
             }
         }
 
         fn walk_x_left(&mut self, last_x: i32, last_y: i32, distance: i32) {
             for x in ((last_x - distance)..(last_x)).rev() {
-                self.traces.push(Point { x: x, y: last_y });
+                self.traces.push(Point { x, y: last_y });
             }
         }
 
         fn walk_y_up(&mut self, last_x: i32, last_y: i32, distance: i32) {
             for y in (last_y + 1)..(last_y + distance + 1) {
-                self.traces.push(Point { x: last_x, y: y });
+                self.traces.push(Point { x: last_x, y });
             }
         }
 
         fn walk_y_down(&mut self, last_x: i32, last_y: i32, distance: i32) {
             for y in ((last_y - distance)..(last_y)).rev() {
-                self.traces.push(Point { x: last_x, y: y });
+                self.traces.push(Point { x: last_x, y });
             }
         }
     }
@@ -108,9 +108,9 @@ mod wiring {
     }
 }
 
-fn build_wire(wire_movements: &String) -> wiring::Wire {
+fn build_wire(wire_movements: &str) -> wiring::Wire {
     let mut wire = wiring::Wire::new();
-    let movements: Vec<String> = wire_movements.split(",").map(|s| s.to_string()).collect();
+    let movements: Vec<String> = wire_movements.split(',').map(|s| s.to_string()).collect();
     for m in movements {
         wire.add_trace(&m);
     }
@@ -118,8 +118,8 @@ fn build_wire(wire_movements: &String) -> wiring::Wire {
 }
 
 fn build_wires_from_input(input: Vec<String>) -> (wiring::Wire, wiring::Wire) {
-    let wire_1_input = input.iter().nth(0).unwrap();
-    let wire_2_input = input.iter().nth(1).unwrap();
+    let wire_1_input = &input[0];
+    let wire_2_input = &input[1];
     let wire_1 = build_wire(wire_1_input);
     let wire_2 = build_wire(wire_2_input);
     (wire_1, wire_2)
@@ -134,7 +134,7 @@ pub fn part_1(input: Vec<String>) -> String {
         distances.push(i.point.manhattan());
     }
     distances.sort();
-    format!("Part 1: {}", distances.iter().nth(0).unwrap()).to_string()
+    format!("Part 1: {}", distances[0]).to_string()
 }
 
 pub fn part_2(input: Vec<String>) -> String {
@@ -142,11 +142,7 @@ pub fn part_2(input: Vec<String>) -> String {
 
     let mut intersections = wiring::Intersection::find_intersections(&wire_1, &wire_2);
     intersections.sort_by_key(|i| i.signal_delay);
-    format!(
-        "Part 2: {}",
-        intersections.iter().nth(0).unwrap().signal_delay
-    )
-    .to_string()
+    format!("Part 2: {}", intersections[0].signal_delay).to_string()
 }
 
 #[cfg(test)]
@@ -164,7 +160,7 @@ mod wiring_tests {
     fn new_wires_have_an_origin_set() {
         let test_wire = wiring::Wire::new();
         let origin = wiring::Point { x: 0, y: 0 };
-        assert_eq!(*test_wire.traces.iter().nth(0).unwrap(), origin);
+        assert_eq!(test_wire.traces[0], origin);
     }
     #[test]
     fn trace_can_add_points() {
@@ -28,10 +28,10 @@ fn combo_to_digits(combo: u32) -> (u32, u32, u32, u32, u32, u32) {
 
 pub fn is_valid_ruleset_2(d6: u32, d5: u32, d4: u32, d3: u32, d2: u32, d1: u32) -> bool {
     let mut m: HashMap<u32, usize> = HashMap::new();
-    for x in vec![d6, d5, d4, d3, d2, d1] {
+    for x in [d6, d5, d4, d3, d2, d1] {
         *m.entry(x).or_default() += 1;
     }
-    return m.values().any(|&val| val == 2);
+    m.values().any(|&val| val == 2)
 }
 
 pub fn is_valid_ruleset_1(d6: u32, d5: u32, d4: u32, d3: u32, d2: u32, d1: u32) -> bool {
@@ -43,7 +43,7 @@ pub fn is_valid_ruleset_1(d6: u32, d5: u32, d4: u32, d3: u32, d2: u32, d1: u32)
     if (d1 == d2) || (d2 == d3) || (d3 == d4) || (d4 == d5) || (d5 == d6) {
         return true;
     }
-    return false;
+    false
 }
 
 pub fn part_1() -> String {
@@ -61,16 +61,16 @@ mod day_04_tests {
     use super::*;
     #[test]
     fn test_valid_ruleset_1() {
-        assert_eq!(is_valid_ruleset_1(1, 1, 1, 1, 1, 1), true);
-        assert_eq!(is_valid_ruleset_1(1, 2, 3, 4, 4, 5), true);
-        assert_eq!(is_valid_ruleset_1(2, 2, 3, 4, 5, 0), false);
-        assert_eq!(is_valid_ruleset_1(1, 2, 3, 7, 8, 9), false);
-        assert_eq!(is_valid_ruleset_1(1, 2, 3, 4, 4, 3), false);
+        assert!(is_valid_ruleset_1(1, 1, 1, 1, 1, 1));
+        assert!(is_valid_ruleset_1(1, 2, 3, 4, 4, 5));
+        assert!(!is_valid_ruleset_1(2, 2, 3, 4, 5, 0));
+        assert!(!is_valid_ruleset_1(1, 2, 3, 7, 8, 9));
+        assert!(!is_valid_ruleset_1(1, 2, 3, 4, 4, 3));
     }
     #[test]
     fn test_valid_ruleset_2() {
-        assert_eq!(is_valid_ruleset_2(1, 1, 2, 2, 3, 3), true);
-        assert_eq!(is_valid_ruleset_2(1, 2, 3, 4, 4, 4), false);
-        assert_eq!(is_valid_ruleset_2(1, 1, 1, 1, 2, 2), true);
+        assert!(is_valid_ruleset_2(1, 1, 2, 2, 3, 3));
+        assert!(!is_valid_ruleset_2(1, 2, 3, 4, 4, 4));
+        assert!(is_valid_ruleset_2(1, 1, 1, 1, 2, 2));
     }
 }
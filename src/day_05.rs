@@ -1,17 +1,17 @@
 use crate::intcode::intcode::Intcode;
 
-pub fn part_1(program: Vec<isize>) -> String {
+pub fn part_1(program: Vec<i64>) -> String {
     let mut intcode = Intcode::new();
     intcode.load_program(program);
     intcode.set_input(1); // Air Conditioners - System ID 1
-    intcode.run();
-    format!("Part 1: {}", intcode.read_output(0)).to_string()
+    intcode.run().unwrap();
+    format!("Part 1: {}", intcode.last_output().unwrap()).to_string()
 }
 
-pub fn part_2(program: Vec<isize>) -> String {
+pub fn part_2(program: Vec<i64>) -> String {
     let mut intcode = Intcode::new();
     intcode.load_program(program);
     intcode.set_input(5); // Thermal Radiators - System ID 5
-    intcode.run();
-    format!("Part 2: {}", intcode.read_output(0)).to_string()
+    intcode.run().unwrap();
+    format!("Part 2: {}", intcode.last_output().unwrap()).to_string()
 }
@@ -1,8 +1,28 @@
+use crate::intcode::intcode::Intcode;
+use crate::intcode::scheduler::Scheduler;
 use itertools::Itertools;
 
 mod amp_stack {
     use crate::intcode::intcode::Intcode;
-    use crate::intcode::intcode::IntcodeState;
+    use crate::intcode::intcode::RunOutcome;
+    use std::collections::VecDeque;
+
+    // Instructions each amp may execute over its lifetime, a buggy program
+    // fails loudly instead of looping forever
+    pub const AMP_STEP_BUDGET: u64 = 1_000_000;
+
+    // Feeds one signal through an amp and returns the signal it answers with
+    fn run_amp(amp: &mut Intcode, signal: i64) -> i64 {
+        let remaining = AMP_STEP_BUDGET.saturating_sub(amp.steps_executed());
+        let mut output = Vec::new();
+        let outcome = amp
+            .run_with_limit(&mut VecDeque::from(vec![signal]), &mut output, remaining)
+            .unwrap();
+        if let RunOutcome::BudgetExhausted { steps } = outcome {
+            panic!("Amp still running after {} instructions", steps);
+        }
+        output.pop().unwrap()
+    }
 
     #[derive(Debug)]
     pub struct AmpStack {
@@ -22,51 +42,30 @@ mod amp_stack {
                 amp_e: Intcode::new(),
             }
         }
-        pub fn is_halted(&self) -> bool {
-            self.amp_e.get_state() == IntcodeState::Halted
-        }
-        pub fn init_amps(&mut self, program: Vec<isize>, phases: Vec<isize>) {
+        pub fn init_amps(&mut self, program: Vec<i64>, phases: Vec<i64>) {
             self.amp_a.load_program(program.clone());
             self.amp_b.load_program(program.clone());
             self.amp_c.load_program(program.clone());
             self.amp_d.load_program(program.clone());
             self.amp_e.load_program(program.clone());
-            self.amp_a.set_input(phases.iter().nth(0).unwrap().clone());
-            self.amp_b.set_input(phases.iter().nth(1).unwrap().clone());
-            self.amp_c.set_input(phases.iter().nth(2).unwrap().clone());
-            self.amp_d.set_input(phases.iter().nth(3).unwrap().clone());
-            self.amp_e.set_input(phases.iter().nth(4).unwrap().clone());
-            // Turn on amps, they will pause for the next input
-            self.amp_a.run();
-            self.amp_b.run();
-            self.amp_c.run();
-            self.amp_d.run();
-            self.amp_e.run();
+            // Phases sit at the front of each input queue ahead of any signal
+            self.amp_a.push_input(phases[0]);
+            self.amp_b.push_input(phases[1]);
+            self.amp_c.push_input(phases[2]);
+            self.amp_d.push_input(phases[3]);
+            self.amp_e.push_input(phases[4]);
         }
-        pub fn process(&mut self, signal_input: isize) -> isize {
+        pub fn process(&mut self, signal_input: i64) -> i64 {
             // Daisy-chain inputs and ouputs, start another processing cycle
-            self.amp_a.set_input(signal_input);
-            self.amp_a.run();
-            self.amp_b.set_input(self.amp_a.read_output(0));
-            self.amp_b.run();
-            self.amp_c.set_input(self.amp_b.read_output(0));
-            self.amp_c.run();
-            self.amp_d.set_input(self.amp_c.read_output(0));
-            self.amp_d.run();
-            self.amp_e.set_input(self.amp_d.read_output(0));
-            self.amp_e.run();
-            let result = self.amp_e.read_output(0);
-            // Flush outputs
-            self.amp_a.flush_output();
-            self.amp_b.flush_output();
-            self.amp_c.flush_output();
-            self.amp_d.flush_output();
-            self.amp_e.flush_output();
-            result
+            let signal = run_amp(&mut self.amp_a, signal_input);
+            let signal = run_amp(&mut self.amp_b, signal);
+            let signal = run_amp(&mut self.amp_c, signal);
+            let signal = run_amp(&mut self.amp_d, signal);
+            run_amp(&mut self.amp_e, signal)
         }
     }
 }
-fn tune_amps_part_1(program: &Vec<isize>) -> isize {
+fn tune_amps_part_1(program: &[i64]) -> i64 {
     let mut outputs = Vec::new();
     let signal_input = 0;
     let phases = vec![4, 3, 2, 1, 0]
@@ -75,7 +74,7 @@ fn tune_amps_part_1(program: &Vec<isize>) -> isize {
         .collect::<Vec<_>>();
 
     for combo in phases {
-        let clone = program.clone();
+        let clone = program.to_vec();
         let mut amp_stack = amp_stack::AmpStack::new();
         amp_stack.init_amps(clone, combo);
         outputs.push(amp_stack.process(signal_input));
@@ -83,7 +82,7 @@ fn tune_amps_part_1(program: &Vec<isize>) -> isize {
     *outputs.iter().max().unwrap()
 }
 
-fn tune_amps_part_2(program: &Vec<isize>) -> isize {
+fn tune_amps_part_2(program: &[i64]) -> i64 {
     let mut outputs = Vec::new();
     let signal_input = 0;
     let phases = vec![9, 8, 7, 6, 5]
@@ -91,29 +90,35 @@ fn tune_amps_part_2(program: &Vec<isize>) -> isize {
         .permutations(5)
         .collect::<Vec<_>>();
 
+    let mut pristine = Intcode::new();
+    pristine.load_program(program.to_vec());
     for combo in phases {
-        let clone = program.clone();
-        let mut amp_stack = amp_stack::AmpStack::new();
-        amp_stack.init_amps(clone, combo);
-        // Infinite loop avoider for testing
-        let max_runs = 1000;
-        let mut output = amp_stack.process(signal_input); // Initial run
-        for _ in 0..max_runs {
-            output = amp_stack.process(output);
-            if amp_stack.is_halted() {
-                outputs.push(output.clone());
-                break;
-            }
+        let mut scheduler = Scheduler::new();
+        for phase in combo {
+            let mut amp = pristine.fork();
+            amp.push_input(phase);
+            scheduler.add(amp);
+        }
+        // The same per-amp step budget keeps a looping program from hanging here
+        scheduler.set_budget(Some(5 * amp_stack::AMP_STEP_BUDGET));
+        // Each amp feeds the next, the last one loops back to the first
+        let result = (0..5)
+            .try_for_each(|id| scheduler.route(id, Some((id + 1) % 5)))
+            .and_then(|_| scheduler.push_input(0, signal_input))
+            .and_then(|_| scheduler.run());
+        if let Err(e) = result {
+            panic!("Amp feedback loop failed: {}", e);
         }
+        outputs.push(scheduler.last_output(4).unwrap());
     }
     *outputs.iter().max().unwrap()
 }
 
-pub fn part_1(program: Vec<isize>) -> String {
+pub fn part_1(program: Vec<i64>) -> String {
     format!("Part 1: {}", tune_amps_part_1(&program))
 }
 
-pub fn part_2(program: Vec<isize>) -> String {
+pub fn part_2(program: Vec<i64>) -> String {
     format!("Part 1: {}", tune_amps_part_2(&program))
 }
 
@@ -179,4 +184,13 @@ mod day_07_tests {
         let signal_output = tune_amps_part_2(&program);
         assert_eq!(signal_output, 18216);
     }
+    #[test]
+    #[should_panic(expected = "Amp still running")]
+    fn day_07_runaway_program_fails_loudly() {
+        // Reads its phase then spins on a jump to itself
+        let program = vec![3, 7, 1105, 1, 2, 99, 0, 0];
+        let mut amp_stack = amp_stack::AmpStack::new();
+        amp_stack.init_amps(program, vec![0, 1, 2, 3, 4]);
+        amp_stack.process(0);
+    }
 }
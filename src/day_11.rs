@@ -1,7 +1,9 @@
 use itertools::Itertools;
 pub mod robot {
     use crate::intcode::intcode::Intcode;
-    use crate::intcode::intcode::IntcodeState;
+    use crate::intcode::io::{InputFn, OutputFn};
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
 
     #[derive(Debug)]
     enum Direction {
@@ -12,9 +14,9 @@ pub mod robot {
     }
     #[derive(Debug, PartialEq, Eq, Clone)]
     pub struct Panel {
-        pub x: isize,
-        pub y: isize,
-        pub color: isize,
+        pub x: i64,
+        pub y: i64,
+        pub color: i64,
     }
     #[derive(Debug)]
     pub struct Robot {
@@ -24,16 +26,15 @@ pub mod robot {
     }
 
     impl Robot {
-        pub fn new(starting_color: isize) -> Robot {
-            let mut path = Vec::new();
-            path.push(Panel {
+        pub fn new(starting_color: i64) -> Robot {
+            let path = vec![Panel {
                 x: 0,
                 y: 0,
                 color: starting_color,
-            });
+            }];
             Robot {
                 brain: Intcode::new(),
-                path: path,
+                path,
                 direction: Direction::Up,
             }
         }
@@ -44,30 +45,38 @@ pub mod robot {
             }
             path
         }
-        pub fn load_program(&mut self, program: Vec<isize>) {
+        pub fn load_program(&mut self, program: Vec<i64>) {
             self.brain.load_program(program);
         }
         pub fn run(&mut self) {
-            loop {
-                if IntcodeState::Halted == self.brain.get_state() {
-                    return;
-                } else {
-                    self.step();
-                }
+            // The brain is lent out so that answering it can move the robot
+            let mut brain = std::mem::take(&mut self.brain);
+            let outputs = RefCell::new(VecDeque::new());
+            let mut sink = OutputFn(|value| outputs.borrow_mut().push_back(value));
+            // Take the tile we're currently on as input, once the brain's
+            // answers to the last one have been acted on
+            let mut source = InputFn(|| {
+                self.follow(&mut outputs.borrow_mut());
+                Some(self.path[0].color)
+            });
+            brain.run_with(&mut source, &mut sink).unwrap();
+            // The last answers come just before the brain halts
+            self.follow(&mut outputs.borrow_mut());
+            self.brain = brain;
+        }
+        // The brain answers with a color and a turn
+        fn follow(&mut self, outputs: &mut VecDeque<i64>) {
+            while outputs.len() >= 2 {
+                let paint_color = outputs.pop_front().unwrap();
+                let direction = outputs.pop_front().unwrap();
+                self.step(paint_color, direction);
             }
         }
-        pub fn step(&mut self) {
+        fn step(&mut self, paint_color: i64, direction: i64) {
             // Paths are always added to the front of the vec so we can easily
             // find the last-seen tile since find() returns the first hit down
             // the vec. If we were to push the logic would be wrong when scanning.
-
-            // Take the tile we're currently on as input
             let current_panel = &self.path[0].clone();
-            self.brain.set_input(current_panel.color);
-            self.brain.run();
-            let paint_color = self.brain.read_output(0);
-            let direction = self.brain.read_output(1);
-            self.brain.flush_output();
             // Paint the current tile _before_ moving
             self.path.first_mut().unwrap().color = paint_color;
             // Now we're allowed to move
@@ -90,9 +99,9 @@ pub mod robot {
             self.path.insert(0, new_panel);
         }
 
-        fn roll(&mut self, direction: isize) -> (isize, isize) {
-            let mut delta_x: isize = 0;
-            let mut delta_y: isize = 0;
+        fn roll(&mut self, direction: i64) -> (i64, i64) {
+            let mut delta_x: i64 = 0;
+            let mut delta_y: i64 = 0;
             if 1 == direction {
                 // Clockwise Rotations
                 match &self.direction {
@@ -139,7 +148,7 @@ pub mod robot {
     }
 }
 
-pub fn part_1(program: Vec<isize>) -> String {
+pub fn part_1(program: Vec<i64>) -> String {
     let mut robot = robot::Robot::new(0);
     robot.load_program(program);
     robot.run();
@@ -152,17 +161,17 @@ pub fn part_1(program: Vec<isize>) -> String {
     format!("Part 1: {}", painted_panels.len())
 }
 
-pub fn part_2(program: Vec<isize>) -> String {
+pub fn part_2(program: Vec<i64>) -> String {
     let mut robot = robot::Robot::new(1);
     robot.load_program(program);
     robot.run();
 
     let painted_panels: Vec<robot::Panel> = robot.get_path();
     // Coords may be negative, calculate an offset suitable for drawing
-    let x_vals: Vec<isize> = painted_panels.iter().map(|p| p.x).collect();
+    let x_vals: Vec<i64> = painted_panels.iter().map(|p| p.x).collect();
     let x_min = *x_vals.iter().min().unwrap();
     let x_max = *x_vals.iter().max().unwrap();
-    let y_vals: Vec<isize> = painted_panels.iter().map(|p| p.y).collect();
+    let y_vals: Vec<i64> = painted_panels.iter().map(|p| p.y).collect();
     let y_min = *y_vals.iter().min().unwrap();
     let y_max = *y_vals.iter().max().unwrap();
 
@@ -174,12 +183,12 @@ pub fn part_2(program: Vec<isize>) -> String {
                 None => 0,
             };
             if 0 == color {
-                output.push_str(&" ");
+                output.push(' ');
             } else {
-                output.push_str(&"#");
+                output.push('#');
             }
         }
-        output.push_str(&"\n");
+        output.push('\n');
     }
     format!("Part 2: {}", output)
 }
@@ -1,79 +1,565 @@
+pub mod assembler;
+pub mod cache;
+pub mod cfg;
+pub mod debugger;
+pub mod decompiler;
+pub mod disassembler;
+pub mod extension;
+pub mod handle;
+pub mod io;
+pub mod journal;
+pub mod memory;
+pub mod profile;
+pub mod scheduler;
+pub mod snapshot;
+pub mod trace;
+pub mod transpiler;
+pub mod word;
+
+#[allow(clippy::module_inception)]
 pub mod intcode {
+    use super::cache::DecodeCache;
+    use super::extension::{Control, OpcodeRegistry, Operand, Param};
+    use super::journal::Journal;
+    use super::memory::Memory;
+    use super::profile::Profiler;
+    use super::trace::Tracer;
+    use super::word::{Arithmetic, Word};
+    use std::cmp::Ordering;
+    use std::collections::vec_deque::Drain;
+    use std::collections::VecDeque;
+    use std::convert::TryFrom;
+    use std::fmt;
+
+    #[derive(Debug, PartialEq, Clone)]
+    pub enum IntcodeError {
+        UnknownOpcode { addr: usize, value: i128 },
+        InvalidMode { addr: usize, value: i128 },
+        NegativeAddress { addr: usize, target: i128 },
+        AddressOverflow { addr: usize, target: i128 }, // Too large to be a usize at all
+        AddressOutOfRange { addr: usize, target: usize },
+        InputUnderflow { addr: usize },
+        ArithmeticOverflow { addr: usize },
+        ImmediateWrite { addr: usize, value: i128 }, // Strict modes only
+        UnusedMode { addr: usize, value: i128 },     // Strict modes only
+    }
+
+    impl fmt::Display for IntcodeError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                IntcodeError::UnknownOpcode { addr, value } => {
+                    write!(f, "unknown opcode {} at address {}", value, addr)
+                }
+                IntcodeError::InvalidMode { addr, value } => {
+                    write!(f, "invalid parameter mode in {} at address {}", value, addr)
+                }
+                IntcodeError::NegativeAddress { addr, target } => write!(
+                    f,
+                    "negative address {} accessed by instruction at {}",
+                    target, addr
+                ),
+                IntcodeError::AddressOverflow { addr, target } => write!(
+                    f,
+                    "address {} too large for instruction at {}",
+                    target, addr
+                ),
+                IntcodeError::AddressOutOfRange { addr, target } => write!(
+                    f,
+                    "address {} out of range for instruction at {}",
+                    target, addr
+                ),
+                IntcodeError::InputUnderflow { addr } => {
+                    write!(f, "no input available for instruction at {}", addr)
+                }
+                IntcodeError::ArithmeticOverflow { addr } => {
+                    write!(f, "arithmetic overflow in instruction at {}", addr)
+                }
+                IntcodeError::ImmediateWrite { addr, value } => write!(
+                    f,
+                    "immediate mode write in instruction {} at {}",
+                    value, addr
+                ),
+                IntcodeError::UnusedMode { addr, value } => write!(
+                    f,
+                    "mode set for a missing parameter in instruction {} at {}",
+                    value, addr
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for IntcodeError {}
+
     #[derive(Debug, PartialEq, Clone)]
     pub enum IntcodeState {
         Ready,
         Halted,
         WaitingForInput,
+        OutputReady, // Yielded right after an output, stepping again resumes
+        Faulted(IntcodeError),
+    }
+
+    #[derive(Debug, PartialEq, Clone)]
+    pub enum RunOutcome {
+        Stopped(IntcodeState),          // Halted, waiting for input or yielded as usual
+        BudgetExhausted { steps: u64 }, // Still running, steps is the machine's lifetime count
+    }
+
+    // What add and multiply do when the result does not fit in a cell
+    #[derive(Debug, PartialEq, Copy, Clone, Default)]
+    pub enum OverflowPolicy {
+        #[default]
+        Wrapping, // Two's complement wrap around, the old release build behaviour
+        Checked,    // Faults with the address of the instruction
+        Saturating, // Clamps to the nearest representable value
+        // Keeps results that do not fit as exact i128 values until the cell is
+        // next written. Add, multiply and compares see the exact value, any
+        // other use of the cell faults. Only differs from Checked below i128.
+        Widened,
+    }
+
+    impl fmt::Display for OverflowPolicy {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let name = match self {
+                OverflowPolicy::Wrapping => "wrapping",
+                OverflowPolicy::Checked => "checked",
+                OverflowPolicy::Saturating => "saturating",
+                OverflowPolicy::Widened => "widened",
+            };
+            write!(f, "{}", name)
+        }
+    }
+
+    // What happens to modes an instruction cannot use
+    #[derive(Debug, PartialEq, Copy, Clone, Default)]
+    pub enum ModePolicy {
+        #[default]
+        Lenient, // Immediate writes act as position mode, stray mode digits are ignored
+        Strict, // Faults on any mode the instruction cannot use
+    }
+
+    impl fmt::Display for ModePolicy {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let name = match self {
+                ModePolicy::Lenient => "lenient",
+                ModePolicy::Strict => "strict",
+            };
+            write!(f, "{}", name)
+        }
     }
 
+    #[allow(clippy::enum_variant_names)]
     #[derive(Debug, PartialEq, Copy, Clone)]
     pub enum MemoryMode {
         ImmediateMode,
         PositionMode,
         RelativeMode,
     }
+    // Generic over the word type so narrower or wider machines can be emulated,
+    // plain `Intcode` is the 64 bit machine the puzzles expect
     #[derive(Debug)]
-    pub struct Intcode {
-        memory: Vec<isize>,   // Day 2 - Special memory that can hold negative values
-        ip: usize,            // Day 2 - Instruction Pointer
-        input: Option<isize>, // Day 5 - External Port
-        output: Vec<isize>,   // Day 5 - External Port, Day 11 - Buffered Output
-        state: IntcodeState,  // Day 7 - System State Support for dynamic input, deprecates halt
-        relative_base: isize, // Day 9 - Relative base addressing
+    pub struct Intcode<W: Word = i64> {
+        pub(super) memory: Memory<W>, // Day 2 - Special memory that can hold negative values
+        pub(super) ip: usize,         // Day 2 - Instruction Pointer
+        pub(super) input: VecDeque<W>, // Day 5 - External Port, queued first in first out
+        pub(super) output: VecDeque<W>, // Day 5 - External Port, Day 11 - Buffered Output
+        pub(super) state: IntcodeState, // Day 7 - System State Support for dynamic input, deprecates halt
+        pub(super) relative_base: W,    // Day 9 - Relative base addressing
+        pub(super) steps: u64,          // Instructions executed over the machine's lifetime
+        pub(super) overflow_policy: OverflowPolicy, // Add and multiply behaviour on overflow
+        pub(super) mode_policy: ModePolicy, // Lenient or strict operand modes
+        mode_diagnostics: Option<Vec<IntcodeError>>, // Opt-in record of mode misuse
+        tracer: Option<Tracer>,         // Opt-in instruction trace
+        profiler: Option<Profiler>,     // Opt-in execution counters
+        pub(super) decode_cache: Option<DecodeCache>, // Decoded instructions by address, on by default
+        pub(super) journal: Option<Journal<W>>,       // Opt-in undo log for reverse stepping
+        extensions: Option<OpcodeRegistry<W>>,        // Opt-in opcodes beyond the built in set
     }
 
-    impl Intcode {
-        pub fn new() -> Intcode {
+    // Other word sizes are built with `Intcode::<i32>::default()`
+    impl<W: Word> Default for Intcode<W> {
+        fn default() -> Intcode<W> {
             Intcode {
-                memory: Vec::new(),
+                memory: Memory::new(),
                 ip: 0,
-                input: None,
-                output: Vec::new(),
+                input: VecDeque::new(),
+                output: VecDeque::new(),
                 state: IntcodeState::Ready,
-                relative_base: 0,
+                relative_base: W::ZERO,
+                steps: 0,
+                overflow_policy: OverflowPolicy::default(),
+                mode_policy: ModePolicy::default(),
+                mode_diagnostics: None,
+                tracer: None,
+                profiler: None,
+                decode_cache: Some(DecodeCache::new()),
+                journal: None,
+                extensions: None,
+            }
+        }
+    }
+
+    // Decoding does not depend on the word type, it lives with the default machine
+    impl Intcode {
+        pub fn new() -> Intcode {
+            Intcode::default()
+        }
+        pub fn decode_mem_mode(value: i128) -> Option<MemoryMode> {
+            match value {
+                0 => Some(MemoryMode::PositionMode),
+                1 => Some(MemoryMode::ImmediateMode),
+                2 => Some(MemoryMode::RelativeMode),
+                _ => None,
+            }
+        }
+        // Splits an instruction into its opcode and the modes of its three parameters
+        pub fn decode_instruction<V: Word>(
+            addr: usize,
+            value: V,
+        ) -> Result<(isize, [MemoryMode; 3]), IntcodeError> {
+            let (opcode, modes) = Intcode::split_instruction(addr, value)?;
+            match Intcode::parameter_count(opcode) {
+                Some(_) => Ok((opcode, modes)),
+                None => Err(IntcodeError::UnknownOpcode {
+                    addr,
+                    value: value.to_i128(),
+                }),
+            }
+        }
+        // Like decode_instruction, without checking the opcode is one we know
+        fn split_instruction<V: Word>(
+            addr: usize,
+            value: V,
+        ) -> Result<(isize, [MemoryMode; 3]), IntcodeError> {
+            let value = value.to_i128();
+            let mut modes = [MemoryMode::PositionMode; 3];
+            // Values over 99 are guarenteed parameter / relative mode opcodes
+            if value > 99 {
+                for (k, mode) in modes.iter_mut().enumerate() {
+                    *mode = Intcode::decode_mem_mode(value / 10i128.pow(k as u32 + 2) % 10)
+                        .ok_or(IntcodeError::InvalidMode { addr, value })?;
+                }
+            }
+            Ok(((value % 100) as isize, modes))
+        }
+        pub fn parameter_count(opcode: isize) -> Option<usize> {
+            match opcode {
+                1 | 2 | 7 | 8 => Some(3),
+                5 | 6 => Some(2),
+                3 | 4 | 9 => Some(1),
+                99 => Some(0),
+                _ => None,
             }
         }
+    }
+
+    impl<W: Word> Intcode<W> {
         pub fn get_state(&self) -> IntcodeState {
             self.state.clone()
         }
-        pub fn set_input(&mut self, input: isize) {
-            self.input = Some(input.clone());
+        pub fn get_ip(&self) -> usize {
+            self.ip
+        }
+        pub fn get_relative_base(&self) -> W {
+            self.relative_base
+        }
+        pub fn steps_executed(&self) -> u64 {
+            self.steps
+        }
+        pub fn overflow_policy(&self) -> OverflowPolicy {
+            self.overflow_policy
+        }
+        pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+            self.overflow_policy = policy;
+        }
+        pub fn mode_policy(&self) -> ModePolicy {
+            self.mode_policy
+        }
+        pub fn set_mode_policy(&mut self, policy: ModePolicy) {
+            self.mode_policy = policy;
+            // Cached instructions were only checked against the old policy
+            self.clear_decode_cache();
+        }
+        // Records each distinct mode misuse, as the fault strict mode would raise,
+        // whatever the policy
+        pub fn set_mode_diagnostics(&mut self, enabled: bool) {
+            self.mode_diagnostics = if enabled { Some(Vec::new()) } else { None };
+            self.clear_decode_cache();
+        }
+        pub fn mode_diagnostics(&self) -> Option<&[IntcodeError]> {
+            self.mode_diagnostics.as_deref()
+        }
+        pub fn take_mode_diagnostics(&mut self) -> Option<Vec<IntcodeError>> {
+            self.mode_diagnostics.take()
+        }
+        pub fn set_input(&mut self, input: W) {
+            self.push_input(input);
+        }
+        pub fn push_input(&mut self, input: W) {
+            self.input.push_back(input);
             // Set state back to ready if intcode is in WaitingForInput state
-            self.state = IntcodeState::Ready;
+            if IntcodeState::WaitingForInput == self.state {
+                self.state = IntcodeState::Ready;
+            }
+        }
+        pub fn push_inputs(&mut self, inputs: impl IntoIterator<Item = W>) {
+            for input in inputs {
+                self.push_input(input);
+            }
+        }
+        pub fn pending_inputs(&self) -> &VecDeque<W> {
+            &self.input
         }
-        pub fn read_output(&mut self, position: usize) -> isize {
-            *self.output.clone().iter().nth(position).unwrap()
+        pub fn clear_inputs(&mut self) {
+            self.input.clear();
+        }
+        #[deprecated(note = "panics past the end of the buffer, use output_at")]
+        pub fn read_output(&mut self, position: usize) -> W {
+            self.output[position]
+        }
+        // Pending output at `position`, None past the end of the buffer
+        pub fn output_at(&self, position: usize) -> Option<W> {
+            self.output.get(position).copied()
         }
         pub fn flush_output(&mut self) {
-            self.output = Vec::new();
+            self.output.clear();
+        }
+        pub fn pending_outputs(&self) -> &VecDeque<W> {
+            &self.output
+        }
+        pub fn take_outputs(&mut self) -> Vec<W> {
+            std::mem::take(&mut self.output).into()
+        }
+        // Oldest output first, same order the program emitted them
+        pub fn pop_output(&mut self) -> Option<W> {
+            self.output.pop_front()
+        }
+        pub fn last_output(&self) -> Option<W> {
+            self.output.back().copied()
+        }
+        pub fn output_len(&self) -> usize {
+            self.output.len()
+        }
+        pub fn drain_outputs(&mut self) -> Drain<'_, W> {
+            self.output.drain(..)
+        }
+        pub fn load_program(&mut self, program: Vec<W>) {
+            // Day 09 - Memory grows on demand, keep any configured cap across reloads
+            let mut memory = Memory::from_program(&program);
+            memory.set_limit(self.memory.get_limit());
+            memory.succeed(&self.memory);
+            self.memory = memory;
+            self.clear_decode_cache();
         }
-        pub fn load_program(&mut self, program: Vec<isize>) {
-            self.memory = program.clone();
-            // Day 09 - Expand memory greatly
-            self.memory.extend(vec![0 as isize; 4000]);
+        // A second machine carrying on from exactly this point. Memory pages are
+        // shared until either machine writes to them, the tracer, profiler and
+        // journal stay with the original.
+        pub fn fork(&self) -> Intcode<W> {
+            Intcode {
+                memory: self.memory.clone(),
+                ip: self.ip,
+                input: self.input.clone(),
+                output: self.output.clone(),
+                state: self.state.clone(),
+                relative_base: self.relative_base,
+                steps: self.steps,
+                overflow_policy: self.overflow_policy,
+                mode_policy: self.mode_policy,
+                decode_cache: self.decode_cache.clone(),
+                extensions: self.extensions.clone(),
+                ..Intcode::default()
+            }
+        }
+        pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+            self.memory.set_limit(limit);
+            // Cached addresses may now be out of range
+            self.clear_decode_cache();
+        }
+        // Disabling falls back to decoding every instruction as it is fetched
+        pub fn set_decode_cache(&mut self, enabled: bool) {
+            self.decode_cache = if enabled {
+                Some(DecodeCache::new())
+            } else {
+                None
+            };
+        }
+        pub fn decode_cache(&self) -> Option<&DecodeCache> {
+            self.decode_cache.as_ref()
+        }
+        pub(super) fn clear_decode_cache(&mut self) {
+            if let Some(cache) = &mut self.decode_cache {
+                cache.clear();
+            }
+        }
+        // Opcodes in the registry run after the built in set turns them down
+        pub fn set_extensions(&mut self, extensions: Option<OpcodeRegistry<W>>) {
+            self.extensions = extensions;
+            // Instructions that faulted as unknown may decode now
+            self.clear_decode_cache();
+        }
+        pub fn extensions(&self) -> Option<&OpcodeRegistry<W>> {
+            self.extensions.as_ref()
+        }
+        pub fn take_extensions(&mut self) -> Option<OpcodeRegistry<W>> {
+            let extensions = self.extensions.take();
+            self.clear_decode_cache();
+            extensions
+        }
+        pub fn highest_address_touched(&self) -> Option<usize> {
+            self.memory.highest_touched()
+        }
+        // Looking from outside, unlike the program's own reads this leaves the
+        // highest address touched alone
+        pub fn peek(&self, memory_addr: usize) -> Result<W, IntcodeError> {
+            self.memory
+                .get(memory_addr)
+                .ok_or(IntcodeError::AddressOutOfRange {
+                    addr: self.ip,
+                    target: memory_addr,
+                })
+        }
+        pub fn poke(&mut self, memory_addr: usize, value: W) -> Result<(), IntcodeError> {
+            self.store(memory_addr, value)
+        }
+        pub fn set_tracer(&mut self, tracer: Option<Tracer>) {
+            self.tracer = tracer;
+        }
+        pub fn tracer(&self) -> Option<&Tracer> {
+            self.tracer.as_ref()
+        }
+        pub fn take_tracer(&mut self) -> Option<Tracer> {
+            self.tracer.take()
+        }
+        pub fn set_profiler(&mut self, profiler: Option<Profiler>) {
+            self.profiler = profiler;
         }
-        pub fn peek(&mut self, memory_addr: usize) -> isize {
-            *self.memory.iter().nth(memory_addr).unwrap()
+        pub fn profiler(&self) -> Option<&Profiler> {
+            self.profiler.as_ref()
         }
-        pub fn poke(&mut self, memory_addr: usize, value: isize) {
-            std::mem::swap(&mut self.memory[memory_addr], &mut value.clone());
+        pub fn take_profiler(&mut self) -> Option<Profiler> {
+            self.profiler.take()
         }
         pub fn core_dump(&mut self) {
             println!("{:?}", self);
         }
-        pub fn step(&mut self) {
-            let value = self.memory.iter().nth(self.ip).unwrap();
-            let mut p1_mode = MemoryMode::PositionMode;
-            let mut p2_mode = MemoryMode::PositionMode;
-            let mut p3_mode = MemoryMode::PositionMode;
-            let opcode_ones = value / (10isize.pow(0)) % 10;
-            let opcode_tens = value / (10isize.pow(1)) % 10;
-            let opcode = (opcode_tens * 10) + opcode_ones;
-            // Values over 99 are guarenteed parameter / relative mode opcodes
-            if *value > 99 {
-                p1_mode = Intcode::decode_mem_mode(value / (10isize.pow(2)) % 10);
-                p2_mode = Intcode::decode_mem_mode(value / (10isize.pow(3)) % 10);
-                p3_mode = Intcode::decode_mem_mode(value / (10isize.pow(4)) % 10);
+        pub fn step(&mut self) -> Result<(), IntcodeError> {
+            if let IntcodeState::Faulted(error) = &self.state {
+                return Err(error.clone());
+            }
+            // The halt has already run, running it again would change nothing
+            if IntcodeState::Halted == self.state {
+                return Ok(());
+            }
+            if let Some(journal) = &mut self.journal {
+                journal.begin(
+                    self.ip,
+                    self.relative_base,
+                    self.state.clone(),
+                    self.memory.highest_touched(),
+                    self.steps,
+                );
+            }
+            if IntcodeState::OutputReady == self.state {
+                self.state = IntcodeState::Ready;
+            }
+            if let Some(tracer) = &mut self.tracer {
+                tracer.begin(self.ip);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.begin(self.ip, self.overflow_policy);
+            }
+            let result = self.execute();
+            // A starved input instruction never ran, there is nothing to record
+            let ran = match &result {
+                Err(IntcodeError::InputUnderflow { .. }) => false,
+                Err(_) => true,
+                Ok(()) => IntcodeState::WaitingForInput != self.state,
+            };
+            if let Some(tracer) = &mut self.tracer {
+                if ran {
+                    tracer.finish(result.as_ref().err());
+                } else {
+                    tracer.discard();
+                }
+            }
+            if let Some(profiler) = &mut self.profiler {
+                if ran && result.is_ok() {
+                    profiler.finish();
+                } else {
+                    profiler.discard();
+                }
+            }
+            if let Some(journal) = &mut self.journal {
+                if ran {
+                    journal.commit();
+                } else {
+                    journal.discard();
+                }
+            }
+            match &result {
+                // Starving a paused machine is the caller's mistake, not the program's
+                Err(IntcodeError::InputUnderflow { .. }) => (),
+                Err(error) => self.state = IntcodeState::Faulted(error.clone()),
+                Ok(()) if ran => self.steps += 1,
+                Ok(()) => (),
+            }
+            result
+        }
+        pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+            self.run_until(|_| false)
+        }
+        // Counts instructions executed outside of step, by transpiled code
+        pub(super) fn retire(&mut self, steps: u64) {
+            self.steps += steps;
+        }
+        // Runs until at least `count` outputs are buffered, yielding OutputReady
+        pub fn run_until_output(&mut self, count: usize) -> Result<IntcodeState, IntcodeError> {
+            self.run_until(|intcode| {
+                IntcodeState::OutputReady == intcode.state && intcode.output.len() >= count
+            })
+        }
+        // Executes at most `steps` instructions, Ready means there is more to do
+        pub fn run_steps(&mut self, steps: usize) -> Result<IntcodeState, IntcodeError> {
+            let mut taken = 0;
+            self.run_until(|_| {
+                taken += 1;
+                taken > steps
+            })
+        }
+        // Like run, but gives up once `max_steps` instructions have executed
+        pub fn run_with_budget(&mut self, max_steps: usize) -> Result<RunOutcome, IntcodeError> {
+            match self.run_steps(max_steps)? {
+                IntcodeState::Ready | IntcodeState::OutputReady => {
+                    Ok(RunOutcome::BudgetExhausted { steps: self.steps })
+                }
+                state => Ok(RunOutcome::Stopped(state)),
+            }
+        }
+        fn run_until(
+            &mut self,
+            mut stop: impl FnMut(&Intcode<W>) -> bool,
+        ) -> Result<IntcodeState, IntcodeError> {
+            loop {
+                match &self.state {
+                    IntcodeState::Ready | IntcodeState::OutputReady => {
+                        if stop(self) {
+                            return Ok(self.get_state());
+                        }
+                        self.step()?
+                    }
+                    IntcodeState::Faulted(error) => return Err(error.clone()),
+                    state => return Ok(state.clone()),
+                }
+            }
+        }
+        fn execute(&mut self) -> Result<(), IntcodeError> {
+            let (opcode, [p1_mode, p2_mode, p3_mode]) = self.decode_at_ip()?;
+            let count = self.arity(opcode).unwrap();
+            if let Some(tracer) = &mut self.tracer {
+                tracer.decoded(opcode, &[p1_mode, p2_mode, p3_mode][..count]);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.decoded(opcode, &[p1_mode, p2_mode, p3_mode][..count]);
             }
             match opcode {
                 1 => self.add(p1_mode, p2_mode, p3_mode),
@@ -85,121 +571,470 @@ pub mod intcode {
                 7 => self.less_than(p1_mode, p2_mode, p3_mode),
                 8 => self.equal(p1_mode, p2_mode, p3_mode),
                 9 => self.set_relative_base(p1_mode),
-                99 => self.state = IntcodeState::Halted,
-                _ => (),
+                99 => {
+                    self.state = IntcodeState::Halted;
+                    Ok(())
+                }
+                _ => self.extension(opcode, [p1_mode, p2_mode, p3_mode]),
             }
         }
-        pub fn run(&mut self) {
-            loop {
-                if IntcodeState::Ready == self.state {
-                    self.step();
-                } else {
-                    return;
+        fn arity(&self, opcode: isize) -> Option<usize> {
+            Intcode::parameter_count(opcode).or_else(|| {
+                let extension = self.extensions.as_ref()?.get(opcode)?;
+                Some(extension.params().len())
+            })
+        }
+        fn extension(&mut self, opcode: isize, modes: [MemoryMode; 3]) -> Result<(), IntcodeError> {
+            let extension = match self.extensions.as_ref().and_then(|e| e.get(opcode)) {
+                Some(extension) => extension.clone(),
+                None => {
+                    return Err(IntcodeError::UnknownOpcode {
+                        addr: self.ip,
+                        value: opcode as i128,
+                    })
                 }
+            };
+            let mut operands = Vec::with_capacity(extension.params().len());
+            for (k, param) in extension.params().iter().enumerate() {
+                let addr = self.ip + 1 + k;
+                operands.push(match param {
+                    Param::Read => Operand::Value(self.read_mem_loc(addr, modes[k])?),
+                    Param::Write => Operand::Target(self.write_target(addr, modes[k])?),
+                });
             }
+            match extension.call(self, &operands)? {
+                Control::Next => self.ip += 1 + operands.len(),
+                Control::Jump(target) => self.ip = target,
+                Control::Halt => self.state = IntcodeState::Halted,
+            }
+            Ok(())
         }
-        pub fn decode_mem_mode(value: isize) -> MemoryMode {
-            match value {
-                0 => MemoryMode::PositionMode,
-                1 => MemoryMode::ImmediateMode,
-                2 => MemoryMode::RelativeMode,
-                _ => panic!("Unsupported memory mode: {}", value),
+        fn decode_at_ip(&mut self) -> Result<(isize, [MemoryMode; 3]), IntcodeError> {
+            let ip = self.ip;
+            if let Some(decoded) = self.decode_cache.as_mut().and_then(|c| c.get(ip)) {
+                return Ok(decoded);
+            }
+            let value = self.fetch(self.ip)?;
+            let decoded = Intcode::split_instruction(self.ip, value)?;
+            if self.arity(decoded.0).is_none() {
+                return Err(IntcodeError::UnknownOpcode {
+                    addr: self.ip,
+                    value: value.to_i128(),
+                });
+            }
+            if ModePolicy::Strict == self.mode_policy || self.mode_diagnostics.is_some() {
+                if let Some(misuse) = self.mode_misuse(value.to_i128(), decoded) {
+                    if let Some(diagnostics) = &mut self.mode_diagnostics {
+                        if !diagnostics.contains(&misuse) {
+                            diagnostics.push(misuse.clone());
+                        }
+                    }
+                    if ModePolicy::Strict == self.mode_policy {
+                        return Err(misuse);
+                    }
+                }
+            }
+            if let Some(cache) = &mut self.decode_cache {
+                cache.insert(self.ip, decoded);
+            }
+            Ok(decoded)
+        }
+        // Modes the lenient decoder lets through, checked once per decode
+        fn mode_misuse(
+            &self,
+            value: i128,
+            (opcode, modes): (isize, [MemoryMode; 3]),
+        ) -> Option<IntcodeError> {
+            let addr = self.ip;
+            if value >= 100_000 {
+                return Some(IntcodeError::InvalidMode { addr, value });
+            }
+            let count = self.arity(opcode)?;
+            if 0 != value / 10i128.pow(count as u32 + 2) {
+                return Some(IntcodeError::UnusedMode { addr, value });
+            }
+            let immediate_write = (0..count)
+                .any(|k| MemoryMode::ImmediateMode == modes[k] && self.is_write_param(opcode, k));
+            if immediate_write {
+                return Some(IntcodeError::ImmediateWrite { addr, value });
+            }
+            None
+        }
+        fn is_write_param(&self, opcode: isize, k: usize) -> bool {
+            match opcode {
+                1 | 2 | 7 | 8 => 2 == k,
+                3 => 0 == k,
+                _ => self
+                    .extensions
+                    .as_ref()
+                    .and_then(|e| e.get(opcode))
+                    .is_some_and(|e| Some(&Param::Write) == e.params().get(k)),
+            }
+        }
+        pub(super) fn to_address(&self, target: W) -> Result<usize, IntcodeError> {
+            target
+                .to_address()
+                .ok_or_else(|| self.bad_address(target.to_i128()))
+        }
+        // Relative addresses are summed in i128 so the word type cannot overflow
+        pub(super) fn to_relative_address(&self, offset: W) -> Result<usize, IntcodeError> {
+            let target = offset
+                .to_i128()
+                .saturating_add(self.relative_base.to_i128());
+            usize::try_from(target).map_err(|_| self.bad_address(target))
+        }
+        fn bad_address(&self, target: i128) -> IntcodeError {
+            if target < 0 {
+                IntcodeError::NegativeAddress {
+                    addr: self.ip,
+                    target,
+                }
+            } else {
+                IntcodeError::AddressOverflow {
+                    addr: self.ip,
+                    target,
+                }
             }
         }
-        fn read_mem_loc(&mut self, addr: usize, mode: MemoryMode) -> isize {
-            let value = *self.memory.iter().nth(addr).unwrap();
+        // A wide cell cannot stand in for a word, only arithmetic may read it
+        pub(super) fn fetch(&mut self, addr: usize) -> Result<W, IntcodeError> {
+            if self.memory.wide(addr).is_some() {
+                return Err(IntcodeError::ArithmeticOverflow { addr: self.ip });
+            }
+            self.fetch_cell(addr)
+        }
+        fn fetch_cell(&mut self, addr: usize) -> Result<W, IntcodeError> {
+            let ip = self.ip;
+            self.memory
+                .read(addr)
+                .ok_or(IntcodeError::AddressOutOfRange {
+                    addr: ip,
+                    target: addr,
+                })
+        }
+        pub(super) fn store(&mut self, addr: usize, value: W) -> Result<(), IntcodeError> {
+            let ip = self.ip;
+            self.memory
+                .write(addr, value)
+                .ok_or(IntcodeError::AddressOutOfRange {
+                    addr: ip,
+                    target: addr,
+                })?;
+            if let Some(cache) = &mut self.decode_cache {
+                cache.invalidate(addr);
+            }
+            Ok(())
+        }
+        fn store_wide(&mut self, addr: usize, value: i128) -> Result<(), IntcodeError> {
+            let ip = self.ip;
+            self.memory
+                .write_wide(addr, value)
+                .ok_or(IntcodeError::AddressOutOfRange {
+                    addr: ip,
+                    target: addr,
+                })?;
+            if let Some(cache) = &mut self.decode_cache {
+                cache.invalidate(addr);
+            }
+            Ok(())
+        }
+        // The cell holding the operand at `addr`, the parameter itself when immediate
+        fn operand_cell(&mut self, addr: usize, mode: MemoryMode) -> Result<usize, IntcodeError> {
             match mode {
-                MemoryMode::PositionMode => return self.memory[value as usize],
-                MemoryMode::ImmediateMode => return value,
+                MemoryMode::PositionMode => {
+                    let value = self.fetch(addr)?;
+                    self.to_address(value)
+                }
+                MemoryMode::ImmediateMode => Ok(addr),
                 MemoryMode::RelativeMode => {
-                    return self.memory[(value + self.relative_base) as usize]
+                    let value = self.fetch(addr)?;
+                    self.to_relative_address(value)
+                }
+            }
+        }
+        fn read_mem_loc(&mut self, addr: usize, mode: MemoryMode) -> Result<W, IntcodeError> {
+            let cell = self.operand_cell(addr, mode)?;
+            let resolved = self.fetch(cell)?;
+            self.operand_read(resolved.to_i128(), mode);
+            Ok(resolved)
+        }
+        // Like read_mem_loc, but sees the exact value of a wide cell
+        fn read_wide(&mut self, addr: usize, mode: MemoryMode) -> Result<i128, IntcodeError> {
+            let cell = self.operand_cell(addr, mode)?;
+            let resolved = match self.memory.wide(cell) {
+                Some(value) => {
+                    self.fetch_cell(cell)?;
+                    value
                 }
+                None => self.fetch_cell(cell)?.to_i128(),
+            };
+            self.operand_read(resolved, mode);
+            Ok(resolved)
+        }
+        fn operand_read(&mut self, value: i128, mode: MemoryMode) {
+            if let Some(tracer) = &mut self.tracer {
+                tracer.operand(value);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.read(mode);
             }
         }
-        fn write_mem_loc(&mut self, addr: usize, value: isize, mode: MemoryMode) {
+        fn write_mem_loc(
+            &mut self,
+            addr: usize,
+            value: W,
+            mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            let dest = self.write_target(addr, mode)?;
+            self.write_operand(dest, value)
+        }
+        // Where the write parameter at `addr` points
+        pub(super) fn write_target(
+            &mut self,
+            addr: usize,
+            mode: MemoryMode,
+        ) -> Result<usize, IntcodeError> {
+            let target = self.fetch(addr)?;
             match mode {
                 // Actually Immediate mode since Position Mode is unsupported for writes
-                MemoryMode::PositionMode => self.memory[addr] = value,
-                MemoryMode::ImmediateMode => self.memory[addr] = value,
-                MemoryMode::RelativeMode => {
-                    self.memory[(addr as isize + self.relative_base) as usize] = value
+                MemoryMode::PositionMode => self.to_address(target),
+                MemoryMode::ImmediateMode => self.to_address(target),
+                MemoryMode::RelativeMode => self.to_relative_address(target),
+            }
+        }
+        // Stores an instruction's result, seen by the journal, tracer and profiler
+        // unlike poke. Extension handlers write their Target operands with this.
+        pub fn write_operand(&mut self, dest: usize, value: W) -> Result<(), IntcodeError> {
+            self.journal_write(dest)?;
+            self.store(dest, value)?;
+            self.operand_written(dest, value.to_i128());
+            Ok(())
+        }
+        fn write_wide_operand(
+            &mut self,
+            addr: usize,
+            value: i128,
+            mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            let dest = self.write_target(addr, mode)?;
+            self.journal_write(dest)?;
+            self.store_wide(dest, value)?;
+            self.operand_written(dest, value);
+            Ok(())
+        }
+        fn journal_write(&mut self, dest: usize) -> Result<(), IntcodeError> {
+            if self.journal.is_some() {
+                let previous = self.fetch_cell(dest)?;
+                let wide = self.memory.wide(dest);
+                if let Some(journal) = &mut self.journal {
+                    journal.write(dest, previous, wide);
                 }
             }
+            Ok(())
+        }
+        fn operand_written(&mut self, dest: usize, value: i128) {
+            if let Some(tracer) = &mut self.tracer {
+                tracer.write(dest, value);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.write();
+            }
         }
-        fn add(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode, p3_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            self.write_mem_loc(self.memory[self.ip + 3] as usize, a + b, p3_mode);
+        fn add(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+            p3_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            if OverflowPolicy::Widened == self.overflow_policy {
+                return self.widened(Arithmetic::Add, p1_mode, p2_mode, p3_mode);
+            }
+            let a = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            let b = self.read_mem_loc(self.ip + 2, p2_mode)?;
+            let sum = self.arithmetic(Arithmetic::Add, a, b)?;
+            self.write_mem_loc(self.ip + 3, sum, p3_mode)?;
+            self.ip += 4;
+            Ok(())
+        }
+        fn multiply(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+            p3_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            if OverflowPolicy::Widened == self.overflow_policy {
+                return self.widened(Arithmetic::Multiply, p1_mode, p2_mode, p3_mode);
+            }
+            let a = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            let b = self.read_mem_loc(self.ip + 2, p2_mode)?;
+            let product = self.arithmetic(Arithmetic::Multiply, a, b)?;
+            self.write_mem_loc(self.ip + 3, product, p3_mode)?;
             self.ip += 4;
+            Ok(())
         }
-        fn multiply(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode, p3_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            self.write_mem_loc(self.memory[self.ip + 3] as usize, a * b, p3_mode);
+        // Add or multiply in i128, which only faults if i128 itself overflows
+        fn widened(
+            &mut self,
+            op: Arithmetic,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+            p3_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            let a = self.read_wide(self.ip + 1, p1_mode)?;
+            let b = self.read_wide(self.ip + 2, p2_mode)?;
+            let result = a
+                .checked(op, b)
+                .ok_or(IntcodeError::ArithmeticOverflow { addr: self.ip })?;
+            if W::from_i128(result).is_none() {
+                if let Some(profiler) = &mut self.profiler {
+                    profiler.overflow();
+                }
+            }
+            self.write_wide_operand(self.ip + 3, result, p3_mode)?;
             self.ip += 4;
+            Ok(())
         }
-        fn input(&mut self, p1_mode: MemoryMode) {
-            if self.input.is_some() {
-                let input = self.input.clone().unwrap();
-                self.write_mem_loc(self.memory[self.ip + 1] as usize, input, p1_mode);
+        // Applies `op` under the overflow policy
+        pub(super) fn arithmetic(&mut self, op: Arithmetic, a: W, b: W) -> Result<W, IntcodeError> {
+            if let Some(value) = a.checked(op, b) {
+                return Ok(value);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.overflow();
+            }
+            match self.overflow_policy {
+                OverflowPolicy::Wrapping => Ok(a.wrapping(op, b)),
+                OverflowPolicy::Saturating => Ok(a.saturating(op, b)),
+                // Outside the interpreter there is no cell to keep the exact value in
+                OverflowPolicy::Checked | OverflowPolicy::Widened => {
+                    Err(IntcodeError::ArithmeticOverflow { addr: self.ip })
+                }
+            }
+        }
+        fn input(&mut self, p1_mode: MemoryMode) -> Result<(), IntcodeError> {
+            if let Some(&input) = self.input.front() {
+                if let Some(tracer) = &mut self.tracer {
+                    tracer.input(input.to_i128());
+                }
+                if let Some(journal) = &mut self.journal {
+                    journal.input(input);
+                }
+                if let Some(profiler) = &mut self.profiler {
+                    profiler.input();
+                }
+                self.write_mem_loc(self.ip + 1, input, p1_mode)?;
                 self.ip += 2;
-                // Flush input buffer
-                self.input = None
+                // Only consume the input once the write has succeeded
+                self.input.pop_front();
+            } else if IntcodeState::WaitingForInput == self.state {
+                // Stepped again without anyone providing the input
+                return Err(IntcodeError::InputUnderflow { addr: self.ip });
             } else {
                 self.state = IntcodeState::WaitingForInput;
             }
+            Ok(())
         }
-        fn output(&mut self, p1_mode: MemoryMode) {
-            let src: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            self.output.push(src);
+        fn output(&mut self, p1_mode: MemoryMode) -> Result<(), IntcodeError> {
+            let src = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            self.output.push_back(src);
+            if let Some(tracer) = &mut self.tracer {
+                tracer.output(src.to_i128());
+            }
+            if let Some(journal) = &mut self.journal {
+                journal.output(src);
+            }
+            if let Some(profiler) = &mut self.profiler {
+                profiler.output();
+            }
             self.ip += 2;
+            self.state = IntcodeState::OutputReady;
+            Ok(())
         }
-        fn jump_if_true(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            if 0 != a {
-                self.ip = b as usize;
+        fn jump_if_true(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            let a = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            let b = self.read_mem_loc(self.ip + 2, p2_mode)?;
+            if W::ZERO != a {
+                self.ip = self.to_address(b)?;
             } else {
                 self.ip += 3;
             }
+            Ok(())
         }
-        fn jump_if_false(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            if 0 == a {
-                self.ip = b as usize;
+        fn jump_if_false(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            let a = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            let b = self.read_mem_loc(self.ip + 2, p2_mode)?;
+            if W::ZERO == a {
+                self.ip = self.to_address(b)?;
             } else {
                 self.ip += 3;
             }
+            Ok(())
         }
-        fn less_than(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode, p3_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            if a < b {
-                self.write_mem_loc(self.memory[self.ip + 3] as usize, 1, p3_mode);
+        fn less_than(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+            p3_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            if Ordering::Less == self.compare(p1_mode, p2_mode)? {
+                self.write_mem_loc(self.ip + 3, W::ONE, p3_mode)?;
             } else {
-                self.write_mem_loc(self.memory[self.ip + 3] as usize, 0, p3_mode);
+                self.write_mem_loc(self.ip + 3, W::ZERO, p3_mode)?;
             }
             self.ip += 4;
+            Ok(())
         }
-        fn equal(&mut self, p1_mode: MemoryMode, p2_mode: MemoryMode, p3_mode: MemoryMode) {
-            let a: isize = self.read_mem_loc(self.ip + 1, p1_mode);
-            let b: isize = self.read_mem_loc(self.ip + 2, p2_mode);
-            if a == b {
-                self.write_mem_loc(self.memory[self.ip + 3] as usize, 1, p3_mode);
+        fn equal(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+            p3_mode: MemoryMode,
+        ) -> Result<(), IntcodeError> {
+            if Ordering::Equal == self.compare(p1_mode, p2_mode)? {
+                self.write_mem_loc(self.ip + 3, W::ONE, p3_mode)?;
             } else {
-                self.write_mem_loc(self.memory[self.ip + 3] as usize, 0, p3_mode);
+                self.write_mem_loc(self.ip + 3, W::ZERO, p3_mode)?;
             }
             self.ip += 4;
+            Ok(())
         }
-        fn set_relative_base(&mut self, p1_mode: MemoryMode) {
-            self.relative_base = self.relative_base + self.read_mem_loc(self.ip + 1, p1_mode);
+        // Wide cells compare by their exact value
+        fn compare(
+            &mut self,
+            p1_mode: MemoryMode,
+            p2_mode: MemoryMode,
+        ) -> Result<Ordering, IntcodeError> {
+            if OverflowPolicy::Widened == self.overflow_policy {
+                let a = self.read_wide(self.ip + 1, p1_mode)?;
+                let b = self.read_wide(self.ip + 2, p2_mode)?;
+                return Ok(a.cmp(&b));
+            }
+            let a = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            let b = self.read_mem_loc(self.ip + 2, p2_mode)?;
+            Ok(a.cmp(&b))
+        }
+        fn set_relative_base(&mut self, p1_mode: MemoryMode) -> Result<(), IntcodeError> {
+            let offset = self.read_mem_loc(self.ip + 1, p1_mode)?;
+            self.relative_base = self
+                .relative_base
+                .checked(Arithmetic::Add, offset)
+                .ok_or(IntcodeError::ArithmeticOverflow { addr: self.ip })?;
             self.ip += 2;
+            Ok(())
         }
     }
 }
 
 #[cfg(test)]
+#[allow(deprecated)] // Most tests still read outputs by index
 mod intcode_tests {
     use super::*;
 
@@ -207,63 +1042,63 @@ mod intcode_tests {
     fn day_02_opcodes_test_addition() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![1, 0, 0, 0, 99]);
-        intcode.run();
-        assert_eq!(intcode.peek(0), 2);
+        intcode.run().unwrap();
+        assert_eq!(intcode.peek(0).unwrap(), 2);
     }
     #[test]
     fn day_02_opcodes_test_multiplication() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![2, 3, 0, 3, 99]);
-        intcode.run();
-        assert_eq!(intcode.peek(3), 6);
+        intcode.run().unwrap();
+        assert_eq!(intcode.peek(3).unwrap(), 6);
     }
     #[test]
     fn day_02_opcodes_small_program() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![2, 4, 4, 5, 99, 0]);
-        intcode.run();
-        assert_eq!(intcode.peek(5), 9801);
+        intcode.run().unwrap();
+        assert_eq!(intcode.peek(5).unwrap(), 9801);
     }
     #[test]
     fn test_peek() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![0, 1, 2]);
-        assert_eq!(intcode.peek(0), 0);
-        assert_eq!(intcode.peek(1), 1);
-        assert_eq!(intcode.peek(2), 2);
+        assert_eq!(intcode.peek(0).unwrap(), 0);
+        assert_eq!(intcode.peek(1).unwrap(), 1);
+        assert_eq!(intcode.peek(2).unwrap(), 2);
     }
     #[test]
     fn test_poke() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![0, 0, 0]);
-        intcode.poke(0, 0);
-        intcode.poke(1, 1);
-        intcode.poke(2, 2);
-        assert_eq!(intcode.peek(0), 0);
-        assert_eq!(intcode.peek(1), 1);
-        assert_eq!(intcode.peek(2), 2);
+        intcode.poke(0, 0).unwrap();
+        intcode.poke(1, 1).unwrap();
+        intcode.poke(2, 2).unwrap();
+        assert_eq!(intcode.peek(0).unwrap(), 0);
+        assert_eq!(intcode.peek(1).unwrap(), 1);
+        assert_eq!(intcode.peek(2).unwrap(), 2);
     }
     #[test]
     fn day_05_opcodes_read_in_set_out() {
         let mut intcode = intcode::Intcode::new();
         intcode.set_input(100);
         intcode.load_program(vec![3, 5, 4, 5, 99, 0]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 100);
     }
     #[test]
     fn day_05_test_program_1() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![1002, 4, 3, 4, 33]);
-        intcode.run();
-        assert_eq!(intcode.peek(4), 99);
+        intcode.run().unwrap();
+        assert_eq!(intcode.peek(4).unwrap(), 99);
     }
     #[test]
     fn day_05_test_program_2() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![1101, 100, -1, 4, 0]);
-        intcode.run();
-        assert_eq!(intcode.peek(4), 99);
+        intcode.run().unwrap();
+        assert_eq!(intcode.peek(4).unwrap(), 99);
     }
     #[test]
     fn day_05_test_program_position_mode_equality_input_0() {
@@ -271,7 +1106,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
         intcode.set_input(0);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -280,7 +1115,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
         intcode.set_input(8);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -289,7 +1124,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8]);
         intcode.set_input(10);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -298,7 +1133,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -307,7 +1142,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1108, -1, 8, 3, 4, 3, 99]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -316,7 +1151,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1108, -1, 8, 3, 4, 3, 99]);
         intcode.set_input(8);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -325,7 +1160,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1107, -1, 8, 3, 4, 3, 99]);
         intcode.set_input(10);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -334,7 +1169,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1107, -1, 8, 3, 4, 3, 99]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -344,7 +1179,7 @@ mod intcode_tests {
             3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9,
         ]);
         intcode.set_input(0);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -354,7 +1189,7 @@ mod intcode_tests {
             3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9,
         ]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -362,7 +1197,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]);
         intcode.set_input(0);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 0);
     }
     #[test]
@@ -370,7 +1205,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -382,7 +1217,7 @@ mod intcode_tests {
             20, 1105, 1, 46, 98, 99,
         ]);
         intcode.set_input(7);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 999);
     }
     #[test]
@@ -394,7 +1229,7 @@ mod intcode_tests {
             20, 1105, 1, 46, 98, 99,
         ]);
         intcode.set_input(8);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1000);
     }
     #[test]
@@ -406,7 +1241,7 @@ mod intcode_tests {
             20, 1105, 1, 46, 98, 99,
         ]);
         intcode.set_input(384);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1001);
     }
     #[test]
@@ -414,7 +1249,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]);
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -422,11 +1257,11 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]);
         // Should run until an input opcode is read
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.get_state(), intcode::IntcodeState::WaitingForInput);
         // Provide input and re-run
         intcode.set_input(3);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
@@ -437,16 +1272,16 @@ mod intcode_tests {
             3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
         ]);
         // Should run until an input opcode is read
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.get_state(), intcode::IntcodeState::WaitingForInput);
         // Provide input and re-run
         intcode.set_input(0);
         // Should run until an input opcode is read
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.get_state(), intcode::IntcodeState::WaitingForInput);
         // Provide input and re-run
         intcode.set_input(4);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 40);
     }
     #[test]
@@ -455,7 +1290,7 @@ mod intcode_tests {
         intcode.load_program(vec![
             109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
         ]);
-        intcode.run();
+        intcode.run().unwrap();
         // It's a quine!
         assert_eq!(intcode.read_output(0), 109);
         assert_eq!(intcode.read_output(1), 1);
@@ -478,63 +1313,63 @@ mod intcode_tests {
     fn day_09_test_part_1_program_2() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1219070632396864);
     }
     #[test]
     fn day_09_test_part_1_program_3() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![104, 1125899906842624, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1125899906842624);
     }
     #[test]
     fn day_09_test_part_1_testing_relative_additions() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 6, 21001, 9, 25, 1, 104, 0, 99, 49]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 74);
     }
     #[test]
     fn day_09_test_part_1_test_suite_1() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, -1, 4, 1, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), -1);
     }
     #[test]
     fn day_09_test_part_1_test_suite_2() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, -1, 104, 1, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 1);
     }
     #[test]
     fn day_09_test_part_1_test_suite_3() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, -1, 204, 1, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 109);
     }
     #[test]
     fn day_09_test_part_1_test_suite_4() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 1, 9, 2, 204, -6, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 204);
     }
     #[test]
     fn day_09_test_part_1_test_suite_5() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 1, 109, 9, 204, -6, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 204);
     }
     #[test]
     fn day_09_test_part_1_test_suite_6() {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 1, 209, -1, 204, -106, 99]);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 204);
     }
     #[test]
@@ -542,7 +1377,7 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 1, 3, 3, 204, 2, 99]);
         intcode.set_input(42);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 42);
     }
     #[test]
@@ -550,7 +1385,488 @@ mod intcode_tests {
         let mut intcode = intcode::Intcode::new();
         intcode.load_program(vec![109, 1, 203, 2, 204, 2, 99]);
         intcode.set_input(42);
-        intcode.run();
+        intcode.run().unwrap();
         assert_eq!(intcode.read_output(0), 42);
     }
+    #[test]
+    fn unknown_opcode_faults_instead_of_spinning() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![1101, 1, 1, 5, 42, 0, 99]);
+        let error = intcode::IntcodeError::UnknownOpcode { addr: 4, value: 42 };
+        assert_eq!(intcode.run(), Err(error.clone()));
+        assert_eq!(intcode.get_state(), intcode::IntcodeState::Faulted(error));
+    }
+    #[test]
+    fn faulted_machine_stays_faulted() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![42, 99]);
+        assert!(intcode.run().is_err());
+        intcode.set_input(1);
+        assert!(intcode.step().is_err());
+        assert!(intcode.run().is_err());
+    }
+    #[test]
+    fn halted_machine_stays_halted() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![1101, 1, 1, 5, 99, 0]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.step(), Ok(()));
+        assert_eq!(intcode.get_ip(), 4);
+        assert_eq!(intcode.peek(5).unwrap(), 2);
+        assert_eq!(intcode.get_state(), intcode::IntcodeState::Halted);
+    }
+    #[test]
+    fn invalid_mode_faults() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![301, 0, 0, 0, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::InvalidMode {
+                addr: 0,
+                value: 301
+            })
+        );
+    }
+    #[test]
+    fn negative_address_faults() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![4, -3, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::NegativeAddress {
+                addr: 0,
+                target: -3
+            })
+        );
+    }
+    #[test]
+    fn address_out_of_range_faults() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_memory_limit(Some(4096));
+        intcode.load_program(vec![4, 1_000_000, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::AddressOutOfRange {
+                addr: 0,
+                target: 1_000_000
+            })
+        );
+        assert!(intcode.peek(1_000_000).is_err());
+    }
+    #[test]
+    fn stepping_a_starved_machine_underflows() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![3, 5, 4, 5, 99, 0]);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::WaitingForInput));
+        assert_eq!(
+            intcode.step(),
+            Err(intcode::IntcodeError::InputUnderflow { addr: 0 })
+        );
+        // The machine is still usable once input arrives
+        intcode.set_input(7);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.read_output(0), 7);
+    }
+    #[test]
+    fn memory_grows_past_the_old_padding() {
+        let mut intcode = intcode::Intcode::new();
+        // Write 7 far past the old 4000 cell pad and echo it back
+        intcode.load_program(vec![1101, 3, 4, 1_000_000, 4, 1_000_000, 4, 2_000_000, 99]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.read_output(0), 7);
+        assert_eq!(intcode.read_output(1), 0);
+        assert_eq!(intcode.highest_address_touched(), Some(2_000_000));
+    }
+    #[test]
+    fn memory_limit_survives_reload() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_memory_limit(Some(8));
+        intcode.load_program(vec![1101, 1, 1, 8, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::AddressOutOfRange { addr: 0, target: 8 })
+        );
+    }
+    #[test]
+    fn inputs_are_consumed_in_order() {
+        let mut intcode = intcode::Intcode::new();
+        // Program taken from day 7 dual input example
+        intcode.load_program(vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ]);
+        intcode.push_inputs(vec![0, 4]);
+        assert_eq!(intcode.pending_inputs().len(), 2);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.read_output(0), 40);
+        assert!(intcode.pending_inputs().is_empty());
+    }
+    #[test]
+    fn waits_only_once_the_queue_is_empty() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![3, 9, 3, 10, 4, 9, 4, 10, 99, 0, 0]);
+        intcode.push_input(1);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::WaitingForInput));
+        intcode.push_input(2);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.read_output(0), 1);
+        assert_eq!(intcode.read_output(1), 2);
+        assert_eq!(intcode.output_at(1), Some(2));
+        assert_eq!(intcode.output_at(2), None);
+    }
+    #[test]
+    fn clear_inputs_drops_pending_values() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![3, 5, 4, 5, 99, 0]);
+        intcode.push_inputs(vec![5, 6, 7]);
+        intcode.clear_inputs();
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::WaitingForInput));
+    }
+    #[test]
+    fn pop_output_returns_values_in_emitted_order() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![104, 1, 104, 2, 99]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.output_len(), 2);
+        assert_eq!(intcode.last_output(), Some(2));
+        assert_eq!(intcode.pop_output(), Some(1));
+        assert_eq!(intcode.pop_output(), Some(2));
+        assert_eq!(intcode.pop_output(), None);
+        assert_eq!(intcode.last_output(), None);
+    }
+    #[test]
+    fn take_and_drain_outputs_empty_the_buffer() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![104, 1, 104, 2, 104, 3, 99]);
+        intcode.run().unwrap();
+        let triples: Vec<i64> = intcode.drain_outputs().collect();
+        assert_eq!(triples, vec![1, 2, 3]);
+        assert_eq!(intcode.output_len(), 0);
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![104, 4, 99]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![4]);
+        assert_eq!(intcode.take_outputs(), Vec::<i64>::new());
+    }
+    #[test]
+    fn run_until_output_yields_after_each_pair() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![104, 1, 104, 2, 104, 3, 104, 4, 99]);
+        assert_eq!(
+            intcode.run_until_output(2),
+            Ok(intcode::IntcodeState::OutputReady)
+        );
+        assert_eq!(intcode.take_outputs(), vec![1, 2]);
+        assert_eq!(
+            intcode.run_until_output(2),
+            Ok(intcode::IntcodeState::OutputReady)
+        );
+        assert_eq!(intcode.take_outputs(), vec![3, 4]);
+        assert_eq!(
+            intcode.run_until_output(2),
+            Ok(intcode::IntcodeState::Halted)
+        );
+    }
+    #[test]
+    fn run_until_output_stops_for_input() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![3, 5, 4, 5, 99, 0]);
+        assert_eq!(
+            intcode.run_until_output(1),
+            Ok(intcode::IntcodeState::WaitingForInput)
+        );
+        intcode.push_input(9);
+        assert_eq!(
+            intcode.run_until_output(1),
+            Ok(intcode::IntcodeState::OutputReady)
+        );
+        assert_eq!(intcode.pop_output(), Some(9));
+    }
+    #[test]
+    fn run_steps_executes_a_bounded_number_of_instructions() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![1101, 1, 1, 0, 1101, 2, 2, 0, 99]);
+        assert_eq!(intcode.run_steps(1), Ok(intcode::IntcodeState::Ready));
+        assert_eq!(intcode.peek(0).unwrap(), 2);
+        assert_eq!(intcode.run_steps(0), Ok(intcode::IntcodeState::Ready));
+        assert_eq!(intcode.run_steps(5), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.peek(0).unwrap(), 4);
+    }
+    #[test]
+    fn run_continues_through_output_ready() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![104, 1, 104, 2, 99]);
+        intcode.step().unwrap();
+        assert_eq!(intcode.get_state(), intcode::IntcodeState::OutputReady);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.output_len(), 2);
+    }
+    #[test]
+    fn run_with_budget_stops_runaway_programs() {
+        let mut intcode = intcode::Intcode::new();
+        // Jumps to itself forever
+        intcode.load_program(vec![1105, 1, 0]);
+        assert_eq!(
+            intcode.run_with_budget(100),
+            Ok(intcode::RunOutcome::BudgetExhausted { steps: 100 })
+        );
+        assert_eq!(
+            intcode.run_with_budget(50),
+            Ok(intcode::RunOutcome::BudgetExhausted { steps: 150 })
+        );
+        assert_eq!(intcode.steps_executed(), 150);
+    }
+    #[test]
+    fn run_with_budget_reports_normal_stops() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![3, 5, 4, 5, 99, 0]);
+        assert_eq!(
+            intcode.run_with_budget(100),
+            Ok(intcode::RunOutcome::Stopped(
+                intcode::IntcodeState::WaitingForInput
+            ))
+        );
+        // The starved input does not count as a step
+        assert_eq!(intcode.steps_executed(), 0);
+        intcode.push_input(1);
+        assert_eq!(
+            intcode.run_with_budget(100),
+            Ok(intcode::RunOutcome::Stopped(intcode::IntcodeState::Halted))
+        );
+        assert_eq!(intcode.steps_executed(), 3);
+    }
+    #[test]
+    fn stepping_a_halted_machine_does_not_count() {
+        let mut intcode = intcode::Intcode::new();
+        intcode.load_program(vec![1101, 1, 1, 5, 99, 0]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.steps_executed(), 2);
+        intcode.step().unwrap();
+        assert_eq!(
+            intcode.run_with_budget(10),
+            Ok(intcode::RunOutcome::Stopped(intcode::IntcodeState::Halted))
+        );
+        assert_eq!(intcode.steps_executed(), 2);
+    }
+    #[test]
+    fn forks_branch_from_a_running_machine() {
+        let mut intcode = intcode::Intcode::new();
+        // Outputs 100 + its input
+        intcode.load_program(vec![104, 7, 3, 11, 1, 11, 12, 11, 4, 11, 99, 0, 100]);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::WaitingForInput));
+        let mut fork = intcode.fork();
+        assert_eq!(fork.get_ip(), 2);
+        assert_eq!(fork.pending_outputs(), &[7]);
+        intcode.push_input(1);
+        fork.push_input(2);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(fork.run(), Ok(intcode::IntcodeState::Halted));
+        assert_eq!(intcode.take_outputs(), vec![7, 101]);
+        assert_eq!(fork.take_outputs(), vec![7, 102]);
+        // Neither branch's write leaked into the other
+        assert_eq!(intcode.peek(11), Ok(101));
+        assert_eq!(fork.peek(11), Ok(102));
+        assert_eq!(fork.steps_executed(), intcode.steps_executed());
+    }
+    #[test]
+    fn strict_modes_fault_where_lenient_modes_carry_on() {
+        let cases = vec![
+            // Immediate mode write destination
+            (
+                vec![11101, 1, 1, 5, 99, 0],
+                intcode::IntcodeError::ImmediateWrite {
+                    addr: 0,
+                    value: 11101,
+                },
+            ),
+            // Mode for a second parameter output does not have
+            (
+                vec![1104, 5, 99],
+                intcode::IntcodeError::UnusedMode {
+                    addr: 0,
+                    value: 1104,
+                },
+            ),
+            // A fourth mode digit
+            (
+                vec![100104, 5, 99],
+                intcode::IntcodeError::InvalidMode {
+                    addr: 0,
+                    value: 100104,
+                },
+            ),
+        ];
+        for (program, error) in cases {
+            let mut intcode = intcode::Intcode::new();
+            intcode.load_program(program.clone());
+            assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+            let mut intcode = intcode::Intcode::new();
+            intcode.set_mode_policy(intcode::ModePolicy::Strict);
+            intcode.load_program(program);
+            assert_eq!(intcode.run(), Err(error));
+        }
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_mode_policy(intcode::ModePolicy::Strict);
+        intcode.load_program(vec![21101, 1, 1, 5, 204, 5, 99]);
+        assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+    }
+    #[test]
+    fn mode_diagnostics_list_each_misuse_once() {
+        // Three passes over an immediate write and a stray output mode
+        let mut program = vec![11101, 0, 0, 20, 1104, 7, 1001, 21, -1, 21, 1005, 21, 0, 99];
+        program.resize(22, 0);
+        program[21] = 3;
+        for cached in &[true, false] {
+            let mut intcode = intcode::Intcode::new();
+            intcode.set_decode_cache(*cached);
+            intcode.set_mode_diagnostics(true);
+            intcode.load_program(program.clone());
+            assert_eq!(intcode.run(), Ok(intcode::IntcodeState::Halted));
+            assert_eq!(intcode.take_outputs(), vec![7, 7, 7]);
+            assert_eq!(
+                intcode.mode_diagnostics(),
+                Some(
+                    &[
+                        intcode::IntcodeError::ImmediateWrite {
+                            addr: 0,
+                            value: 11101
+                        },
+                        intcode::IntcodeError::UnusedMode {
+                            addr: 4,
+                            value: 1104
+                        },
+                    ][..]
+                )
+            );
+        }
+    }
+    #[test]
+    fn overflow_policy_decides_add_and_multiply_results() {
+        // [7] * [8], then outputs [9]
+        let program = vec![2, 7, 8, 9, 4, 9, 99, i64::MAX, 2, 0];
+        let run = |policy| {
+            let mut intcode = intcode::Intcode::new();
+            intcode.set_overflow_policy(policy);
+            intcode.load_program(program.clone());
+            intcode.run().map(|_| intcode.last_output())
+        };
+        assert_eq!(run(intcode::OverflowPolicy::Wrapping), Ok(Some(-2)));
+        assert_eq!(run(intcode::OverflowPolicy::Saturating), Ok(Some(i64::MAX)));
+        assert_eq!(
+            run(intcode::OverflowPolicy::Checked),
+            Err(intcode::IntcodeError::ArithmeticOverflow { addr: 0 })
+        );
+        // The product is kept, outputting it is what cannot fit
+        assert_eq!(
+            run(intcode::OverflowPolicy::Widened),
+            Err(intcode::IntcodeError::ArithmeticOverflow { addr: 4 })
+        );
+    }
+    #[test]
+    fn widened_cells_keep_their_exact_value_for_arithmetic() {
+        // [19] = [17] * [18]; [21] = [17] < [19]; [19] += [20]; out [19]; out [21]
+        let program = vec![
+            2,
+            17,
+            18,
+            19,
+            7,
+            17,
+            19,
+            21,
+            1,
+            19,
+            20,
+            19,
+            4,
+            19,
+            4,
+            21,
+            99, // Code
+            i64::MAX,
+            2,
+            0,
+            -i64::MAX,
+            0,
+        ];
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_overflow_policy(intcode::OverflowPolicy::Checked);
+        intcode.load_program(program.clone());
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::ArithmeticOverflow { addr: 0 })
+        );
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_overflow_policy(intcode::OverflowPolicy::Widened);
+        intcode.load_program(program);
+        intcode.run_steps(1).unwrap();
+        assert_eq!(intcode.peek(19), Ok(i64::MAX));
+        intcode.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![i64::MAX, 1]);
+    }
+    #[test]
+    fn overflow_policy_only_matters_on_overflow() {
+        // i64::MIN + -1 would overflow, i64::MIN + 1 does not
+        let program = vec![1101, i64::MIN, 1, 9, 4, 9, 99, 0, 0, 0];
+        for policy in &[
+            intcode::OverflowPolicy::Wrapping,
+            intcode::OverflowPolicy::Checked,
+            intcode::OverflowPolicy::Saturating,
+            intcode::OverflowPolicy::Widened,
+        ] {
+            let mut intcode = intcode::Intcode::new();
+            intcode.set_overflow_policy(*policy);
+            intcode.load_program(program.clone());
+            intcode.run().unwrap();
+            assert_eq!(intcode.last_output(), Some(i64::MIN + 1));
+        }
+        let mut intcode = intcode::Intcode::new();
+        intcode.set_overflow_policy(intcode::OverflowPolicy::Saturating);
+        intcode.load_program(vec![1101, i64::MIN, -1, 9, 4, 9, 99, 0, 0, 0]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.last_output(), Some(i64::MIN));
+    }
+    #[test]
+    fn narrow_words_overflow_at_their_own_width() {
+        let mut intcode = intcode::Intcode::<i32>::default();
+        intcode.load_program(vec![1002, 7, 2, 7, 4, 7, 99, i32::MAX]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.last_output(), Some(-2));
+
+        let mut intcode = intcode::Intcode::<i32>::default();
+        intcode.set_overflow_policy(intcode::OverflowPolicy::Checked);
+        intcode.load_program(vec![1002, 7, 2, 7, 4, 7, 99, i32::MAX]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::ArithmeticOverflow { addr: 0 })
+        );
+    }
+    #[test]
+    fn wide_words_hold_values_past_i64() {
+        let mut intcode = intcode::Intcode::<i128>::default();
+        intcode.load_program(vec![1102, i64::MAX as i128, 4, 7, 4, 7, 99, 0]);
+        intcode.run().unwrap();
+        assert_eq!(intcode.last_output(), Some(i64::MAX as i128 * 4));
+    }
+    #[test]
+    fn address_conversion_is_checked() {
+        let mut intcode = intcode::Intcode::<i128>::default();
+        intcode.load_program(vec![1105, 1, 1 << 100]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::AddressOverflow {
+                addr: 0,
+                target: 1 << 100
+            })
+        );
+        let mut intcode = intcode::Intcode::<i32>::default();
+        // Relative base plus offset is summed without overflowing the word
+        intcode.load_program(vec![109, i32::MIN, 204, -1, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(intcode::IntcodeError::NegativeAddress {
+                addr: 2,
+                target: i32::MIN as i128 - 1
+            })
+        );
+    }
 }
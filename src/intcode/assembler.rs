@@ -0,0 +1,315 @@
+use super::disassembler::mnemonic;
+use super::intcode::{Intcode, MemoryMode};
+use std::collections::HashMap;
+use std::fmt;
+
+// Assembly syntax, one statement per line, ';' starts a comment:
+//
+//   start:  in [n]            ; position mode operand
+//           add [n], #-1, [n] ; immediate operand
+//           arb #10
+//           out [rb-2]        ; relative operand
+//           jnz [n], #start   ; labels resolve to their address
+//           hlt
+//   n:      .data 0
+//
+// A numeric `12:` prefix is an address check, which lets disassembler
+// listings assemble straight back into their program.
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AssembleError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Number(i64),
+    Label(String, i64), // Label plus an offset
+}
+
+#[derive(Debug, Clone)]
+enum Statement {
+    Code {
+        opcode: isize,
+        operands: Vec<(MemoryMode, Value)>,
+    },
+    Data(Vec<Value>),
+}
+
+impl Statement {
+    fn size(&self) -> usize {
+        match self {
+            Statement::Code { operands, .. } => 1 + operands.len(),
+            Statement::Data(values) => values.len(),
+        }
+    }
+}
+
+fn error(line: usize, message: String) -> AssembleError {
+    AssembleError { line, message }
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || '_' == c => {
+            chars.all(|c| c.is_ascii_alphanumeric() || '_' == c)
+        }
+        _ => false,
+    }
+}
+
+fn opcode_for(name: &str) -> Option<isize> {
+    (1..=99).find(|&opcode| mnemonic(opcode) == Some(name))
+}
+
+fn parse_value(line: usize, text: &str) -> Result<Value, AssembleError> {
+    let text = text.trim();
+    if let Ok(number) = text.parse() {
+        return Ok(Value::Number(number));
+    }
+    // label, label+offset or label-offset
+    let (name, offset) = match text.find(['+', '-']) {
+        Some(split) => {
+            let offset = text[split..].replace(' ', "");
+            let offset = offset
+                .trim_start_matches('+')
+                .parse()
+                .map_err(|_| error(line, format!("bad offset in '{}'", text)))?;
+            (text[..split].trim(), offset)
+        }
+        None => (text, 0),
+    };
+    if !is_label(name) {
+        return Err(error(
+            line,
+            format!("expected a number or label, got '{}'", text),
+        ));
+    }
+    Ok(Value::Label(name.to_string(), offset))
+}
+
+fn parse_operand(line: usize, text: &str) -> Result<(MemoryMode, Value), AssembleError> {
+    let text = text.trim();
+    if let Some(value) = text.strip_prefix('#') {
+        return Ok((MemoryMode::ImmediateMode, parse_value(line, value)?));
+    }
+    let inner = text
+        .strip_prefix('[')
+        .and_then(|t| t.strip_suffix(']'))
+        .ok_or_else(|| error(line, format!("bad operand '{}'", text)))?
+        .trim();
+    match inner.strip_prefix("rb") {
+        Some("") => Ok((MemoryMode::RelativeMode, Value::Number(0))),
+        Some(offset) if offset.starts_with(['+', '-']) => {
+            let offset = offset.trim_start_matches('+');
+            Ok((MemoryMode::RelativeMode, parse_value(line, offset)?))
+        }
+        _ => Ok((MemoryMode::PositionMode, parse_value(line, inner)?)),
+    }
+}
+
+fn parse_statement(line: usize, text: &str) -> Result<Statement, AssembleError> {
+    let (head, rest) = match text.find(char::is_whitespace) {
+        Some(split) => (&text[..split], text[split..].trim()),
+        None => (text, ""),
+    };
+    let args: Vec<&str> = if rest.is_empty() {
+        Vec::new()
+    } else {
+        rest.split(',').collect()
+    };
+    if ".data" == head {
+        let values = args
+            .iter()
+            .map(|arg| parse_value(line, arg))
+            .collect::<Result<Vec<Value>, AssembleError>>()?;
+        if values.is_empty() {
+            return Err(error(line, ".data needs at least one value".to_string()));
+        }
+        return Ok(Statement::Data(values));
+    }
+    let opcode =
+        opcode_for(head).ok_or_else(|| error(line, format!("unknown mnemonic '{}'", head)))?;
+    let expected = Intcode::parameter_count(opcode).unwrap();
+    if args.len() != expected {
+        return Err(error(
+            line,
+            format!("{} takes {} operands, got {}", head, expected, args.len()),
+        ));
+    }
+    let operands = args
+        .iter()
+        .map(|arg| parse_operand(line, arg))
+        .collect::<Result<Vec<(MemoryMode, Value)>, AssembleError>>()?;
+    Ok(Statement::Code { opcode, operands })
+}
+
+fn mode_digit(mode: MemoryMode) -> i64 {
+    match mode {
+        MemoryMode::PositionMode => 0,
+        MemoryMode::ImmediateMode => 1,
+        MemoryMode::RelativeMode => 2,
+    }
+}
+
+pub fn assemble(source: &str) -> Result<Vec<i64>, AssembleError> {
+    // Pass 1 - parse statements and place labels
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    let mut statements = Vec::new();
+    let mut addr = 0;
+    for (index, raw) in source.lines().enumerate() {
+        let line = index + 1;
+        let mut text = raw.split(';').next().unwrap().trim();
+        while let Some(split) = text.find(':') {
+            let name = text[..split].trim();
+            if let Ok(expected) = name.parse::<usize>() {
+                if expected != addr {
+                    return Err(error(
+                        line,
+                        format!("address {} does not match {}", expected, addr),
+                    ));
+                }
+            } else if !is_label(name) {
+                return Err(error(line, format!("bad label '{}'", name)));
+            } else if labels.insert(name.to_string(), addr).is_some() {
+                return Err(error(line, format!("duplicate label '{}'", name)));
+            }
+            text = text[split + 1..].trim();
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let statement = parse_statement(line, text)?;
+        addr += statement.size();
+        statements.push((line, statement));
+    }
+    // Pass 2 - resolve labels and emit
+    let resolve = |line: usize, value: &Value| -> Result<i64, AssembleError> {
+        match value {
+            Value::Number(number) => Ok(*number),
+            Value::Label(name, offset) => labels
+                .get(name)
+                .map(|&addr| addr as i64 + offset)
+                .ok_or_else(|| error(line, format!("undefined label '{}'", name))),
+        }
+    };
+    let mut program = Vec::with_capacity(addr);
+    for (line, statement) in statements {
+        match statement {
+            Statement::Code { opcode, operands } => {
+                let mut instruction = opcode as i64;
+                for (k, (mode, _)) in operands.iter().enumerate() {
+                    instruction += mode_digit(*mode) * 10i64.pow(k as u32 + 2);
+                }
+                program.push(instruction);
+                for (_, value) in operands.iter() {
+                    program.push(resolve(line, value)?);
+                }
+            }
+            Statement::Data(values) => {
+                for value in values.iter() {
+                    program.push(resolve(line, value)?);
+                }
+            }
+        }
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod assembler_tests {
+    use super::*;
+    use crate::intcode::disassembler::listing;
+
+    #[test]
+    fn assemble_with_labels_and_data() {
+        let source = "
+            ; Count down from the input, printing each value
+            start: in [n]
+            loop:  out [n]
+                   add [n], #-1, [n]
+                   jnz [n], #loop
+                   hlt
+            n:     .data 0
+        ";
+        let program = assemble(source).unwrap();
+        assert_eq!(
+            program,
+            vec![3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+        );
+        let mut intcode = Intcode::new();
+        intcode.load_program(program);
+        intcode.push_input(3);
+        intcode.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![3, 2, 1]);
+    }
+    #[test]
+    fn assemble_relative_operands() {
+        let program =
+            assemble("arb #1\nout [rb-1]\nadd [rb], [rb+2], [rb+label]\nlabel: hlt").unwrap();
+        assert_eq!(program, vec![109, 1, 204, -1, 22201, 0, 2, 8, 99]);
+    }
+    #[test]
+    fn assemble_matches_hand_written_programs() {
+        assert_eq!(
+            assemble("add #100, #-1, [4]\n.data 0").unwrap(),
+            vec![1101, 100, -1, 4, 0]
+        );
+        assert_eq!(
+            assemble("mul [4], #3, [4]\n.data 33").unwrap(),
+            vec![1002, 4, 3, 4, 33]
+        );
+    }
+    #[test]
+    fn round_trips_existing_test_programs() {
+        let programs = vec![
+            vec![1, 0, 0, 0, 99],
+            vec![2, 4, 4, 5, 99, 0],
+            vec![1002, 4, 3, 4, 33],
+            vec![1101, 100, -1, 4, 0],
+            vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8],
+            vec![3, 3, 1108, -1, 8, 3, 4, 3, 99],
+            vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9],
+            vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1],
+            vec![
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ],
+            vec![
+                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+            ],
+            vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0],
+            vec![104, 1125899906842624, 99],
+            vec![109, 1, 203, 2, 204, 2, 99],
+        ];
+        for program in programs {
+            assert_eq!(assemble(&listing(&program)).unwrap(), program);
+        }
+    }
+    #[test]
+    fn errors_carry_line_numbers() {
+        let unknown = assemble("hlt\nfoo [1]").unwrap_err();
+        assert_eq!(unknown.line, 2);
+        assert!(unknown.message.contains("foo"));
+        let arity = assemble("\n\nadd [1], [2]").unwrap_err();
+        assert_eq!(arity.line, 3);
+        let undefined = assemble("jnz #1, #nowhere").unwrap_err();
+        assert_eq!(undefined.line, 1);
+        assert_eq!(undefined.to_string(), "line 1: undefined label 'nowhere'");
+        let duplicate = assemble("a: hlt\na: hlt").unwrap_err();
+        assert_eq!(duplicate.line, 2);
+        let misplaced = assemble("0: hlt\n5: hlt").unwrap_err();
+        assert_eq!(misplaced.line, 2);
+    }
+}
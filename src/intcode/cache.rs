@@ -0,0 +1,107 @@
+use super::intcode::MemoryMode;
+use std::fmt;
+
+// Addresses past this are decoded every time rather than growing the table
+pub const CACHE_LIMIT: usize = 1 << 16;
+
+pub type Decoded = (isize, [MemoryMode; 3]);
+
+// Opcode and modes per address, so each instruction is only decoded once.
+// Entries are dropped when their cell is written to keep self-modifying code correct.
+#[derive(Clone, Default)]
+pub struct DecodeCache {
+    entries: Vec<Option<Decoded>>,
+    hits: u64,
+    misses: u64,
+}
+
+impl fmt::Debug for DecodeCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DecodeCache")
+            .field(
+                "cached",
+                &self.entries.iter().filter(|e| e.is_some()).count(),
+            )
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+impl DecodeCache {
+    pub fn new() -> DecodeCache {
+        DecodeCache::default()
+    }
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+    pub fn get(&mut self, addr: usize) -> Option<Decoded> {
+        let decoded = self.entries.get(addr).copied().flatten();
+        match decoded {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        decoded
+    }
+    pub fn insert(&mut self, addr: usize, decoded: Decoded) {
+        if addr >= CACHE_LIMIT {
+            return;
+        }
+        if addr >= self.entries.len() {
+            self.entries.resize(addr + 1, None);
+        }
+        self.entries[addr] = Some(decoded);
+    }
+    pub fn invalidate(&mut self, addr: usize) {
+        if let Some(entry) = self.entries.get_mut(addr) {
+            *entry = None;
+        }
+    }
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+    use crate::intcode::intcode::Intcode;
+
+    const ADD: Decoded = (1, [MemoryMode::PositionMode; 3]);
+
+    #[test]
+    fn entries_are_dropped_on_invalidate() {
+        let mut cache = DecodeCache::new();
+        assert_eq!(cache.get(4), None);
+        cache.insert(4, ADD);
+        assert_eq!(cache.get(4), Some(ADD));
+        cache.invalidate(4);
+        assert_eq!(cache.get(4), None);
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        cache.insert(CACHE_LIMIT, ADD);
+        assert_eq!(cache.get(CACHE_LIMIT), None);
+    }
+    #[test]
+    fn self_modifying_code_sees_its_writes() {
+        // Outputs a counter, then rewrites that output into an immediate one
+        // before looping back round to it
+        let mut program = vec![
+            1001, 30, 1, 30, 4, 30, 1008, 30, 2, 31, 1005, 31, 20, 1101, 0, 104, 4, 1105, 1, 0, 99,
+        ];
+        program.resize(32, 0);
+        let mut intcode = Intcode::new();
+        intcode.load_program(program.clone());
+        intcode.run().unwrap();
+        let mut uncached = Intcode::new();
+        uncached.set_decode_cache(false);
+        uncached.load_program(program);
+        uncached.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![1, 30]);
+        assert_eq!(uncached.take_outputs(), vec![1, 30]);
+        assert!(intcode.decode_cache().unwrap().hits() > 0);
+        assert!(uncached.decode_cache().is_none());
+    }
+}
@@ -0,0 +1,439 @@
+use super::disassembler::{decode_at, Instruction, Operand};
+use super::intcode::MemoryMode;
+use super::word::Word;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use std::ops::Range;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum EdgeKind {
+    Fallthrough, // On to the next instruction, including a branch not taken
+    Jump,        // Branch taken to an immediate target
+}
+
+// Edges run between the start addresses of basic blocks
+#[derive(Debug, PartialEq, Clone)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub kind: EdgeKind,
+}
+
+// A jump whose target is only known at run time, or lies outside the image
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnresolvedJump {
+    pub block: usize,
+    pub addr: usize,
+    pub target: Operand,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BasicBlock {
+    pub start: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl BasicBlock {
+    // One past the last cell of the block
+    pub fn end(&self) -> usize {
+        let last = self.instructions.last().unwrap();
+        last.addr() + last.size()
+    }
+}
+
+// Where execution can go after an instruction, as far as the image alone tells
+struct Flow {
+    next: Option<usize>,
+    jump: Option<usize>,
+    unresolved: Option<Operand>,
+}
+
+fn flow_of(instruction: &Instruction, len: usize) -> Flow {
+    let (addr, opcode, operands) = match instruction {
+        Instruction::Code {
+            addr,
+            opcode,
+            operands,
+        } => (*addr, *opcode, operands),
+        Instruction::Data { .. } => unreachable!("data has no control flow"),
+    };
+    let next = addr + instruction.size();
+    match opcode {
+        99 => Flow {
+            next: None,
+            jump: None,
+            unresolved: None,
+        },
+        5 | 6 => {
+            let (condition, target) = (operands[0], operands[1]);
+            // An immediate condition makes the branch always or never taken
+            let (taken, not_taken) = match condition.mode {
+                MemoryMode::ImmediateMode => {
+                    let taken = (5 == opcode) == (0 != condition.value);
+                    (taken, !taken)
+                }
+                _ => (true, true),
+            };
+            let resolved = match target.mode {
+                MemoryMode::ImmediateMode => usize::try_from(target.value)
+                    .ok()
+                    .filter(|&target| target < len),
+                _ => None,
+            };
+            Flow {
+                next: Some(next).filter(|_| not_taken),
+                jump: resolved.filter(|_| taken),
+                unresolved: Some(target).filter(|_| taken && resolved.is_none()),
+            }
+        }
+        _ => Flow {
+            next: Some(next),
+            jump: None,
+            unresolved: None,
+        },
+    }
+}
+
+// The value an add or multiply of two immediates stores, which is how return
+// addresses and function pointers get stored before an indirect jump
+pub fn stored_constant(instruction: &Instruction) -> Option<i128> {
+    let (opcode, operands) = match instruction {
+        Instruction::Code {
+            opcode, operands, ..
+        } => (*opcode, operands),
+        Instruction::Data { .. } => return None,
+    };
+    let immediate = |k: usize| {
+        Some(operands[k].value).filter(|_| MemoryMode::ImmediateMode == operands[k].mode)
+    };
+    match opcode {
+        1 => immediate(0)?.checked_add(immediate(1)?),
+        2 => immediate(0)?.checked_mul(immediate(1)?),
+        _ => None,
+    }
+}
+
+// Addresses worth an entry of their own beyond what immediate jumps reach:
+// inputs, where a paused machine resumes, and stored constants. Hints that
+// turn out not to be code only cost a few extra blocks.
+fn entry_hint(instruction: &Instruction, len: usize) -> Option<usize> {
+    let hint = match instruction {
+        Instruction::Code { addr, opcode, .. } if 3 == *opcode => Some(*addr as i128),
+        _ => stored_constant(instruction),
+    };
+    usize::try_from(hint?).ok().filter(|&hint| hint < len)
+}
+
+// Static view of a program: only code reachable from the entry points through
+// immediate jumps is followed, everything else is treated as data. Self
+// modifying code and returns through the stack are beyond what it can see,
+// `explore` guesses at the latter.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ControlFlowGraph {
+    pub blocks: BTreeMap<usize, BasicBlock>,
+    pub edges: Vec<Edge>,
+    pub unresolved: Vec<UnresolvedJump>,
+    pub data: Vec<Range<usize>>,
+}
+
+impl ControlFlowGraph {
+    pub fn build<W: Word>(program: &[W]) -> ControlFlowGraph {
+        ControlFlowGraph::build_from(program, &[0])
+    }
+    // Extra entries let callers add code only reached through indirect jumps
+    pub fn build_from<W: Word>(program: &[W], entries: &[usize]) -> ControlFlowGraph {
+        let mut instructions = BTreeMap::new();
+        let mut leaders = BTreeSet::new();
+        let mut pending = Vec::new();
+        for &entry in entries.iter().filter(|&&entry| entry < program.len()) {
+            leaders.insert(entry);
+            pending.push(entry);
+        }
+        while let Some(addr) = pending.pop() {
+            if instructions.contains_key(&addr) {
+                continue;
+            }
+            let instruction = decode_at(program, addr);
+            if let Instruction::Data { .. } = instruction {
+                continue;
+            }
+            let flow = flow_of(&instruction, program.len());
+            if let Some(target) = flow.jump {
+                leaders.insert(target);
+                pending.push(target);
+            }
+            if let Some(next) = flow.next.filter(|&next| next < program.len()) {
+                // Code after a branch starts a block of its own
+                if flow.jump.is_some() || flow.unresolved.is_some() {
+                    leaders.insert(next);
+                }
+                pending.push(next);
+            }
+            instructions.insert(addr, instruction);
+        }
+
+        let mut graph = ControlFlowGraph {
+            blocks: BTreeMap::new(),
+            edges: Vec::new(),
+            unresolved: Vec::new(),
+            data: Vec::new(),
+        };
+        for &start in leaders.iter().filter(|a| instructions.contains_key(a)) {
+            let mut block = BasicBlock {
+                start,
+                instructions: Vec::new(),
+            };
+            let mut addr = start;
+            let flow = loop {
+                let instruction = instructions[&addr].clone();
+                let flow = flow_of(&instruction, program.len());
+                block.instructions.push(instruction);
+                match flow.next {
+                    Some(next)
+                        if flow.jump.is_none()
+                            && flow.unresolved.is_none()
+                            && !leaders.contains(&next)
+                            && instructions.contains_key(&next) =>
+                    {
+                        addr = next
+                    }
+                    _ => break flow,
+                }
+            };
+            if let Some(next) = flow.next.filter(|next| instructions.contains_key(next)) {
+                graph.edges.push(Edge {
+                    from: start,
+                    to: next,
+                    kind: EdgeKind::Fallthrough,
+                });
+            }
+            if let Some(target) = flow.jump.filter(|target| instructions.contains_key(target)) {
+                graph.edges.push(Edge {
+                    from: start,
+                    to: target,
+                    kind: EdgeKind::Jump,
+                });
+            }
+            if let Some(target) = flow.unresolved {
+                graph.unresolved.push(UnresolvedJump {
+                    block: start,
+                    addr: block.instructions.last().unwrap().addr(),
+                    target,
+                });
+            }
+            graph.blocks.insert(start, block);
+        }
+
+        // Cells no reachable instruction covers
+        let mut covered = vec![false; program.len()];
+        for instruction in instructions.values() {
+            let addr = instruction.addr();
+            covered[addr..addr + instruction.size()]
+                .iter_mut()
+                .for_each(|cell| *cell = true);
+        }
+        let mut addr = 0;
+        while addr < covered.len() {
+            let start = addr;
+            while addr < covered.len() && covered[addr] == covered[start] {
+                addr += 1;
+            }
+            if !covered[start] {
+                graph.data.push(start..addr);
+            }
+        }
+        graph
+    }
+    // Like build, but also follows the entry hints of the code found and any
+    // value in a data cell that could be an address, since it may be a jump
+    // table, until they turn up nothing new
+    pub fn explore<W: Word>(program: &[W]) -> ControlFlowGraph {
+        let mut entries = BTreeSet::new();
+        entries.insert(0);
+        loop {
+            let graph =
+                ControlFlowGraph::build_from(program, &entries.iter().copied().collect::<Vec<_>>());
+            let hints: BTreeSet<usize> = graph
+                .blocks
+                .values()
+                .flat_map(|block| block.instructions.iter())
+                .filter_map(|instruction| entry_hint(instruction, program.len()))
+                .chain(
+                    graph
+                        .data
+                        .iter()
+                        .flat_map(|range| program[range.clone()].iter())
+                        .filter_map(|value| value.to_address())
+                        .filter(|&addr| addr < program.len()),
+                )
+                .collect();
+            if hints.is_subset(&entries) {
+                return graph;
+            }
+            entries.extend(hints);
+        }
+    }
+    // The block holding the instruction that starts at `addr`
+    pub fn block_of(&self, addr: usize) -> Option<&BasicBlock> {
+        let (_, block) = self.blocks.range(..=addr).next_back()?;
+        block
+            .instructions
+            .iter()
+            .any(|instruction| addr == instruction.addr())
+            .then_some(block)
+    }
+    pub fn is_data(&self, addr: usize) -> bool {
+        self.data.iter().any(|range| range.contains(&addr))
+    }
+    // Graphviz source, render with e.g. `dot -Tsvg`
+    pub fn to_dot(&self) -> String {
+        let mut text = String::from("digraph intcode {\n");
+        text.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+        for block in self.blocks.values() {
+            let label: String = block
+                .instructions
+                .iter()
+                .map(|instruction| format!("{}\\l", instruction.to_string().trim_start()))
+                .collect();
+            text.push_str(&format!("    b{} [label=\"{}\"];\n", block.start, label));
+        }
+        for edge in &self.edges {
+            match edge.kind {
+                EdgeKind::Fallthrough => {
+                    text.push_str(&format!("    b{} -> b{};\n", edge.from, edge.to))
+                }
+                EdgeKind::Jump => text.push_str(&format!(
+                    "    b{} -> b{} [label=\"jump\"];\n",
+                    edge.from, edge.to
+                )),
+            }
+        }
+        for jump in &self.unresolved {
+            text.push_str(&format!(
+                "    u{} [shape=plaintext, label=\"?\"];\n",
+                jump.addr
+            ));
+            text.push_str(&format!(
+                "    b{} -> u{} [style=dashed, label=\"{}\"];\n",
+                jump.block, jump.addr, jump.target
+            ));
+        }
+        text.push_str("}\n");
+        text
+    }
+}
+
+#[cfg(test)]
+mod cfg_tests {
+    use super::*;
+
+    use crate::intcode::assembler::assemble;
+    #[test]
+    fn loops_split_at_their_targets() {
+        // Day 9 quine, one block looping on itself ahead of the halt
+        let program = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let graph = ControlFlowGraph::build(&program);
+        assert_eq!(
+            graph.blocks.keys().copied().collect::<Vec<_>>(),
+            vec![0, 15]
+        );
+        assert_eq!(graph.blocks[&0].end(), 15);
+        assert_eq!(
+            graph.edges,
+            vec![
+                Edge {
+                    from: 0,
+                    to: 15,
+                    kind: EdgeKind::Fallthrough
+                },
+                Edge {
+                    from: 0,
+                    to: 0,
+                    kind: EdgeKind::Jump
+                },
+            ]
+        );
+        assert!(graph.unresolved.is_empty());
+        assert!(graph.data.is_empty());
+        assert_eq!(graph.block_of(8).map(|b| b.start), Some(0));
+        assert_eq!(graph.block_of(9), None);
+    }
+    #[test]
+    fn unreachable_cells_are_data_and_indirect_jumps_unresolved() {
+        // Always jumps over two data cells, then returns through the stack
+        let program = vec![1105, 1, 5, 42, 43, 2106, 0, 0, 99];
+        let graph = ControlFlowGraph::build(&program);
+        assert_eq!(graph.blocks.keys().copied().collect::<Vec<_>>(), vec![0, 5]);
+        assert_eq!(
+            graph.edges,
+            vec![Edge {
+                from: 0,
+                to: 5,
+                kind: EdgeKind::Jump
+            }]
+        );
+        assert_eq!(
+            graph.unresolved,
+            vec![UnresolvedJump {
+                block: 5,
+                addr: 5,
+                target: Operand {
+                    mode: MemoryMode::RelativeMode,
+                    value: 0
+                }
+            }]
+        );
+        assert_eq!(graph.data, vec![3..5, 8..9]);
+        assert!(graph.is_data(4));
+        assert!(!graph.is_data(5));
+    }
+    #[test]
+    fn explore_follows_inputs_return_addresses_and_jump_tables() {
+        let program = assemble(
+            "
+                    add #ret, #0, [rb]
+                    jz #0, #sub
+            ret:    out #3
+                    in [x]
+                    jnz [x], [table]
+                    hlt
+            sub:    out #1
+                    jz #0, [rb]
+            table:  .data other
+            other:  out #2
+                    hlt
+            x:      .data 0
+            ",
+        )
+        .unwrap();
+        let graph = ControlFlowGraph::explore(&program);
+        assert_eq!(
+            graph.blocks.keys().copied().collect::<Vec<_>>(),
+            vec![0, 7, 9, 14, 15, 21]
+        );
+        assert_eq!(graph.data, vec![20..21, 24..25]);
+    }
+    #[test]
+    fn dot_export() {
+        // Outputs 1 for a non-zero input, 0 then 1 otherwise
+        let program = vec![3, 11, 1005, 11, 7, 104, 0, 104, 1, 99, 0, 0];
+        let graph = ControlFlowGraph::build(&program);
+        assert_eq!(graph.data, vec![10..12]);
+        assert_eq!(
+            graph.to_dot(),
+            "digraph intcode {\n\
+             \x20   node [shape=box, fontname=\"monospace\"];\n\
+             \x20   b0 [label=\"0: in [11]\\l2: jnz [11], #7\\l\"];\n\
+             \x20   b5 [label=\"5: out #0\\l\"];\n\
+             \x20   b7 [label=\"7: out #1\\l9: hlt\\l\"];\n\
+             \x20   b0 -> b5;\n\
+             \x20   b0 -> b7 [label=\"jump\"];\n\
+             \x20   b5 -> b7;\n\
+             }\n"
+        );
+        let indirect = ControlFlowGraph::build(&[106, 0, 0]).to_dot();
+        assert!(indirect.contains("    b0 -> u0 [style=dashed, label=\"[0]\"];\n"));
+    }
+}
@@ -0,0 +1,401 @@
+use super::disassembler::{decode_at, Instruction};
+use super::intcode::{Intcode, IntcodeState};
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+pub const HELP: &str = "\
+break <addr>        set a breakpoint (b)
+delete <addr>       remove a breakpoint
+breaks              list breakpoints
+step [n]            execute n instructions, default 1 (s)
+continue [n]        run to a breakpoint, halt, input wait or fault, at most
+                    n instructions, default 10000000 (c)
+regs                show ip, relative base and state (r)
+dump <from> [to]    show at most 4096 cells of memory, to is inclusive (x)
+poke <addr> <value> write a memory cell
+list [addr] [n]     disassemble n instructions, default at ip (l)
+input <v>[,v...]    queue input values (i)
+outputs             show pending outputs without consuming them (o)
+reset               reload the program and clear the machine
+help                show this text
+quit                leave the debugger (q)";
+
+// Keeps continue from hanging the session on a program that never stops
+const CONTINUE_LIMIT: u64 = 10_000_000;
+
+// Likewise for dump on a huge range, a screenful is all anyone reads anyway
+const DUMP_LIMIT: usize = 4096;
+
+// Command driven debugger, every command writes its report to `out` so a
+// session can be replayed from a script and compared as plain text.
+pub struct Debugger {
+    program: Vec<i64>,
+    machine: Intcode,
+    breakpoints: BTreeSet<usize>,
+}
+
+impl Debugger {
+    pub fn new(program: Vec<i64>) -> Debugger {
+        let mut machine = Intcode::new();
+        machine.load_program(program.clone());
+        Debugger {
+            program,
+            machine,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+    pub fn machine(&self) -> &Intcode {
+        &self.machine
+    }
+    // Returns false once the session should end
+    pub fn execute(&mut self, command: &str, out: &mut impl Write) -> io::Result<bool> {
+        let command = command.split('#').next().unwrap().trim();
+        let mut words = command.split_whitespace();
+        let name = match words.next() {
+            Some(name) => name,
+            None => return Ok(true),
+        };
+        let args: Vec<&str> = words.collect();
+        let result = match name {
+            "break" | "b" => self.set_breakpoint(&args, out),
+            "delete" => self.delete_breakpoint(&args, out),
+            "breaks" => self.list_breakpoints(out),
+            "step" | "s" => self.step(&args, out),
+            "continue" | "c" => self.continue_run(&args, out),
+            "regs" | "r" => self.registers(out),
+            "dump" | "x" => self.dump(&args, out),
+            "poke" => self.poke(&args, out),
+            "list" | "l" => self.list(&args, out),
+            "input" | "i" => self.input(&args, out),
+            "outputs" | "o" => self.outputs(out),
+            "reset" => self.reset(out),
+            "help" | "h" => writeln!(out, "{}", HELP).map_err(|e| e.to_string()),
+            "quit" | "q" => return Ok(false),
+            _ => Err(format!("unknown command '{}', try help", name)),
+        };
+        if let Err(message) = result {
+            writeln!(out, "error: {}", message)?;
+        }
+        Ok(true)
+    }
+    // Runs every line of a script, stopping early on quit
+    pub fn execute_script(&mut self, script: &str, out: &mut impl Write) -> io::Result<bool> {
+        for line in script.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            writeln!(out, "> {}", line.trim())?;
+            if !self.execute(line, out)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+    fn set_breakpoint(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let addr = parse_arg(args, 0, "address")?;
+        self.breakpoints.insert(addr);
+        writeln!(out, "breakpoint at {}", addr).map_err(|e| e.to_string())
+    }
+    fn delete_breakpoint(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let addr: usize = parse_arg(args, 0, "address")?;
+        if !self.breakpoints.remove(&addr) {
+            return Err(format!("no breakpoint at {}", addr));
+        }
+        writeln!(out, "deleted breakpoint at {}", addr).map_err(|e| e.to_string())
+    }
+    fn list_breakpoints(&self, out: &mut impl Write) -> Result<(), String> {
+        let addrs: Vec<String> = self.breakpoints.iter().map(|a| a.to_string()).collect();
+        writeln!(out, "breakpoints: {}", addrs.join(", ")).map_err(|e| e.to_string())
+    }
+    fn step(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let count = if args.is_empty() {
+            1
+        } else {
+            parse_arg(args, 0, "count")?
+        };
+        for _ in 0..count {
+            // Stepping again would only fault on the missing input
+            if IntcodeState::WaitingForInput == self.machine.get_state() {
+                writeln!(out, "waiting for input, queue some with input")
+                    .map_err(|e| e.to_string())?;
+                break;
+            }
+            self.show_instruction(self.machine.get_ip(), out)?;
+            if let Err(e) = self.machine.step() {
+                writeln!(out, "fault: {}", e).map_err(|e| e.to_string())?;
+                break;
+            }
+            if !self.is_running() {
+                break;
+            }
+        }
+        self.registers(out)
+    }
+    fn continue_run(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let limit = if args.is_empty() {
+            CONTINUE_LIMIT
+        } else {
+            parse_arg(args, 0, "limit")?
+        };
+        let mut steps = 0;
+        while self.is_running() {
+            if limit == steps {
+                writeln!(out, "stopped after {} steps", limit).map_err(|e| e.to_string())?;
+                break;
+            }
+            if let Err(e) = self.machine.step() {
+                writeln!(out, "fault: {}", e).map_err(|e| e.to_string())?;
+                break;
+            }
+            // A starved input instruction did not actually execute
+            if IntcodeState::WaitingForInput == self.machine.get_state() {
+                break;
+            }
+            steps += 1;
+            if self.breakpoints.contains(&self.machine.get_ip()) {
+                writeln!(out, "hit breakpoint at {}", self.machine.get_ip())
+                    .map_err(|e| e.to_string())?;
+                break;
+            }
+        }
+        writeln!(out, "{} steps", steps).map_err(|e| e.to_string())?;
+        self.registers(out)
+    }
+    fn registers(&self, out: &mut impl Write) -> Result<(), String> {
+        writeln!(
+            out,
+            "ip={} rb={} state={:?}",
+            self.machine.get_ip(),
+            self.machine.get_relative_base(),
+            self.machine.get_state()
+        )
+        .map_err(|e| e.to_string())
+    }
+    fn dump(&self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let from: usize = parse_arg(args, 0, "address")?;
+        let to: usize = if args.len() > 1 {
+            parse_arg(args, 1, "address")?
+        } else {
+            from
+        };
+        if to >= from && to - from >= DUMP_LIMIT {
+            return Err(format!("dump at most {} cells at a time", DUMP_LIMIT));
+        }
+        // Eight cells per row
+        for row in (from..=to).step_by(8) {
+            let mut line = format!("{:>6}:", row);
+            for addr in row..=to.min(row.saturating_add(7)) {
+                let value = self.machine.peek(addr).map_err(|e| e.to_string())?;
+                line.push_str(&format!(" {}", value));
+            }
+            writeln!(out, "{}", line).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+    fn poke(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let addr = parse_arg(args, 0, "address")?;
+        let value = parse_arg(args, 1, "value")?;
+        self.machine.poke(addr, value).map_err(|e| e.to_string())?;
+        writeln!(out, "[{}] = {}", addr, value).map_err(|e| e.to_string())
+    }
+    fn list(&self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let mut addr = if args.is_empty() {
+            self.machine.get_ip()
+        } else {
+            parse_arg(args, 0, "address")?
+        };
+        let count: usize = if args.len() > 1 {
+            parse_arg(args, 1, "count")?
+        } else {
+            5
+        };
+        for _ in 0..count {
+            let size = self.show_instruction(addr, out)?;
+            addr = match addr.checked_add(size) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(())
+    }
+    fn input(&mut self, args: &[&str], out: &mut impl Write) -> Result<(), String> {
+        let values = args
+            .join(" ")
+            .split(|c: char| ',' == c || c.is_whitespace())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.parse().map_err(|_| format!("bad input value '{}'", v)))
+            .collect::<Result<Vec<i64>, String>>()?;
+        if values.is_empty() {
+            return Err("input needs at least one value".to_string());
+        }
+        self.machine.push_inputs(values);
+        writeln!(out, "pending inputs: {:?}", self.machine.pending_inputs())
+            .map_err(|e| e.to_string())
+    }
+    fn outputs(&self, out: &mut impl Write) -> Result<(), String> {
+        writeln!(out, "pending outputs: {:?}", self.machine.pending_outputs())
+            .map_err(|e| e.to_string())
+    }
+    fn reset(&mut self, out: &mut impl Write) -> Result<(), String> {
+        self.machine = Intcode::new();
+        self.machine.load_program(self.program.clone());
+        writeln!(out, "reset").map_err(|e| e.to_string())?;
+        self.registers(out)
+    }
+    fn is_running(&self) -> bool {
+        matches!(
+            self.machine.get_state(),
+            IntcodeState::Ready | IntcodeState::OutputReady
+        )
+    }
+    // Prints the instruction at addr and returns how many cells it spans
+    fn show_instruction(&self, addr: usize, out: &mut impl Write) -> Result<usize, String> {
+        // Longest instruction is an opcode and three parameters
+        let window: Vec<i64> = (0..4)
+            .map(|k| {
+                addr.checked_add(k)
+                    .map_or(0, |a| self.machine.peek(a).unwrap_or(0))
+            })
+            .collect();
+        let mut instruction = decode_at(&window, 0);
+        let size = instruction.size();
+        match &mut instruction {
+            Instruction::Code { addr: a, .. } => *a = addr,
+            Instruction::Data { addr: a, .. } => *a = addr,
+        }
+        let marker = if self.breakpoints.contains(&addr) {
+            "*"
+        } else {
+            " "
+        };
+        writeln!(out, "{}{}", marker, instruction).map_err(|e| e.to_string())?;
+        Ok(size)
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String> {
+    let arg = args.get(index).ok_or_else(|| format!("missing {}", what))?;
+    arg.parse().map_err(|_| format!("bad {} '{}'", what, arg))
+}
+
+#[cfg(test)]
+mod debugger_tests {
+    use super::*;
+
+    fn session(program: Vec<i64>, script: &str) -> String {
+        let mut debugger = Debugger::new(program);
+        let mut out = Vec::new();
+        debugger.execute_script(script, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn step_and_registers() {
+        let transcript = session(vec![109, 5, 104, 7, 99], "step\nregs");
+        assert_eq!(
+            transcript,
+            "> step\n      0: arb #5\nip=2 rb=5 state=Ready\n> regs\nip=2 rb=5 state=Ready\n"
+        );
+    }
+    #[test]
+    fn continue_stops_at_breakpoints_and_input() {
+        let program = vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        let transcript = session(
+            program,
+            "break 6\ncontinue\ninput 8\ncontinue\noutputs\ncontinue",
+        );
+        let expected = "\
+> break 6
+breakpoint at 6
+> continue
+0 steps
+ip=0 rb=0 state=WaitingForInput
+> input 8
+pending inputs: [8]
+> continue
+hit breakpoint at 6
+2 steps
+ip=6 rb=0 state=Ready
+> outputs
+pending outputs: []
+> continue
+2 steps
+ip=8 rb=0 state=Halted
+";
+        assert_eq!(transcript, expected);
+    }
+    #[test]
+    fn dump_poke_and_reset() {
+        let transcript = session(vec![1, 0, 0, 0, 99], "c\ndump 0 4\nreset\npoke 4 42\nx 4");
+        let expected = "\
+> c
+2 steps
+ip=4 rb=0 state=Halted
+> dump 0 4
+     0: 2 0 0 0 99
+> reset
+reset
+ip=0 rb=0 state=Ready
+> poke 4 42
+[4] = 42
+> x 4
+     4: 42
+";
+        assert_eq!(transcript, expected);
+    }
+    #[test]
+    fn faults_and_bad_commands_are_reported() {
+        let transcript = session(vec![42], "frobnicate\nbreak\ns");
+        assert!(transcript.contains("error: unknown command 'frobnicate'"));
+        assert!(transcript.contains("error: missing address"));
+        assert!(transcript.contains("fault: unknown opcode 42 at address 0"));
+    }
+    #[test]
+    fn quit_ends_the_script() {
+        let mut debugger = Debugger::new(vec![99]);
+        let mut out = Vec::new();
+        let keep_going = debugger.execute_script("q\nstep", &mut out).unwrap();
+        assert!(!keep_going);
+        assert_eq!(String::from_utf8(out).unwrap(), "> q\n");
+    }
+    #[test]
+    fn step_reports_an_input_wait_and_continue_stops_at_its_limit() {
+        let program = vec![3, 5, 1105, 1, 2, 0];
+        let transcript = session(program, "step\nstep\ninput 1\ncontinue 3");
+        let expected = "\
+> step
+      0: in [5]
+ip=0 rb=0 state=WaitingForInput
+> step
+waiting for input, queue some with input
+ip=0 rb=0 state=WaitingForInput
+> input 1
+pending inputs: [1]
+> continue 3
+stopped after 3 steps
+3 steps
+ip=2 rb=0 state=Ready
+";
+        assert_eq!(transcript, expected);
+    }
+    #[test]
+    fn dump_and_list_stop_at_the_top_of_memory() {
+        let mut debugger = Debugger::new(vec![99]);
+        let mut out = Vec::new();
+        let top = usize::MAX;
+        for command in [
+            format!("dump {} {}", top - 1, top),
+            format!("list {} 2", top),
+        ] {
+            debugger.execute(&command, &mut out).unwrap();
+        }
+        let transcript = String::from_utf8(out).unwrap();
+        assert_eq!(transcript, format!("{}: 0 0\n {}: .data 0\n", top - 1, top));
+    }
+    #[test]
+    fn dump_rejects_huge_ranges() {
+        let transcript = session(vec![99], "dump 0 18446744073709551615\ndump 0 4095");
+        assert!(transcript.contains("error: dump at most 4096 cells at a time"));
+        assert!(transcript.contains("  4088: 0 0 0 0 0 0 0 0\n"));
+    }
+}
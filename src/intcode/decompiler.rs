@@ -0,0 +1,1021 @@
+use super::cfg::{stored_constant, BasicBlock, ControlFlowGraph};
+use super::disassembler::{Instruction, Operand};
+use super::intcode::MemoryMode;
+use super::word::Word;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Range;
+
+const INDENT: &str = "    ";
+
+// Position cells are named after their address, relative ones after the frame
+fn expr(operand: &Operand) -> String {
+    match operand.mode {
+        MemoryMode::ImmediateMode => operand.value.to_string(),
+        MemoryMode::PositionMode => format!("var_{}", operand.value),
+        MemoryMode::RelativeMode if operand.value < 0 => format!("stack[rb{}]", operand.value),
+        MemoryMode::RelativeMode => format!("stack[rb+{}]", operand.value),
+    }
+}
+
+// Immediate write destinations behave as position mode
+fn place(operand: &Operand) -> String {
+    match operand.mode {
+        MemoryMode::ImmediateMode => format!("var_{}", operand.value),
+        _ => expr(operand),
+    }
+}
+
+fn same_cell(read: &Operand, write: &Operand) -> bool {
+    MemoryMode::ImmediateMode != read.mode && expr(read) == place(write)
+}
+
+fn code(instruction: &Instruction) -> Option<(usize, isize, &[Operand])> {
+    match instruction {
+        Instruction::Code {
+            addr,
+            opcode,
+            operands,
+        } => Some((*addr, *opcode, operands)),
+        Instruction::Data { .. } => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Condition {
+    left: String,
+    op: &'static str,
+    right: String,
+}
+
+impl Condition {
+    fn test(operand: &Operand, nonzero: bool) -> Condition {
+        Condition {
+            left: expr(operand),
+            op: if nonzero { "!=" } else { "==" },
+            right: "0".to_string(),
+        }
+    }
+    fn negate(&self) -> Condition {
+        let op = match self.op {
+            "<" => ">=",
+            ">=" => "<",
+            "==" => "!=",
+            _ => "==",
+        };
+        Condition { op, ..self.clone() }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.op, self.right.as_str()) {
+            ("!=", "0") => write!(f, "{}", self.left),
+            ("==", "0") => write!(f, "!{}", self.left),
+            _ => write!(f, "{} {} {}", self.left, self.op, self.right),
+        }
+    }
+}
+
+// The jump ending a block, None if it never jumps
+struct Branch {
+    addr: usize,
+    condition: Option<Condition>, // None when the jump is always taken
+    target: Operand,
+    folded: Option<usize>, // Compare merged into the condition
+}
+
+impl Branch {
+    fn target_addr(&self) -> Option<usize> {
+        match self.target.mode {
+            MemoryMode::ImmediateMode if self.target.value >= 0 => Some(self.target.value as usize),
+            _ => None,
+        }
+    }
+}
+
+fn branch(block: &BasicBlock) -> Option<Branch> {
+    let (last, rest) = block.instructions.split_last()?;
+    let (addr, opcode, operands) = code(last)?;
+    if 5 != opcode && 6 != opcode {
+        return None;
+    }
+    let (test, target) = (&operands[0], operands[1]);
+    if MemoryMode::ImmediateMode == test.mode {
+        let taken = (5 == opcode) == (0 != test.value);
+        return Some(Branch {
+            addr,
+            condition: None,
+            target,
+            folded: None,
+        })
+        .filter(|_| taken);
+    }
+    // A compare straight into the tested cell reads better as the condition itself
+    if let Some((compare_addr, compare, args)) = rest.last().and_then(code) {
+        if (7 == compare || 8 == compare) && same_cell(test, &args[2]) {
+            let condition = Condition {
+                left: expr(&args[0]),
+                op: if 7 == compare { "<" } else { "==" },
+                right: expr(&args[1]),
+            };
+            return Some(Branch {
+                addr,
+                condition: Some(if 5 == opcode {
+                    condition
+                } else {
+                    condition.negate()
+                }),
+                target,
+                folded: Some(compare_addr),
+            });
+        }
+    }
+    Some(Branch {
+        addr,
+        condition: Some(Condition::test(test, 5 == opcode)),
+        target,
+        folded: None,
+    })
+}
+
+// The usual calling convention: store the address after the jump, then jump.
+// Calls through a function pointer have no target.
+struct Call {
+    target: Option<usize>,
+    pointer: Operand,
+    ret: usize,
+    store: usize,
+}
+
+fn call(block: &BasicBlock) -> Option<Call> {
+    let branch = branch(block).filter(|branch| branch.condition.is_none())?;
+    let count = block.instructions.len();
+    let store = block.instructions.get(count.checked_sub(2)?)?;
+    if Some(block.end() as i128) != stored_constant(store) {
+        return None;
+    }
+    Some(Call {
+        target: branch.target_addr(),
+        pointer: branch.target,
+        ret: block.end(),
+        store: store.addr(),
+    })
+}
+
+fn arithmetic(symbol: &str, operands: &[Operand]) -> String {
+    let (a, b, dest) = (&operands[0], &operands[1], &operands[2]);
+    let identity = if "+" == symbol { 0 } else { 1 };
+    let immediate = |operand: &Operand| {
+        Some(operand.value).filter(|_| MemoryMode::ImmediateMode == operand.mode)
+    };
+    let constant = match (immediate(a), immediate(b)) {
+        (Some(a), Some(b)) if "+" == symbol => a.checked_add(b),
+        (Some(a), Some(b)) => a.checked_mul(b),
+        _ => None,
+    };
+    if let Some(constant) = constant {
+        return format!("{} = {}", place(dest), constant);
+    }
+    if Some(identity) == immediate(a) {
+        return format!("{} = {}", place(dest), expr(b));
+    }
+    if Some(identity) == immediate(b) {
+        return format!("{} = {}", place(dest), expr(a));
+    }
+    if "*" == symbol && Some(-1) == immediate(a) {
+        return format!("{} = -{}", place(dest), expr(b));
+    }
+    if "*" == symbol && Some(-1) == immediate(b) {
+        return format!("{} = -{}", place(dest), expr(a));
+    }
+    // Updates in place, either operand since both operations commute
+    let update = if same_cell(a, dest) {
+        Some(b)
+    } else if same_cell(b, dest) {
+        Some(a)
+    } else {
+        None
+    };
+    match update {
+        Some(other) if "+" == symbol && immediate(other).is_some_and(|v| v < 0) => {
+            format!("{} -= {}", place(dest), -other.value)
+        }
+        Some(other) => format!("{} {}= {}", place(dest), symbol, expr(other)),
+        None => format!("{} = {} {} {}", place(dest), expr(a), symbol, expr(b)),
+    }
+}
+
+fn statement(instruction: &Instruction) -> Option<String> {
+    let (_, opcode, operands) = code(instruction)?;
+    Some(match opcode {
+        1 => arithmetic("+", operands),
+        2 => arithmetic("*", operands),
+        3 => format!("{} = input()", place(&operands[0])),
+        4 => format!("output({})", expr(&operands[0])),
+        7 => format!(
+            "{} = {} < {}",
+            place(&operands[2]),
+            expr(&operands[0]),
+            expr(&operands[1])
+        ),
+        8 => format!(
+            "{} = {} == {}",
+            place(&operands[2]),
+            expr(&operands[0]),
+            expr(&operands[1])
+        ),
+        // Moving the relative base by a constant opens or closes a stack frame
+        9 => match operands[0] {
+            Operand {
+                mode: MemoryMode::ImmediateMode,
+                value,
+            } if value > 0 => format!("push_frame({})", value),
+            Operand {
+                mode: MemoryMode::ImmediateMode,
+                value,
+            } if value < 0 => format!("pop_frame({})", -value),
+            Operand {
+                mode: MemoryMode::ImmediateMode,
+                ..
+            } => return None,
+            operand => format!("rb += {}", expr(&operand)),
+        },
+        99 => "halt".to_string(),
+        // Jumps that are taken end their block and are rendered with it
+        _ => return None,
+    })
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Stmt {
+    Label(usize),
+    Line(String),
+    Goto(Option<Condition>, usize),
+    If(Condition, Vec<Stmt>, Vec<Stmt>),
+    While(Condition, Vec<Stmt>),
+    DoWhile(Vec<Stmt>, Condition),
+    Loop(Vec<Stmt>),
+}
+
+// Every statement, nested ones included
+fn visit(stmts: &[Stmt], each: &mut impl FnMut(&Stmt)) {
+    for stmt in stmts {
+        each(stmt);
+        match stmt {
+            Stmt::If(_, then, otherwise) => {
+                visit(then, each);
+                visit(otherwise, each);
+            }
+            Stmt::While(_, body) | Stmt::DoWhile(body, _) | Stmt::Loop(body) => visit(body, each),
+            Stmt::Label(_) | Stmt::Line(_) | Stmt::Goto(..) => (),
+        }
+    }
+}
+
+fn goto_label(target: usize, labels: &BTreeSet<usize>) -> String {
+    if labels.contains(&target) {
+        format!("label_{}", target)
+    } else {
+        format!("{} /* not code */", target)
+    }
+}
+
+fn render(stmts: &[Stmt], depth: usize, labels: &BTreeSet<usize>, text: &mut String) {
+    let indent = INDENT.repeat(depth);
+    for stmt in stmts {
+        match stmt {
+            Stmt::Label(addr) if labels.contains(addr) => {
+                text.push_str(&format!("{}label_{}:\n", INDENT.repeat(depth - 1), addr))
+            }
+            Stmt::Label(_) => (),
+            Stmt::Line(line) => text.push_str(&format!("{}{}\n", indent, line)),
+            Stmt::Goto(None, target) => {
+                text.push_str(&format!("{}goto {}\n", indent, goto_label(*target, labels)))
+            }
+            Stmt::Goto(Some(condition), target) => text.push_str(&format!(
+                "{}if ({}) goto {}\n",
+                indent,
+                condition,
+                goto_label(*target, labels)
+            )),
+            Stmt::If(condition, then, otherwise) => {
+                text.push_str(&format!("{}if ({}) {{\n", indent, condition));
+                render(then, depth + 1, labels, text);
+                if !otherwise.is_empty() {
+                    text.push_str(&format!("{}}} else {{\n", indent));
+                    render(otherwise, depth + 1, labels, text);
+                }
+                text.push_str(&format!("{}}}\n", indent));
+            }
+            Stmt::While(condition, body) => {
+                text.push_str(&format!("{}while ({}) {{\n", indent, condition));
+                render(body, depth + 1, labels, text);
+                text.push_str(&format!("{}}}\n", indent));
+            }
+            Stmt::DoWhile(body, condition) => {
+                text.push_str(&format!("{}do {{\n", indent));
+                render(body, depth + 1, labels, text);
+                text.push_str(&format!("{}}} while ({})\n", indent, condition));
+            }
+            Stmt::Loop(body) => {
+                text.push_str(&format!("{}loop {{\n", indent));
+                render(body, depth + 1, labels, text);
+                text.push_str(&format!("{}}}\n", indent));
+            }
+        }
+    }
+}
+
+// Cells of decoded instructions that some instruction writes to directly
+fn patched_cells<'a>(blocks: impl Iterator<Item = &'a BasicBlock>) -> BTreeSet<usize> {
+    let instructions: Vec<&Instruction> = blocks.flat_map(|block| &block.instructions).collect();
+    let written: BTreeSet<i128> = instructions
+        .iter()
+        .filter_map(|instruction| code(instruction))
+        .filter_map(|(_, opcode, operands)| match opcode {
+            1 | 2 | 7 | 8 => operands.get(2),
+            3 => operands.first(),
+            _ => None,
+        })
+        .filter(|dest| MemoryMode::RelativeMode != dest.mode)
+        .map(|dest| dest.value)
+        .collect();
+    instructions
+        .iter()
+        .flat_map(|instruction| instruction.addr()..instruction.addr() + instruction.size())
+        .filter(|cell| written.contains(&(*cell as i128)))
+        .collect()
+}
+
+struct Decompiler {
+    graph: ControlFlowGraph,
+    calls: BTreeMap<usize, Call>, // By the start of the calling block
+    entries: BTreeSet<usize>,     // Of main and every function found
+    tables: BTreeSet<usize>,      // Entries only ever reached through a jump table
+    patched: BTreeSet<usize>,
+    data: Vec<Range<usize>>, // Cells none of the functions cover
+}
+
+impl Decompiler {
+    // Return addresses and function pointers are only ever jumped to
+    // indirectly, exploring finds them from the constants stored beforehand.
+    // Functions are then found from main outwards through calls and stored
+    // addresses, and once those run out through jump tables: runs of cells no
+    // function covers that all hold the start of code no function covers.
+    fn new<W: Word>(program: &[W]) -> Decompiler {
+        let graph = ControlFlowGraph::explore(program);
+        let calls = graph
+            .blocks
+            .values()
+            .filter_map(|block| call(block).map(|call| (block.start, call)))
+            .collect();
+        let mut decompiler = Decompiler {
+            graph,
+            calls,
+            entries: BTreeSet::new(),
+            tables: BTreeSet::new(),
+            patched: BTreeSet::new(),
+            data: Vec::new(),
+        };
+        decompiler.entries.insert(0);
+        loop {
+            let covered = decompiler.covered(program.len());
+            let found = decompiler.functions(&covered);
+            if !found.is_subset(&decompiler.entries) {
+                decompiler.entries.extend(found);
+                continue;
+            }
+            let tables = decompiler.jump_tables(program, &covered);
+            if tables.is_empty() {
+                break;
+            }
+            decompiler.entries.extend(&tables);
+            decompiler.tables.extend(tables);
+        }
+        let covered = decompiler.covered(program.len());
+        let blocks: BTreeSet<usize> = decompiler
+            .entries
+            .iter()
+            .flat_map(|&entry| decompiler.function(entry))
+            .collect();
+        decompiler.patched =
+            patched_cells(blocks.iter().map(|start| &decompiler.graph.blocks[start]));
+        let mut addr = 0;
+        while addr < covered.len() {
+            let start = addr;
+            while addr < covered.len() && covered[addr] == covered[start] {
+                addr += 1;
+            }
+            if !covered[start] {
+                decompiler.data.push(start..addr);
+            }
+        }
+        decompiler
+    }
+    // Cells of the blocks of every entry found so far
+    fn covered(&self, len: usize) -> Vec<bool> {
+        let mut covered = vec![false; len];
+        for entry in &self.entries {
+            for start in self.function(*entry) {
+                let block = &self.graph.blocks[&start];
+                covered[block.start..block.end()]
+                    .iter_mut()
+                    .for_each(|cell| *cell = true);
+            }
+        }
+        covered
+    }
+    // Call targets, and code whose address is stored other than to return to
+    fn functions(&self, covered: &[bool]) -> BTreeSet<usize> {
+        let returns: BTreeSet<usize> = self.calls.values().map(|call| call.ret).collect();
+        let blocks: BTreeSet<usize> = self
+            .entries
+            .iter()
+            .flat_map(|&entry| self.function(entry))
+            .collect();
+        let called = blocks
+            .iter()
+            .filter_map(|start| self.calls.get(start)?.target);
+        let pointers = blocks
+            .iter()
+            .flat_map(|start| &self.graph.blocks[start].instructions)
+            .filter_map(stored_constant)
+            .filter_map(|value| usize::try_from(value).ok())
+            .filter(|addr| {
+                self.graph.blocks.contains_key(addr) && !covered[*addr] && !returns.contains(addr)
+            });
+        called.chain(pointers).collect()
+    }
+    fn jump_tables<W: Word>(&self, program: &[W], covered: &[bool]) -> BTreeSet<usize> {
+        let target = |addr: usize| {
+            Some(addr)
+                .filter(|&addr| !covered[addr])
+                .and_then(|addr| program[addr].to_address())
+                .filter(|target| self.graph.blocks.contains_key(target) && !covered[*target])
+        };
+        let mut tables = BTreeSet::new();
+        let mut addr = 0;
+        while addr < program.len() {
+            let run: Vec<usize> = (addr..program.len()).map_while(target).collect();
+            if run.len() > 1 {
+                tables.extend(run.iter().copied());
+            }
+            addr += run.len().max(1);
+        }
+        tables
+    }
+    // Blocks reached from `entry` without following calls into other functions
+    fn function(&self, entry: usize) -> BTreeSet<usize> {
+        let mut blocks = BTreeSet::new();
+        let mut pending = vec![entry];
+        while let Some(start) = pending.pop() {
+            if !self.graph.blocks.contains_key(&start) || !blocks.insert(start) {
+                continue;
+            }
+            match self.calls.get(&start) {
+                Some(call) => pending.push(call.ret),
+                None => pending.extend(
+                    self.graph
+                        .edges
+                        .iter()
+                        .filter(|edge| start == edge.from)
+                        .map(|edge| edge.to),
+                ),
+            }
+        }
+        blocks
+    }
+    // Where a block goes on to when it does not jump away for good
+    fn falls_through(&self, block: &BasicBlock) -> Option<usize> {
+        if let Some(call) = self.calls.get(&block.start) {
+            return Some(call.ret);
+        }
+        let halts = block
+            .instructions
+            .last()
+            .and_then(code)
+            .is_some_and(|(_, opcode, _)| 99 == opcode);
+        let jumps = branch(block).is_some_and(|branch| branch.condition.is_none());
+        Some(block.end()).filter(|_| !halts && !jumps)
+    }
+    // Whether every block starting in `range` also ends there, which a jump
+    // into the middle of an instruction can break
+    fn nests(&self, blocks: &BTreeSet<usize>, range: Range<usize>) -> bool {
+        let end = range.end;
+        blocks
+            .range(range)
+            .all(|start| self.graph.blocks[start].end() <= end)
+    }
+    fn open_branch(&self, block: &BasicBlock, consumed: &BTreeSet<usize>) -> Option<Branch> {
+        if self.calls.contains_key(&block.start) {
+            return None;
+        }
+        branch(block).filter(|branch| !consumed.contains(&branch.addr))
+    }
+    fn block_stmts(&self, block: &BasicBlock, consumed: &BTreeSet<usize>) -> Vec<Stmt> {
+        let branch = branch(block);
+        let call = self.calls.get(&block.start);
+        let mut hidden = BTreeSet::new();
+        if let Some(branch) = &branch {
+            hidden.insert(branch.addr);
+            hidden.extend(branch.folded);
+        }
+        if let Some(call) = call {
+            hidden.insert(call.store);
+        }
+        let mut stmts = Vec::new();
+        for instruction in &block.instructions {
+            // What is decoded here need not be what runs
+            let cells: Vec<String> = (instruction.addr()..instruction.addr() + instruction.size())
+                .filter(|cell| self.patched.contains(cell))
+                .map(|cell| cell.to_string())
+                .collect();
+            if !cells.is_empty() {
+                stmts.push(Stmt::Line(format!(
+                    "// self-modified: {} written at run time",
+                    cells.join(", ")
+                )));
+            }
+            if !hidden.contains(&instruction.addr()) {
+                stmts.extend(statement(instruction).map(Stmt::Line));
+            }
+        }
+        if let Some(branch) = branch.filter(|branch| !consumed.contains(&branch.addr)) {
+            let target = branch.target_addr();
+            stmts.push(match (call, target, branch.condition) {
+                (Some(call), _, _) => Stmt::Line(match call.target {
+                    Some(target) => format!("call sub_{}()", target),
+                    None => format!("call *{}()", expr(&call.pointer)),
+                }),
+                (None, Some(target), condition) => Stmt::Goto(condition, target),
+                // Jumping through the stack is how functions return
+                (None, None, None) if MemoryMode::RelativeMode == branch.target.mode => {
+                    Stmt::Line("return".to_string())
+                }
+                (None, None, None) => Stmt::Line(format!("goto *{}", expr(&branch.target))),
+                (None, None, Some(condition)) => {
+                    Stmt::Line(format!("if ({}) goto *{}", condition, expr(&branch.target)))
+                }
+            });
+        }
+        stmts
+    }
+    // Recovers loops from jumps back to a block and ifs from jumps forward
+    // within `range`, anything that does not nest is left as a goto. `follow`
+    // is where running off the end of `range` goes, None in a loop body or
+    // past the last block, so a jump to `range.end` only closes an if when it
+    // lands there too.
+    fn structure(
+        &self,
+        blocks: &BTreeSet<usize>,
+        range: Range<usize>,
+        follow: Option<usize>,
+        consumed: &mut BTreeSet<usize>,
+    ) -> Vec<Stmt> {
+        let closes = |target: usize| {
+            target < range.end || (target == range.end && Some(range.end) == follow)
+        };
+        let mut stmts = Vec::new();
+        let mut addr = range.start;
+        while addr < range.end {
+            let start = match blocks.range(addr..range.end).next() {
+                Some(&start) => start,
+                None => break,
+            };
+            let block = &self.graph.blocks[&start];
+            // The last jump back to this block closes a loop around everything between
+            let latch = blocks
+                .range(start..range.end)
+                .rev()
+                .map(|start| &self.graph.blocks[start])
+                .find(|latch| {
+                    latch.end() <= range.end
+                        && self.nests(blocks, start..latch.end())
+                        && self
+                            .open_branch(latch, consumed)
+                            .is_some_and(|branch| Some(start) == branch.target_addr())
+                });
+            if let Some(latch) = latch {
+                let back = self.open_branch(latch, consumed).unwrap();
+                consumed.insert(back.addr);
+                let end = latch.end();
+                let exit = self
+                    .open_branch(block, consumed)
+                    .filter(|_| back.condition.is_none() && latch.start != start)
+                    .filter(|exit| exit.condition.is_some() && Some(end) == exit.target_addr());
+                match (back.condition, exit) {
+                    (Some(condition), _) => {
+                        let body = self.structure(blocks, start..end, None, consumed);
+                        stmts.push(Stmt::DoWhile(body, condition));
+                    }
+                    (None, Some(exit)) => {
+                        consumed.insert(exit.addr);
+                        let header = self.block_stmts(block, consumed);
+                        let body = self.structure(blocks, block.end()..end, None, consumed);
+                        let condition = exit.condition.unwrap();
+                        stmts.push(Stmt::Label(start));
+                        if header.is_empty() {
+                            stmts.push(Stmt::While(condition.negate(), body));
+                        } else {
+                            let mut inner = header;
+                            let leave = vec![Stmt::Line("break".to_string())];
+                            inner.push(Stmt::If(condition, leave, Vec::new()));
+                            inner.extend(body);
+                            stmts.push(Stmt::Loop(inner));
+                        }
+                    }
+                    (None, None) => {
+                        let body = self.structure(blocks, start..end, None, consumed);
+                        stmts.push(Stmt::Loop(body));
+                    }
+                }
+                addr = end;
+                continue;
+            }
+            stmts.push(Stmt::Label(start));
+            // A jump over data to the very next block adds nothing to read
+            if let Some(skip) = self.open_branch(block, consumed) {
+                let next = blocks.range(block.end()..).next();
+                if skip.condition.is_none() && skip.target_addr().is_some_and(|t| Some(&t) == next)
+                {
+                    consumed.insert(skip.addr);
+                }
+            }
+            let forward = self
+                .open_branch(block, consumed)
+                .filter(|branch| branch.condition.is_some())
+                .and_then(|branch| {
+                    let target = branch.target_addr()?;
+                    Some((branch, target)).filter(|_| {
+                        target > block.end()
+                            && closes(target)
+                            && self.nests(blocks, block.end()..target)
+                    })
+                });
+            let (branch, then_end) = match forward {
+                Some(forward) => forward,
+                None => {
+                    stmts.extend(self.block_stmts(block, consumed));
+                    addr = block.end();
+                    continue;
+                }
+            };
+            consumed.insert(branch.addr);
+            stmts.extend(self.block_stmts(block, consumed));
+            let condition = branch.condition.unwrap().negate();
+            // An unconditional jump ending the then part skips over an else part
+            let skip = blocks
+                .range(block.end()..then_end)
+                .next_back()
+                .map(|start| &self.graph.blocks[start])
+                .filter(|last| then_end == last.end())
+                .and_then(|last| self.open_branch(last, consumed))
+                .filter(|jump| jump.condition.is_none())
+                .and_then(|jump| {
+                    let target = jump.target_addr()?;
+                    Some((jump, target)).filter(|_| {
+                        target > then_end && closes(target) && self.nests(blocks, then_end..target)
+                    })
+                });
+            match skip {
+                Some((jump, else_end)) => {
+                    consumed.insert(jump.addr);
+                    let then =
+                        self.structure(blocks, block.end()..then_end, Some(else_end), consumed);
+                    let otherwise =
+                        self.structure(blocks, then_end..else_end, Some(else_end), consumed);
+                    stmts.push(Stmt::If(condition, then, otherwise));
+                    addr = else_end;
+                }
+                None => {
+                    let then =
+                        self.structure(blocks, block.end()..then_end, Some(then_end), consumed);
+                    stmts.push(Stmt::If(condition, then, Vec::new()));
+                    addr = then_end;
+                }
+            }
+        }
+        stmts
+    }
+}
+
+// Pseudocode for a program: `main` starts at address 0, every other function
+// becomes a `sub_<addr>` and code only reached through a jump table an
+// `entry_<addr>`. Cells no function covers are listed as data at the top.
+pub fn decompile<W: Word>(program: &[W]) -> String {
+    let decompiler = Decompiler::new(program);
+    let mut text = String::new();
+    if !decompiler.data.is_empty() {
+        let ranges: Vec<String> = decompiler
+            .data
+            .iter()
+            .map(|range| format!("{}..{}", range.start, range.end))
+            .collect();
+        text.push_str(&format!("// data {}\n", ranges.join(", ")));
+    }
+    for &entry in &decompiler.entries {
+        let blocks = decompiler.function(entry);
+        let end = match blocks
+            .iter()
+            .map(|s| decompiler.graph.blocks[s].end())
+            .max()
+        {
+            Some(end) => end,
+            None => continue,
+        };
+        let mut consumed = BTreeSet::new();
+        let mut stmts = decompiler.structure(&blocks, entry..end, None, &mut consumed);
+        let mut placed = BTreeSet::new();
+        visit(&stmts, &mut |stmt| {
+            if let Stmt::Label(addr) = stmt {
+                placed.insert(*addr);
+            }
+        });
+        // Blocks overlapping ones already placed are only reached by jumping
+        // into the middle of an instruction, they go at the end with every
+        // jump spelled out
+        for start in blocks.difference(&placed.clone()) {
+            let block = &decompiler.graph.blocks[start];
+            placed.insert(*start);
+            stmts.push(Stmt::Label(*start));
+            stmts.extend(decompiler.block_stmts(block, &BTreeSet::new()));
+            if let Some(next) = decompiler.falls_through(block) {
+                stmts.push(Stmt::Goto(None, next));
+            }
+        }
+        let mut labels = BTreeSet::new();
+        visit(&stmts, &mut |stmt| {
+            if let Stmt::Goto(_, target) = stmt {
+                labels.insert(*target);
+            }
+        });
+        let labels = labels.intersection(&placed).cloned().collect();
+        let name = if 0 == entry {
+            "main".to_string()
+        } else if decompiler.tables.contains(&entry) {
+            format!("entry_{}", entry)
+        } else {
+            format!("sub_{}", entry)
+        };
+        if 0 != entry {
+            text.push('\n');
+        }
+        text.push_str(&format!("fn {}() {{\n", name));
+        render(&stmts, 1, &labels, &mut text);
+        text.push_str("}\n");
+    }
+    text
+}
+
+#[cfg(test)]
+mod decompiler_tests {
+    use super::*;
+    use crate::intcode::assembler::assemble;
+
+    #[test]
+    fn do_while_from_a_conditional_jump_back() {
+        let program = assemble(
+            "
+                   in [n]
+            loop:  out [n]
+                   add [n], #-1, [n]
+                   jnz [n], #loop
+                   hlt
+            n:     .data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 12..13\n\
+             fn main() {\n\
+             \x20   var_12 = input()\n\
+             \x20   do {\n\
+             \x20       output(var_12)\n\
+             \x20       var_12 -= 1\n\
+             \x20   } while (var_12)\n\
+             \x20   halt\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn while_loops_if_else_and_calls() {
+        let program = assemble(
+            "
+                    in [n]
+            loop:   jz [n], #done
+                    lt [n], #10, [small]
+                    jz [small], #big
+                    out #1
+                    jnz #1, #next
+            big:    out #2
+            next:   add [n], #-1, [n]
+                    jnz #1, #loop
+            done:   add #ret, #0, [rb]
+                    jz #0, #square
+            ret:    hlt
+            square: arb #1
+                    out #9
+                    arb #-1
+                    jz #0, [rb]
+            n:      .data 0
+            small:  .data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 43..45\n\
+             fn main() {\n\
+             \x20   var_43 = input()\n\
+             \x20   while (var_43) {\n\
+             \x20       if (var_43 < 10) {\n\
+             \x20           output(1)\n\
+             \x20       } else {\n\
+             \x20           output(2)\n\
+             \x20       }\n\
+             \x20       var_43 -= 1\n\
+             \x20   }\n\
+             \x20   call sub_34()\n\
+             \x20   halt\n\
+             }\n\
+             \n\
+             fn sub_34() {\n\
+             \x20   push_frame(1)\n\
+             \x20   output(9)\n\
+             \x20   pop_frame(1)\n\
+             \x20   return\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn loops_that_exit_midway_and_leftover_gotos() {
+        let program = assemble(
+            "
+            start:  in [x]
+                    eq [x], #0, [t]
+                    jnz [t], #end
+                    lt [x], #0, [t]
+                    jz [t], #skip
+                    mul [x], #-1, [x]
+            skip:   out [x]
+                    jz #0, #start
+            end:    in [x]
+                    jnz [x], #skip
+                    hlt
+            x:      .data 0
+            t:      .data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 31..33\n\
+             fn main() {\n\
+             \x20   loop {\n\
+             \x20       var_31 = input()\n\
+             \x20       if (!var_31) {\n\
+             \x20           break\n\
+             \x20       }\n\
+             \x20       if (var_31 < 0) {\n\
+             \x20           var_31 = -var_31\n\
+             \x20       }\n\
+             \x20   label_20:\n\
+             \x20       output(var_31)\n\
+             \x20   }\n\
+             \x20   var_31 = input()\n\
+             \x20   if (var_31) goto label_20\n\
+             \x20   halt\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn pointer_calls_and_jumps_over_data() {
+        let program = assemble(
+            "
+                    jnz #1, #code
+            f:      .data 0
+            code:   add #hook, #0, [f]
+                    mul #1, #ret1, [rb]
+                    jz #0, #hook
+            ret1:   add #ret2, #0, [rb]
+                    jz #0, [f]
+            ret2:   hlt
+            hook:   out #7
+                    jz #0, [rb]
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 3..4\n\
+             fn main() {\n\
+             \x20   var_3 = 23\n\
+             \x20   call sub_23()\n\
+             \x20   call *var_3()\n\
+             \x20   halt\n\
+             }\n\
+             \n\
+             fn sub_23() {\n\
+             \x20   output(7)\n\
+             \x20   return\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn conditions_that_jump_into_the_else_part() {
+        let program = assemble(
+            "
+                    in [a]
+                    in [b]
+                    lt [a], #1, [t]
+                    jz [t], #other
+                    lt [b], #5, [t]
+                    jz [t], #other
+                    out #1
+                    jnz #1, #done
+            other:  out #2
+            done:   hlt
+            a:      .data 0
+            b:      .data 0
+            t:      .data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 26..29\n\
+             fn main() {\n\
+             \x20   var_26 = input()\n\
+             \x20   var_27 = input()\n\
+             \x20   if (var_26 < 1) {\n\
+             \x20       if (var_27 >= 5) goto label_23\n\
+             \x20       output(1)\n\
+             \x20   } else {\n\
+             \x20   label_23:\n\
+             \x20       output(2)\n\
+             \x20   }\n\
+             \x20   halt\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn overlapping_blocks_jumps_into_data_and_patched_operands() {
+        let program = assemble(
+            "
+                    in [n]
+                    in [port+1]
+                    jz [n], #half+2
+            half:   add #1, #99, [n]
+            port:   out #0
+                    jnz [n], #n
+                    hlt
+            n:      .data 0
+            ",
+        )
+        .unwrap();
+        assert_eq!(
+            decompile(&program),
+            "// data 17..18\n\
+             fn main() {\n\
+             \x20   var_17 = input()\n\
+             \x20   var_12 = input()\n\
+             \x20   if (!var_17) goto label_9\n\
+             \x20   var_17 = 100\n\
+             \x20   // self-modified: 12 written at run time\n\
+             \x20   output(0)\n\
+             \x20   if (var_17) goto 17 /* not code */\n\
+             \x20   halt\n\
+             label_9:\n\
+             \x20   halt\n\
+             }\n"
+        );
+    }
+    #[test]
+    fn day_11_calls_through_the_stack() {
+        let program = crate::helpers::read_file_delim_ints("./inputs/day_11.txt", ",").unwrap();
+        let pseudo = decompile(&program);
+        let functions: Vec<&str> = pseudo
+            .lines()
+            .filter(|line| line.starts_with("fn "))
+            .collect();
+        assert_eq!(
+            functions,
+            vec![
+                "fn main() {",
+                "fn sub_441() {",
+                "fn sub_472() {",
+                "fn sub_505() {",
+                "fn sub_546() {"
+            ]
+        );
+        assert!(pseudo.starts_with("// data 8..11, 467..472, 504..505\n"));
+        assert!(pseudo.contains("    } while (var_9 < 968)\n"));
+        assert!(pseudo.contains("    stack[rb+3] = 472\n    call sub_505()\n"));
+        assert!(pseudo.contains("        call sub_546()\n        stack[rb-4] = stack[rb+1]\n"));
+        assert!(pseudo.contains("            call *var_504()\n"));
+        assert_eq!(pseudo.matches("    return\n").count(), 4);
+    }
+}
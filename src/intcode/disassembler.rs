@@ -0,0 +1,199 @@
+use super::intcode::{Intcode, MemoryMode};
+use super::word::Word;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Operand {
+    pub mode: MemoryMode,
+    pub value: i128,
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            MemoryMode::PositionMode => write!(f, "[{}]", self.value),
+            MemoryMode::ImmediateMode => write!(f, "#{}", self.value),
+            MemoryMode::RelativeMode if self.value < 0 => write!(f, "[rb{}]", self.value),
+            MemoryMode::RelativeMode => write!(f, "[rb+{}]", self.value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Instruction {
+    Code {
+        addr: usize,
+        opcode: isize,
+        operands: Vec<Operand>,
+    },
+    // Anything that does not decode, or runs off the end of the image
+    Data {
+        addr: usize,
+        value: i128,
+    },
+}
+
+impl Instruction {
+    pub fn addr(&self) -> usize {
+        match self {
+            Instruction::Code { addr, .. } => *addr,
+            Instruction::Data { addr, .. } => *addr,
+        }
+    }
+    // Number of memory cells the instruction occupies
+    pub fn size(&self) -> usize {
+        match self {
+            Instruction::Code { operands, .. } => 1 + operands.len(),
+            Instruction::Data { .. } => 1,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Code {
+                addr,
+                opcode,
+                operands,
+            } => {
+                write!(f, "{:>6}: {}", addr, mnemonic(*opcode).unwrap_or("???"))?;
+                for (i, operand) in operands.iter().enumerate() {
+                    let separator = if 0 == i { " " } else { ", " };
+                    write!(f, "{}{}", separator, operand)?;
+                }
+                Ok(())
+            }
+            Instruction::Data { addr, value } => write!(f, "{:>6}: .data {}", addr, value),
+        }
+    }
+}
+
+pub fn mnemonic(opcode: isize) -> Option<&'static str> {
+    match opcode {
+        1 => Some("add"),
+        2 => Some("mul"),
+        3 => Some("in"),
+        4 => Some("out"),
+        5 => Some("jnz"),
+        6 => Some("jz"),
+        7 => Some("lt"),
+        8 => Some("eq"),
+        9 => Some("arb"),
+        99 => Some("hlt"),
+        _ => None,
+    }
+}
+
+// Linear sweep over the image, cells that do not decode are marked as data
+pub fn disassemble<W: Word>(program: &[W]) -> Vec<Instruction> {
+    let mut listing = Vec::new();
+    let mut addr = 0;
+    while addr < program.len() {
+        listing.push(decode_at(program, addr));
+        addr += listing.last().unwrap().size();
+    }
+    listing
+}
+
+pub fn decode_at<W: Word>(program: &[W], addr: usize) -> Instruction {
+    let value = program[addr];
+    let data = Instruction::Data {
+        addr,
+        value: value.to_i128(),
+    };
+    let (opcode, modes) = match Intcode::decode_instruction(addr, value) {
+        Ok(decoded) => decoded,
+        Err(_) => return data,
+    };
+    let count = Intcode::parameter_count(opcode).unwrap();
+    match program.get(addr + 1..addr + 1 + count) {
+        Some(params) => Instruction::Code {
+            addr,
+            opcode,
+            operands: params
+                .iter()
+                .zip(modes.iter())
+                .map(|(&value, &mode)| Operand {
+                    mode,
+                    value: value.to_i128(),
+                })
+                .collect(),
+        },
+        None => data,
+    }
+}
+
+pub fn listing<W: Word>(program: &[W]) -> String {
+    let mut text = String::new();
+    for instruction in disassemble(program) {
+        text.push_str(&instruction.to_string());
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod disassembler_tests {
+    use super::*;
+
+    #[test]
+    fn operands_print_by_mode() {
+        let position = Operand {
+            mode: MemoryMode::PositionMode,
+            value: 12,
+        };
+        let immediate = Operand {
+            mode: MemoryMode::ImmediateMode,
+            value: 5,
+        };
+        let relative = Operand {
+            mode: MemoryMode::RelativeMode,
+            value: 3,
+        };
+        let relative_negative = Operand {
+            mode: MemoryMode::RelativeMode,
+            value: -1,
+        };
+        assert_eq!(position.to_string(), "[12]");
+        assert_eq!(immediate.to_string(), "#5");
+        assert_eq!(relative.to_string(), "[rb+3]");
+        assert_eq!(relative_negative.to_string(), "[rb-1]");
+    }
+    #[test]
+    fn disassemble_day_09_quine() {
+        let program = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let text = listing(&program);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "     0: arb #1",
+                "     2: out [rb-1]",
+                "     4: add [100], #1, [100]",
+                "     8: eq [100], #16, [101]",
+                "    12: jz [101], #0",
+                "    15: hlt",
+            ]
+        );
+    }
+    #[test]
+    fn undecodable_cells_are_data() {
+        let program = vec![1101, 100, -1, 4, 0, 42, 1105, 1];
+        let instructions = disassemble(&program);
+        assert_eq!(instructions.len(), 5);
+        assert_eq!(instructions[1], Instruction::Data { addr: 4, value: 0 });
+        assert_eq!(instructions[2], Instruction::Data { addr: 5, value: 42 });
+        // Truncated jump at the end of the image
+        assert_eq!(
+            instructions[3],
+            Instruction::Data {
+                addr: 6,
+                value: 1105
+            }
+        );
+        assert_eq!(instructions[4], Instruction::Data { addr: 7, value: 1 });
+    }
+}
@@ -0,0 +1,256 @@
+use super::intcode::{Intcode, IntcodeError};
+use super::word::Word;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Param {
+    Read,  // The handler gets the mode-resolved value
+    Write, // The handler gets the mode-resolved destination address
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Operand<W: Word> {
+    Value(W),
+    Target(usize),
+}
+
+impl<W: Word> Operand<W> {
+    // Panics on a write parameter, the registration declared which is which
+    pub fn value(self) -> W {
+        match self {
+            Operand::Value(value) => value,
+            Operand::Target(_) => panic!("write parameter used as a value"),
+        }
+    }
+    pub fn target(self) -> usize {
+        match self {
+            Operand::Target(target) => target,
+            Operand::Value(_) => panic!("read parameter used as a destination"),
+        }
+    }
+}
+
+// Where the machine goes once a handler returns
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Control {
+    Next, // Past the instruction and its parameters
+    Jump(usize),
+    Halt,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum RegistryError {
+    Builtin(isize),
+    OutOfRange(isize), // Only the last two digits of an instruction are the opcode
+    TooManyParameters { opcode: isize, count: usize },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Builtin(opcode) => write!(f, "opcode {} is built in", opcode),
+            RegistryError::OutOfRange(opcode) => {
+                write!(f, "opcode {} does not fit in two digits", opcode)
+            }
+            RegistryError::TooManyParameters { opcode, count } => write!(
+                f,
+                "opcode {} takes {} parameters, at most 3 have modes",
+                opcode, count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+pub type Handler<W> =
+    dyn Fn(&mut Intcode<W>, &[Operand<W>]) -> Result<Control, IntcodeError> + Send + Sync;
+
+pub struct Extension<W: Word> {
+    name: String,
+    params: Vec<Param>,
+    handler: Box<Handler<W>>,
+}
+
+impl<W: Word> Extension<W> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+    pub(crate) fn call(
+        &self,
+        intcode: &mut Intcode<W>,
+        operands: &[Operand<W>],
+    ) -> Result<Control, IntcodeError> {
+        (self.handler)(intcode, operands)
+    }
+}
+
+// Extra opcodes on top of the built in set. Cloning is cheap and shares the
+// handlers, so forks and copies of a machine keep the same instruction set.
+#[derive(Clone, Default)]
+pub struct OpcodeRegistry<W: Word = i64> {
+    extensions: BTreeMap<isize, Arc<Extension<W>>>,
+}
+
+impl<W: Word> fmt::Debug for OpcodeRegistry<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.extensions
+                    .iter()
+                    .map(|(opcode, extension)| (opcode, &extension.name)),
+            )
+            .finish()
+    }
+}
+
+impl<W: Word> OpcodeRegistry<W> {
+    pub fn new() -> OpcodeRegistry<W> {
+        OpcodeRegistry::default()
+    }
+    // Registering the same opcode again replaces the earlier handler
+    pub fn register(
+        &mut self,
+        opcode: isize,
+        name: &str,
+        params: &[Param],
+        handler: impl Fn(&mut Intcode<W>, &[Operand<W>]) -> Result<Control, IntcodeError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Result<(), RegistryError> {
+        if Intcode::parameter_count(opcode).is_some() {
+            return Err(RegistryError::Builtin(opcode));
+        }
+        if !(0..100).contains(&opcode) {
+            return Err(RegistryError::OutOfRange(opcode));
+        }
+        if params.len() > 3 {
+            return Err(RegistryError::TooManyParameters {
+                opcode,
+                count: params.len(),
+            });
+        }
+        let extension = Extension {
+            name: name.to_string(),
+            params: params.to_vec(),
+            handler: Box::new(handler),
+        };
+        self.extensions.insert(opcode, Arc::new(extension));
+        Ok(())
+    }
+    pub fn get(&self, opcode: isize) -> Option<&Arc<Extension<W>>> {
+        self.extensions.get(&opcode)
+    }
+    pub fn opcodes(&self) -> Vec<isize> {
+        self.extensions.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod extension_tests {
+    use super::*;
+    use crate::intcode::intcode::IntcodeState;
+
+    fn registry() -> OpcodeRegistry {
+        let mut registry = OpcodeRegistry::new();
+        // 10: [c] = a - b
+        registry
+            .register(
+                10,
+                "sub",
+                &[Param::Read, Param::Read, Param::Write],
+                |intcode, operands| {
+                    let difference = operands[0].value() - operands[1].value();
+                    intcode.write_operand(operands[2].target(), difference)?;
+                    Ok(Control::Next)
+                },
+            )
+            .unwrap();
+        // 11: jump to b while a is positive, decrementing a in place
+        registry
+            .register(
+                11,
+                "loop",
+                &[Param::Write, Param::Read],
+                |intcode, operands| {
+                    let counter = intcode.peek(operands[0].target())?;
+                    if counter > 0 {
+                        intcode.write_operand(operands[0].target(), counter - 1)?;
+                        Ok(Control::Jump(operands[1].value() as usize))
+                    } else {
+                        Ok(Control::Next)
+                    }
+                },
+            )
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn extensions_run_alongside_builtin_opcodes() {
+        let mut intcode = Intcode::new();
+        intcode.set_extensions(Some(registry()));
+        // Outputs 50 - 8 with relative and immediate operands
+        intcode.load_program(vec![109, 10, 21110, 50, 8, 2, 4, 12, 99, 0, 0, 0, 0]);
+        assert_eq!(intcode.run(), Ok(IntcodeState::Halted));
+        assert_eq!(intcode.take_outputs(), vec![42]);
+    }
+    #[test]
+    fn handlers_can_jump_and_halt() {
+        let mut registry = registry();
+        registry
+            .register(12, "stop", &[], |_, _| Ok(Control::Halt))
+            .unwrap();
+        let mut intcode = Intcode::new();
+        intcode.set_extensions(Some(registry));
+        // Outputs [8] three times around the loop, then stops on the extension
+        intcode.load_program(vec![4, 8, 1011, 7, 0, 12, 99, 2, 5]);
+        assert_eq!(intcode.run(), Ok(IntcodeState::Halted));
+        assert_eq!(intcode.take_outputs(), vec![5, 5, 5]);
+        assert_eq!(intcode.get_ip(), 5);
+        assert_eq!(intcode.peek(7), Ok(0));
+    }
+    #[test]
+    fn unregistered_opcodes_still_fault() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![10, 0, 0, 0, 99]);
+        assert_eq!(
+            intcode.run(),
+            Err(IntcodeError::UnknownOpcode { addr: 0, value: 10 })
+        );
+        // Forks carry the instruction set with them
+        let mut parent = Intcode::new();
+        parent.set_extensions(Some(registry()));
+        let mut fork = parent.fork();
+        fork.load_program(vec![1110, 7, 2, 5, 4, 5, 99]);
+        assert_eq!(fork.run(), Ok(IntcodeState::Halted));
+        assert_eq!(fork.take_outputs(), vec![5]);
+    }
+    #[test]
+    fn builtin_and_out_of_range_opcodes_are_refused() {
+        let mut registry: OpcodeRegistry = OpcodeRegistry::new();
+        let nop = |_: &mut Intcode, _: &[Operand<i64>]| Ok(Control::Next);
+        assert_eq!(
+            registry.register(1, "add", &[], nop),
+            Err(RegistryError::Builtin(1))
+        );
+        assert_eq!(
+            registry.register(100, "big", &[], nop),
+            Err(RegistryError::OutOfRange(100))
+        );
+        assert_eq!(
+            registry.register(20, "wide", &[Param::Read; 4], nop),
+            Err(RegistryError::TooManyParameters {
+                opcode: 20,
+                count: 4
+            })
+        );
+        assert!(registry.opcodes().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+use super::intcode::{Intcode, IntcodeError, IntcodeState};
+use super::word::Word;
+use std::sync::mpsc::{
+    self, Receiver, RecvError, RecvTimeoutError, SendError, Sender, TryRecvError,
+};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+// A machine running on its own thread, fed and drained through channels.
+// Dropping the input side lets a machine waiting for input finish cleanly.
+#[derive(Debug)]
+pub struct IntcodeHandle<W: Word = i64> {
+    input: Option<Sender<W>>,
+    output: Receiver<W>,
+    thread: JoinHandle<Result<IntcodeState, IntcodeError>>,
+}
+
+impl<W: Word> IntcodeHandle<W> {
+    // Fails once the machine has stopped and can take no more input
+    pub fn send(&self, value: W) -> Result<(), SendError<W>> {
+        match &self.input {
+            Some(input) => input.send(value),
+            None => Err(SendError(value)),
+        }
+    }
+    // Blocks for the next output, an error means the machine has stopped
+    pub fn recv(&self) -> Result<W, RecvError> {
+        self.output.recv()
+    }
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<W, RecvTimeoutError> {
+        self.output.recv_timeout(timeout)
+    }
+    pub fn try_recv(&self) -> Result<W, TryRecvError> {
+        self.output.try_recv()
+    }
+    // No more input is coming, the machine stops the next time it asks for some
+    pub fn close_input(&mut self) {
+        self.input = None;
+    }
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+    // Closes the input and waits for the machine to stop. Outputs not yet
+    // received are dropped, a panic on the machine thread is passed on.
+    pub fn join(mut self) -> Result<IntcodeState, IntcodeError> {
+        self.close_input();
+        match self.thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+impl<W: Word> Intcode<W> {
+    pub fn spawn(program: Vec<W>) -> IntcodeHandle<W> {
+        let mut intcode = Intcode::default();
+        intcode.load_program(program);
+        intcode.into_thread()
+    }
+    // Moves an already configured machine, e.g. with queued inputs, onto a thread
+    pub fn into_thread(mut self) -> IntcodeHandle<W> {
+        let (input, mut machine_input) = mpsc::channel();
+        let (mut machine_output, output) = mpsc::channel();
+        let thread = thread::spawn(move || self.run_with(&mut machine_input, &mut machine_output));
+        IntcodeHandle {
+            input: Some(input),
+            output,
+            thread,
+        }
+    }
+}
+
+#[cfg(test)]
+mod handle_tests {
+    use super::*;
+
+    // Echoes inputs until a zero comes in
+    fn echo_program() -> Vec<i64> {
+        vec![3, 9, 4, 9, 1005, 9, 0, 99, 0, 0]
+    }
+
+    #[test]
+    fn send_and_receive_across_threads() {
+        let handle = Intcode::spawn(echo_program());
+        handle.send(5).unwrap();
+        assert_eq!(handle.recv(), Ok(5));
+        handle.send(7).unwrap();
+        assert_eq!(handle.recv_timeout(Duration::from_secs(5)), Ok(7));
+        handle.send(0).unwrap();
+        assert_eq!(handle.recv(), Ok(0));
+        // The machine halted and hung up its output
+        assert_eq!(handle.recv(), Err(RecvError));
+        assert_eq!(handle.join(), Ok(IntcodeState::Halted));
+    }
+    #[test]
+    fn nothing_to_receive_while_waiting_for_input() {
+        let handle = Intcode::spawn(echo_program());
+        assert_eq!(handle.try_recv(), Err(TryRecvError::Empty));
+        assert_eq!(
+            handle.recv_timeout(Duration::from_millis(10)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        // Dropping the input lets the waiting machine finish
+        assert_eq!(handle.join(), Ok(IntcodeState::WaitingForInput));
+    }
+    #[test]
+    fn configured_machines_keep_their_inputs() {
+        let mut intcode = Intcode::new();
+        // Day 7 dual input example, outputs 10 * second + first
+        intcode.load_program(vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ]);
+        intcode.push_input(4);
+        let handle = intcode.into_thread();
+        handle.send(3).unwrap();
+        assert_eq!(handle.recv(), Ok(34));
+        assert_eq!(handle.join(), Ok(IntcodeState::Halted));
+    }
+    #[test]
+    fn faults_come_back_through_join() {
+        let mut handle = Intcode::spawn(vec![42]);
+        assert_eq!(handle.recv(), Err(RecvError));
+        handle.close_input();
+        assert_eq!(handle.send(1), Err(SendError(1)));
+        assert_eq!(
+            handle.join(),
+            Err(IntcodeError::UnknownOpcode { addr: 0, value: 42 })
+        );
+    }
+    #[test]
+    fn day_07_feedback_loop_on_threads() {
+        let program = vec![
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        let amps: Vec<IntcodeHandle> = vec![9, 8, 7, 6, 5]
+            .into_iter()
+            .map(|phase| {
+                let handle = Intcode::spawn(program.clone());
+                handle.send(phase).unwrap();
+                handle
+            })
+            .collect();
+        // Pass the signal round the ring until the last amp hangs up
+        let mut signal = 0;
+        'feedback: loop {
+            for amp in &amps {
+                if amp.send(signal).is_err() {
+                    break 'feedback;
+                }
+                signal = match amp.recv() {
+                    Ok(value) => value,
+                    Err(_) => break 'feedback,
+                };
+            }
+        }
+        assert_eq!(signal, 139629729);
+        for amp in amps {
+            assert_eq!(amp.join(), Ok(IntcodeState::Halted));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use super::intcode::{Intcode, IntcodeError, IntcodeState, RunOutcome};
+use super::word::Word;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::sync::mpsc::{Receiver, Sender, SyncSender};
+
+// Where a machine pulls its input from, None means nothing more is coming
+pub trait InputSource<W: Word = i64> {
+    fn next_input(&mut self) -> Option<W>;
+}
+
+// Where a machine pushes its output to, called once per emitted value
+pub trait OutputSink<W: Word = i64> {
+    fn send_output(&mut self, value: W);
+}
+
+impl<W: Word> InputSource<W> for VecDeque<W> {
+    fn next_input(&mut self) -> Option<W> {
+        self.pop_front()
+    }
+}
+
+impl<W: Word> OutputSink<W> for Vec<W> {
+    fn send_output(&mut self, value: W) {
+        self.push(value);
+    }
+}
+
+impl<W: Word> OutputSink<W> for VecDeque<W> {
+    fn send_output(&mut self, value: W) {
+        self.push_back(value);
+    }
+}
+
+// Blocks until a value arrives, a hung up sender ends the input
+impl<W: Word> InputSource<W> for Receiver<W> {
+    fn next_input(&mut self) -> Option<W> {
+        self.recv().ok()
+    }
+}
+
+// A hung up receiver simply stops listening, the machine keeps running
+impl<W: Word> OutputSink<W> for Sender<W> {
+    fn send_output(&mut self, value: W) {
+        let _ = self.send(value);
+    }
+}
+
+impl<W: Word> OutputSink<W> for SyncSender<W> {
+    fn send_output(&mut self, value: W) {
+        let _ = self.send(value);
+    }
+}
+
+pub struct InputFn<F>(pub F);
+
+impl<W: Word, F: FnMut() -> Option<W>> InputSource<W> for InputFn<F> {
+    fn next_input(&mut self) -> Option<W> {
+        (self.0)()
+    }
+}
+
+pub struct OutputFn<F>(pub F);
+
+impl<W: Word, F: FnMut(W)> OutputSink<W> for OutputFn<F> {
+    fn send_output(&mut self, value: W) {
+        (self.0)(value)
+    }
+}
+
+// Reads comma or whitespace separated integers, input ends at EOF or on a bad value
+#[derive(Debug, Default)]
+pub struct StdinInput<W: Word = i64> {
+    pending: VecDeque<W>,
+}
+
+impl<W: Word> StdinInput<W> {
+    pub fn new() -> StdinInput<W> {
+        StdinInput::default()
+    }
+}
+
+impl<W: Word> InputSource<W> for StdinInput<W> {
+    fn next_input(&mut self) -> Option<W> {
+        while self.pending.is_empty() {
+            let mut line = String::new();
+            if 0 == std::io::stdin().lock().read_line(&mut line).ok()? {
+                return None;
+            }
+            for token in line
+                .split(|c: char| ',' == c || c.is_whitespace())
+                .filter(|t| !t.is_empty())
+            {
+                self.pending.push_back(token.parse().ok()?);
+            }
+        }
+        self.pending.pop_front()
+    }
+}
+
+// Prints one value per line
+#[derive(Debug, Default)]
+pub struct StdoutOutput;
+
+impl<W: Word> OutputSink<W> for StdoutOutput {
+    fn send_output(&mut self, value: W) {
+        println!("{}", value);
+    }
+}
+
+impl<W: Word> Intcode<W> {
+    // Runs until halted, faulted or the input source runs dry. Outputs are
+    // handed over as they are emitted so the source can react to them.
+    pub fn run_with(
+        &mut self,
+        input: &mut impl InputSource<W>,
+        output: &mut impl OutputSink<W>,
+    ) -> Result<IntcodeState, IntcodeError> {
+        loop {
+            let state = self.run_until_output(1)?;
+            for value in self.drain_outputs() {
+                output.send_output(value);
+            }
+            match state {
+                IntcodeState::OutputReady => (),
+                IntcodeState::WaitingForInput => match input.next_input() {
+                    Some(value) => self.push_input(value),
+                    None => return Ok(state),
+                },
+                _ => return Ok(state),
+            }
+        }
+    }
+    // Like run_with, but gives up once `max_steps` instructions have executed.
+    // Outputs are handed over whenever the machine stops, so before it asks
+    // for the next input.
+    pub fn run_with_limit(
+        &mut self,
+        input: &mut impl InputSource<W>,
+        output: &mut impl OutputSink<W>,
+        max_steps: u64,
+    ) -> Result<RunOutcome, IntcodeError> {
+        let limit = self.steps_executed().saturating_add(max_steps);
+        loop {
+            let remaining = limit.saturating_sub(self.steps_executed());
+            let outcome = self.run_with_budget(remaining as usize)?;
+            for value in self.drain_outputs() {
+                output.send_output(value);
+            }
+            match outcome {
+                RunOutcome::Stopped(IntcodeState::WaitingForInput) => match input.next_input() {
+                    Some(value) => self.push_input(value),
+                    None => return Ok(outcome),
+                },
+                _ => return Ok(outcome),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod io_tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::mpsc;
+    use std::thread;
+
+    // Day 7 dual input example, outputs 10 * second + first
+    fn dual_input_program() -> Vec<i64> {
+        vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ]
+    }
+
+    #[test]
+    fn run_with_vectors() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(dual_input_program());
+        let mut input = VecDeque::from(vec![0, 4]);
+        let mut output = Vec::new();
+        let state = intcode.run_with(&mut input, &mut output);
+        assert_eq!(state, Ok(IntcodeState::Halted));
+        assert_eq!(output, vec![40]);
+    }
+    #[test]
+    fn run_with_stops_when_input_runs_dry() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(dual_input_program());
+        let mut input = VecDeque::from(vec![0]);
+        let mut output = Vec::new();
+        let state = intcode.run_with(&mut input, &mut output);
+        assert_eq!(state, Ok(IntcodeState::WaitingForInput));
+        assert!(output.is_empty());
+    }
+    #[test]
+    fn run_with_closures_sees_each_output() {
+        let mut intcode = Intcode::new();
+        // Echo inputs until a zero comes in
+        intcode.load_program(vec![3, 9, 4, 9, 1005, 9, 0, 99, 0, 0]);
+        let last = Cell::new(3);
+        let mut input = InputFn(|| Some(last.get() - 1));
+        let mut seen = Vec::new();
+        let mut output = OutputFn(|value| {
+            last.set(value);
+            seen.push(value);
+        });
+        let state = intcode.run_with(&mut input, &mut output);
+        assert_eq!(state, Ok(IntcodeState::Halted));
+        assert_eq!(seen, vec![2, 1, 0]);
+    }
+    #[test]
+    fn run_with_channels() {
+        let (to_machine, machine_input) = mpsc::channel();
+        let (machine_output, from_machine) = mpsc::channel();
+        let handle = thread::spawn(move || {
+            let mut intcode = Intcode::new();
+            intcode.load_program(dual_input_program());
+            let mut input = machine_input;
+            let mut output = machine_output;
+            intcode.run_with(&mut input, &mut output)
+        });
+        to_machine.send(3).unwrap();
+        to_machine.send(7).unwrap();
+        assert_eq!(from_machine.recv(), Ok(73));
+        assert_eq!(handle.join().unwrap(), Ok(IntcodeState::Halted));
+    }
+    #[test]
+    fn run_with_limit_stops_runaway_programs() {
+        let mut intcode = Intcode::new();
+        // Echoes one input, then spins on a jump to itself
+        intcode.load_program(vec![3, 9, 4, 9, 1105, 1, 4, 99, 0, 0]);
+        let mut input = VecDeque::from(vec![5]);
+        let mut output = Vec::new();
+        let outcome = intcode.run_with_limit(&mut input, &mut output, 100);
+        assert_eq!(outcome, Ok(RunOutcome::BudgetExhausted { steps: 100 }));
+        assert_eq!(output, vec![5]);
+        let mut intcode = Intcode::new();
+        intcode.load_program(dual_input_program());
+        let mut input = VecDeque::from(vec![0, 4]);
+        let outcome = intcode.run_with_limit(&mut input, &mut output, 100);
+        assert_eq!(outcome, Ok(RunOutcome::Stopped(IntcodeState::Halted)));
+        assert_eq!(output, vec![5, 40]);
+    }
+}
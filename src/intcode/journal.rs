@@ -0,0 +1,261 @@
+use super::intcode::{Intcode, IntcodeState};
+use super::word::Word;
+use std::collections::VecDeque;
+
+// Everything needed to put the machine back the way it was before one step
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry<W: Word> {
+    pub ip: usize,
+    pub relative_base: W,
+    pub state: IntcodeState,
+    pub writes: Vec<(usize, W)>,  // (address, value before the write)
+    pub wide: Vec<(usize, i128)>, // (address, exact value before the write) of wide cells
+    pub input: Option<W>,
+    pub output: Option<W>,
+    pub highest_touched: Option<usize>,
+    pub steps: u64,
+}
+
+// Undo log for reverse stepping, only the newest `window` steps are kept
+#[derive(Debug, Clone)]
+pub struct Journal<W: Word> {
+    entries: VecDeque<JournalEntry<W>>,
+    window: usize,
+    current: Option<JournalEntry<W>>,
+}
+
+impl<W: Word> Journal<W> {
+    pub fn new(window: usize) -> Journal<W> {
+        Journal {
+            entries: VecDeque::new(),
+            window,
+            current: None,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn window(&self) -> usize {
+        self.window
+    }
+    pub fn entries(&self) -> &VecDeque<JournalEntry<W>> {
+        &self.entries
+    }
+    pub(super) fn begin(
+        &mut self,
+        ip: usize,
+        relative_base: W,
+        state: IntcodeState,
+        highest_touched: Option<usize>,
+        steps: u64,
+    ) {
+        self.current = Some(JournalEntry {
+            ip,
+            relative_base,
+            state,
+            writes: Vec::new(),
+            wide: Vec::new(),
+            input: None,
+            output: None,
+            highest_touched,
+            steps,
+        });
+    }
+    pub(super) fn write(&mut self, addr: usize, previous: W, wide: Option<i128>) {
+        if let Some(entry) = &mut self.current {
+            entry.writes.push((addr, previous));
+            entry.wide.extend(wide.map(|value| (addr, value)));
+        }
+    }
+    pub(super) fn input(&mut self, value: W) {
+        if let Some(entry) = &mut self.current {
+            entry.input = Some(value);
+        }
+    }
+    pub(super) fn output(&mut self, value: W) {
+        if let Some(entry) = &mut self.current {
+            entry.output = Some(value);
+        }
+    }
+    pub(super) fn discard(&mut self) {
+        self.current = None;
+    }
+    pub(super) fn commit(&mut self) {
+        if let Some(entry) = self.current.take() {
+            self.entries.push_back(entry);
+            while self.entries.len() > self.window {
+                self.entries.pop_front();
+            }
+        }
+    }
+    fn pop(&mut self) -> Option<JournalEntry<W>> {
+        self.entries.pop_back()
+    }
+}
+
+impl<W: Word> Intcode<W> {
+    // Start recording undo information for at most `window` steps
+    pub fn enable_journal(&mut self, window: usize) {
+        self.journal = Some(Journal::new(window));
+    }
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+    pub fn journal(&self) -> Option<&Journal<W>> {
+        self.journal.as_ref()
+    }
+    // Undoes up to `count` steps, returns how many were actually undone.
+    // Outputs the caller already took from the buffer stay taken.
+    pub fn step_back(&mut self, count: usize) -> usize {
+        for undone in 0..count {
+            let entry = match self.journal.as_mut().and_then(|j| j.pop()) {
+                Some(entry) => entry,
+                None => return undone,
+            };
+            self.undo(entry);
+        }
+        count
+    }
+    // Steps back to the most recent time the ip was at `addr`. Returns the
+    // number of steps undone, or None and leaves the machine alone if the
+    // journal does not reach that far.
+    pub fn run_back_to(&mut self, addr: usize) -> Option<usize> {
+        let entries = self.journal.as_ref()?.entries();
+        let position = entries.iter().rposition(|entry| entry.ip == addr)?;
+        let count = entries.len() - position;
+        Some(self.step_back(count))
+    }
+    fn undo(&mut self, entry: JournalEntry<W>) {
+        for (addr, previous) in entry.writes.into_iter().rev() {
+            self.memory.write(addr, previous);
+        }
+        for (addr, value) in entry.wide {
+            self.memory.write_wide(addr, value);
+        }
+        if let Some(value) = entry.input {
+            self.input.push_front(value);
+        }
+        if let Some(value) = entry.output {
+            if Some(&value) == self.output.back() {
+                self.output.pop_back();
+            }
+        }
+        self.ip = entry.ip;
+        self.relative_base = entry.relative_base;
+        self.state = entry.state;
+        self.memory.set_highest_touched(entry.highest_touched);
+        self.steps = entry.steps;
+        // Rewound cells may hold different code, and a re-fetch keeps highest_touched exact
+        self.clear_decode_cache();
+    }
+}
+
+#[cfg(test)]
+mod journal_tests {
+    use super::*;
+    use crate::intcode::intcode::OverflowPolicy;
+
+    fn countdown() -> Vec<i64> {
+        // in [12]; out [12]; add [12], #-1, [12]; jnz [12], #2; hlt; .data 0
+        vec![3, 12, 4, 12, 1001, 12, -1, 12, 1005, 12, 2, 99, 0]
+    }
+
+    #[test]
+    fn step_back_restores_exact_state() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(countdown());
+        intcode.enable_journal(100);
+        intcode.push_input(2);
+        let before = intcode.to_snapshot();
+        intcode.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![2, 1]);
+        // Undo everything, outputs were already taken by us
+        let undone = intcode.step_back(1000);
+        assert_eq!(undone, 8);
+        assert_eq!(intcode.to_snapshot(), before);
+        assert_eq!(intcode.get_ip(), 0);
+        assert_eq!(intcode.pending_inputs().len(), 1);
+        assert_eq!(intcode.peek(12).unwrap(), 0);
+        assert_eq!(intcode.get_state(), IntcodeState::Ready);
+        // Replaying gives the same answer
+        intcode.run().unwrap();
+        assert_eq!(intcode.take_outputs(), vec![2, 1]);
+    }
+    #[test]
+    fn step_back_removes_buffered_outputs() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![104, 1, 104, 2, 99]);
+        intcode.enable_journal(10);
+        intcode.run().unwrap();
+        assert_eq!(intcode.step_back(2), 2);
+        assert_eq!(intcode.take_outputs(), vec![1]);
+        assert_eq!(intcode.get_state(), IntcodeState::OutputReady);
+    }
+    #[test]
+    fn run_back_to_finds_the_latest_visit() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(countdown());
+        intcode.enable_journal(100);
+        intcode.push_input(3);
+        intcode.run().unwrap();
+        // Last time through the loop the counter was 1 when we were at the add
+        assert_eq!(intcode.run_back_to(4), Some(3));
+        assert_eq!(intcode.get_ip(), 4);
+        assert_eq!(intcode.peek(12).unwrap(), 1);
+        assert_eq!(intcode.run_back_to(42), None);
+        assert_eq!(intcode.get_ip(), 4);
+    }
+    #[test]
+    fn step_back_out_of_a_fault() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![1101, 20, 22, 4, 0]);
+        intcode.enable_journal(10);
+        assert!(intcode.run().is_err());
+        assert_eq!(intcode.step_back(1), 1);
+        assert_eq!(intcode.get_state(), IntcodeState::Ready);
+        assert_eq!(intcode.get_ip(), 4);
+        assert_eq!(intcode.step_back(1), 1);
+        assert_eq!(intcode.peek(4).unwrap(), 0);
+        assert_eq!(intcode.get_ip(), 0);
+    }
+    #[test]
+    fn window_bounds_the_journal() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(countdown());
+        intcode.enable_journal(3);
+        intcode.push_input(5);
+        intcode.run().unwrap();
+        assert_eq!(intcode.journal().unwrap().len(), 3);
+        assert_eq!(intcode.step_back(10), 3);
+        assert_eq!(intcode.journal().unwrap().len(), 0);
+    }
+    #[test]
+    fn stepping_a_halted_machine_records_nothing() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![104, 1, 99]);
+        intcode.enable_journal(10);
+        intcode.run().unwrap();
+        intcode.run().unwrap();
+        assert_eq!(intcode.journal().unwrap().len(), 2);
+        intcode.step().unwrap();
+        intcode.step().unwrap();
+        assert_eq!(intcode.journal().unwrap().len(), 2);
+    }
+    #[test]
+    fn step_back_restores_wide_cells() {
+        // [9] = [8] * [8]; [9] = [8] + [8]
+        let mut intcode = Intcode::new();
+        intcode.set_overflow_policy(OverflowPolicy::Widened);
+        intcode.load_program(vec![2, 8, 8, 9, 1, 8, 8, 9, i64::MAX, 0]);
+        intcode.enable_journal(10);
+        intcode.run_steps(1).unwrap();
+        let wide = intcode.to_snapshot();
+        intcode.run_steps(1).unwrap();
+        assert_eq!(intcode.memory.wide(9), Some(i64::MAX as i128 * 2));
+        intcode.step_back(1);
+        assert_eq!(intcode.to_snapshot(), wide);
+    }
+}
@@ -0,0 +1,248 @@
+use super::word::Word;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub const PAGE_SIZE: usize = 1024;
+// Pages below this are indexed directly, which covers any real program and its
+// heap. Stray addresses beyond it go in a map so they cannot grow the table.
+const DIRECT_PAGES: usize = 4096;
+
+type Page<W> = [W; PAGE_SIZE];
+
+// Sparse memory, pages are only allocated the first time something is written to them.
+// Clones share their pages until one side writes, which copies just that page.
+#[derive(Debug, Clone, Default)]
+pub struct Memory<W: Word> {
+    pages: Vec<Option<Arc<Page<W>>>>, // By page number, up to DIRECT_PAGES
+    far_pages: HashMap<usize, Arc<Page<W>>>,
+    limit: Option<usize>, // Optional cap on the address space, None grows forever
+    highest_touched: Option<usize>,
+    generation: u64, // Bumped by every write, so cached views of memory can tell they are stale
+    last_write: Option<usize>,
+    wide: HashMap<usize, i128>, // Exact values of cells too wide for W, see OverflowPolicy::Widened
+}
+
+impl<W: Word> Memory<W> {
+    pub fn new() -> Memory<W> {
+        Memory::default()
+    }
+    pub fn from_program(program: &[W]) -> Memory<W> {
+        let mut memory = Memory::new();
+        for (addr, value) in program.iter().enumerate() {
+            if W::ZERO != *value {
+                memory.page_mut(addr)[addr % PAGE_SIZE] = *value;
+            }
+        }
+        memory
+    }
+    pub fn get_limit(&self) -> Option<usize> {
+        self.limit
+    }
+    pub fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+    }
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+    pub(super) fn last_write(&self) -> Option<usize> {
+        self.last_write
+    }
+    // Memory that replaces this one carries on counting from it
+    pub(super) fn succeed(&mut self, previous: &Memory<W>) {
+        self.generation = previous.generation + 1;
+    }
+    pub fn highest_touched(&self) -> Option<usize> {
+        self.highest_touched
+    }
+    pub(super) fn set_highest_touched(&mut self, addr: Option<usize>) {
+        self.highest_touched = addr;
+    }
+    pub fn allocated_pages(&self) -> usize {
+        self.all_pages().count()
+    }
+    // Pages still shared with a clone, not yet copied by a write on either side
+    pub fn shared_pages(&self) -> usize {
+        self.all_pages()
+            .filter(|(_, page)| Arc::strong_count(page) > 1)
+            .count()
+    }
+    // Allocated pages in address order as (first address, cells)
+    pub fn pages(&self) -> Vec<(usize, &[W])> {
+        let mut pages: Vec<(usize, &[W])> = self
+            .all_pages()
+            .map(|(index, page)| (index * PAGE_SIZE, &page[..]))
+            .collect();
+        pages.sort_by_key(|(addr, _)| *addr);
+        pages
+    }
+    // The exact value of a cell whose last write did not fit in a word
+    pub fn wide(&self, addr: usize) -> Option<i128> {
+        if self.wide.is_empty() {
+            return None;
+        }
+        self.wide.get(&addr).copied()
+    }
+    // Wide cells in address order
+    pub fn wide_cells(&self) -> Vec<(usize, i128)> {
+        let mut cells: Vec<(usize, i128)> = self.wide.iter().map(|(&a, &v)| (a, v)).collect();
+        cells.sort_unstable();
+        cells
+    }
+    // Values that do not fit are stored clamped, with the exact value kept aside
+    // until the next write to the cell
+    pub(super) fn write_wide(&mut self, addr: usize, value: i128) -> Option<()> {
+        match W::from_i128(value) {
+            Some(word) => self.write(addr, word),
+            None => {
+                self.write(addr, if value < 0 { W::MIN } else { W::MAX })?;
+                self.wide.insert(addr, value);
+                Some(())
+            }
+        }
+    }
+    fn all_pages(&self) -> impl Iterator<Item = (usize, &Arc<Page<W>>)> {
+        let direct = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, page)| page.as_ref().map(|page| (index, page)));
+        direct.chain(self.far_pages.iter().map(|(index, page)| (*index, page)))
+    }
+    // Untouched memory reads as zero, None means the address is past the limit
+    pub fn read(&mut self, addr: usize) -> Option<W> {
+        let value = self.get(addr)?;
+        self.touch(addr);
+        Some(value)
+    }
+    // Like read, but only looking: the highest address touched is left alone
+    pub fn get(&self, addr: usize) -> Option<W> {
+        if !self.in_range(addr) {
+            return None;
+        }
+        let index = addr / PAGE_SIZE;
+        let page = match self.pages.get(index) {
+            Some(page) => page.as_ref(),
+            None if index >= DIRECT_PAGES => self.far_pages.get(&index),
+            None => None,
+        };
+        Some(page.map_or(W::ZERO, |page| page[addr % PAGE_SIZE]))
+    }
+    pub fn write(&mut self, addr: usize, value: W) -> Option<()> {
+        if !self.in_range(addr) {
+            return None;
+        }
+        self.touch(addr);
+        self.page_mut(addr)[addr % PAGE_SIZE] = value;
+        if !self.wide.is_empty() {
+            self.wide.remove(&addr);
+        }
+        self.generation += 1;
+        self.last_write = Some(addr);
+        Some(())
+    }
+    fn in_range(&self, addr: usize) -> bool {
+        match self.limit {
+            Some(limit) => addr < limit,
+            None => true,
+        }
+    }
+    fn touch(&mut self, addr: usize) {
+        if self.highest_touched < Some(addr) {
+            self.highest_touched = Some(addr);
+        }
+    }
+    fn page_mut(&mut self, addr: usize) -> &mut Page<W> {
+        let index = addr / PAGE_SIZE;
+        let fresh = || Arc::new([W::ZERO; PAGE_SIZE]);
+        let page = if index < DIRECT_PAGES {
+            if index >= self.pages.len() {
+                self.pages.resize(index + 1, None);
+            }
+            self.pages[index].get_or_insert_with(fresh)
+        } else {
+            self.far_pages.entry(index).or_insert_with(fresh)
+        };
+        Arc::make_mut(page)
+    }
+}
+
+#[cfg(test)]
+mod memory_tests {
+    use super::*;
+
+    #[test]
+    fn untouched_memory_reads_zero() {
+        let mut memory: Memory<i64> = Memory::new();
+        assert_eq!(memory.read(0), Some(0));
+        assert_eq!(memory.read(1 << 40), Some(0));
+        assert_eq!(memory.allocated_pages(), 0);
+    }
+    #[test]
+    fn writes_allocate_pages_on_demand() {
+        let mut memory = Memory::from_program(&[1, 2, 3]);
+        assert_eq!(memory.allocated_pages(), 1);
+        memory.write(PAGE_SIZE * 10 + 3, 42).unwrap();
+        assert_eq!(memory.allocated_pages(), 2);
+        assert_eq!(memory.read(PAGE_SIZE * 10 + 3), Some(42));
+        assert_eq!(memory.read(2), Some(3));
+    }
+    #[test]
+    fn limit_caps_the_address_space() {
+        let mut memory: Memory<i32> = Memory::new();
+        memory.set_limit(Some(16));
+        assert_eq!(memory.write(15, 1), Some(()));
+        assert_eq!(memory.write(16, 1), None);
+        assert_eq!(memory.read(16), None);
+    }
+    #[test]
+    fn highest_touched_tracks_reads_and_writes() {
+        let mut memory = Memory::from_program(&[1, 2, 3]);
+        assert_eq!(memory.highest_touched(), None);
+        memory.read(7);
+        assert_eq!(memory.highest_touched(), Some(7));
+        memory.write(3, 1);
+        assert_eq!(memory.highest_touched(), Some(7));
+        memory.write(5000, 1);
+        assert_eq!(memory.highest_touched(), Some(5000));
+        assert_eq!(memory.generation(), 2);
+        // Looking is not touching
+        assert_eq!(memory.get(6000), Some(0));
+        assert_eq!(memory.highest_touched(), Some(5000));
+    }
+    #[test]
+    fn far_pages_do_not_grow_the_page_table() {
+        let mut memory = Memory::from_program(&[1, 2, 3]);
+        let far = PAGE_SIZE * DIRECT_PAGES * 1000;
+        memory.write(far + 1, 7).unwrap();
+        assert_eq!(memory.pages.len(), 1);
+        assert_eq!(memory.read(far + 1), Some(7));
+        assert_eq!(memory.read(far + PAGE_SIZE), Some(0));
+        let firsts: Vec<usize> = memory.pages().iter().map(|(addr, _)| *addr).collect();
+        assert_eq!(firsts, vec![0, far]);
+    }
+    #[test]
+    fn clones_copy_pages_on_write() {
+        let mut memory = Memory::from_program(&[1, 2, 3]);
+        memory.write(PAGE_SIZE, 4).unwrap();
+        let mut clone = memory.clone();
+        assert_eq!(clone.shared_pages(), 2);
+        clone.write(0, 9).unwrap();
+        assert_eq!(clone.shared_pages(), 1);
+        assert_eq!(memory.shared_pages(), 1);
+        assert_eq!(clone.read(0), Some(9));
+        assert_eq!(memory.read(0), Some(1));
+        assert_eq!(clone.read(PAGE_SIZE), Some(4));
+    }
+    #[test]
+    fn wide_values_last_until_the_next_write() {
+        let mut memory: Memory<i32> = Memory::new();
+        memory.write_wide(3, 1 << 40).unwrap();
+        memory.write_wide(4, 7).unwrap();
+        assert_eq!(memory.read(3), Some(i32::MAX));
+        assert_eq!(memory.wide_cells(), vec![(3, 1 << 40)]);
+        let clone = memory.clone();
+        memory.write(3, 1).unwrap();
+        assert_eq!(memory.wide(3), None);
+        assert_eq!(clone.wide(3), Some(1 << 40));
+    }
+}
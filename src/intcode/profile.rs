@@ -0,0 +1,293 @@
+use super::disassembler::mnemonic;
+use super::intcode::{MemoryMode, OverflowPolicy};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+// What the instruction in flight has done so far
+#[derive(Debug, Default)]
+struct Pending {
+    ip: usize,
+    opcode: Option<isize>,
+    modes: [u64; 3],
+    reads: u64,
+    writes: u64,
+    inputs: u64,
+    outputs: u64,
+    overflows: u64,
+}
+
+// Opt-in execution counters, attach with Intcode::set_profiler
+#[derive(Debug, Default)]
+pub struct Profiler {
+    steps: u64,
+    reads: u64,
+    writes: u64,
+    inputs: u64,
+    outputs: u64,
+    overflows: u64,
+    policy: OverflowPolicy, // As of the last instruction executed
+    opcodes: BTreeMap<isize, u64>,
+    addresses: BTreeMap<usize, u64>,
+    modes: [u64; 3],
+    current: Option<Pending>,
+}
+
+fn mode_index(mode: MemoryMode) -> usize {
+    match mode {
+        MemoryMode::PositionMode => 0,
+        MemoryMode::ImmediateMode => 1,
+        MemoryMode::RelativeMode => 2,
+    }
+}
+
+fn percent(count: u64, total: u64) -> f64 {
+    if 0 == total {
+        0.0
+    } else {
+        100.0 * count as f64 / total as f64
+    }
+}
+
+impl Profiler {
+    pub fn new() -> Profiler {
+        Profiler::default()
+    }
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+    // Parameters loaded from memory, immediate values are not reads
+    pub fn reads(&self) -> u64 {
+        self.reads
+    }
+    pub fn writes(&self) -> u64 {
+        self.writes
+    }
+    pub fn inputs(&self) -> u64 {
+        self.inputs
+    }
+    pub fn outputs(&self) -> u64 {
+        self.outputs
+    }
+    // Add and multiply results that did not fit, however the policy handled them
+    pub fn overflows(&self) -> u64 {
+        self.overflows
+    }
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+    pub fn opcode_counts(&self) -> &BTreeMap<isize, u64> {
+        &self.opcodes
+    }
+    pub fn address_hits(&self) -> &BTreeMap<usize, u64> {
+        &self.addresses
+    }
+    pub fn mode_count(&self, mode: MemoryMode) -> u64 {
+        self.modes[mode_index(mode)]
+    }
+    // The `count` most executed addresses, busiest first
+    pub fn hottest(&self, count: usize) -> Vec<(usize, u64)> {
+        let mut hits: Vec<(usize, u64)> = self.addresses.iter().map(|(&a, &h)| (a, h)).collect();
+        hits.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        hits.truncate(count);
+        hits
+    }
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        writeln!(
+            out,
+            "{} steps, {} reads, {} writes, {} inputs, {} outputs",
+            self.steps, self.reads, self.writes, self.inputs, self.outputs
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "{} overflows, {} overflow policy",
+            self.overflows, self.policy
+        )
+        .unwrap();
+        writeln!(out, "\nopcode      count       %").unwrap();
+        let mut opcodes: Vec<(&isize, &u64)> = self.opcodes.iter().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        for (&opcode, &count) in opcodes {
+            let name = mnemonic(opcode).unwrap_or("???");
+            let share = percent(count, self.steps);
+            writeln!(out, "{:>6} {:>10} {:>6.2}%", name, count, share).unwrap();
+        }
+        let total_modes: u64 = self.modes.iter().sum();
+        writeln!(out, "\nmode        count       %").unwrap();
+        for (name, &count) in ["position", "immediate", "relative"]
+            .iter()
+            .zip(&self.modes)
+        {
+            let share = percent(count, total_modes);
+            writeln!(out, "{:>9} {:>7} {:>6.2}%", name, count, share).unwrap();
+        }
+        writeln!(out, "\naddress      hits       %").unwrap();
+        for (addr, hits) in self.hottest(10) {
+            let share = percent(hits, self.steps);
+            writeln!(out, "{:>7} {:>9} {:>6.2}%", addr, hits, share).unwrap();
+        }
+        out
+    }
+    // One `<kind> [key] <count>` record per line, for scripts and spreadsheets
+    pub fn dump(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "steps {}", self.steps).unwrap();
+        writeln!(out, "reads {}", self.reads).unwrap();
+        writeln!(out, "writes {}", self.writes).unwrap();
+        writeln!(out, "inputs {}", self.inputs).unwrap();
+        writeln!(out, "outputs {}", self.outputs).unwrap();
+        writeln!(out, "overflows {}", self.overflows).unwrap();
+        writeln!(out, "policy {}", self.policy).unwrap();
+        for (opcode, count) in &self.opcodes {
+            writeln!(out, "opcode {} {}", opcode, count).unwrap();
+        }
+        for (name, count) in ["P", "I", "R"].iter().zip(&self.modes) {
+            writeln!(out, "mode {} {}", name, count).unwrap();
+        }
+        for (addr, hits) in &self.addresses {
+            writeln!(out, "addr {} {}", addr, hits).unwrap();
+        }
+        out
+    }
+    pub(crate) fn begin(&mut self, ip: usize, policy: OverflowPolicy) {
+        self.policy = policy;
+        self.current = Some(Pending {
+            ip,
+            ..Pending::default()
+        });
+    }
+    pub(crate) fn decoded(&mut self, opcode: isize, modes: &[MemoryMode]) {
+        if let Some(pending) = &mut self.current {
+            pending.opcode = Some(opcode);
+            for &mode in modes {
+                pending.modes[mode_index(mode)] += 1;
+            }
+        }
+    }
+    pub(crate) fn read(&mut self, mode: MemoryMode) {
+        if let Some(pending) = &mut self.current {
+            if MemoryMode::ImmediateMode != mode {
+                pending.reads += 1;
+            }
+        }
+    }
+    pub(crate) fn write(&mut self) {
+        if let Some(pending) = &mut self.current {
+            pending.writes += 1;
+        }
+    }
+    pub(crate) fn input(&mut self) {
+        if let Some(pending) = &mut self.current {
+            pending.inputs += 1;
+        }
+    }
+    pub(crate) fn output(&mut self) {
+        if let Some(pending) = &mut self.current {
+            pending.outputs += 1;
+        }
+    }
+    pub(crate) fn overflow(&mut self) {
+        if let Some(pending) = &mut self.current {
+            pending.overflows += 1;
+        }
+    }
+    // The instruction starved or faulted, only completed instructions are counted
+    pub(crate) fn discard(&mut self) {
+        self.current = None;
+    }
+    pub(crate) fn finish(&mut self) {
+        let pending = match self.current.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+        self.steps += 1;
+        self.reads += pending.reads;
+        self.writes += pending.writes;
+        self.inputs += pending.inputs;
+        self.outputs += pending.outputs;
+        self.overflows += pending.overflows;
+        if let Some(opcode) = pending.opcode {
+            *self.opcodes.entry(opcode).or_insert(0) += 1;
+        }
+        *self.addresses.entry(pending.ip).or_insert(0) += 1;
+        for (total, count) in self.modes.iter_mut().zip(&pending.modes) {
+            *total += count;
+        }
+    }
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use super::*;
+    use crate::intcode::intcode::Intcode;
+
+    #[test]
+    fn counts_match_the_executed_program() {
+        let mut intcode = Intcode::new();
+        // Read a number, double it three times in a loop, print it
+        intcode.load_program(vec![
+            3, 20, 1002, 20, 2, 20, 1001, 21, -1, 21, 1005, 21, 2, 4, 20, 99, 0, 0, 0, 0, 0, 3,
+        ]);
+        intcode.set_profiler(Some(Profiler::new()));
+        intcode.run().unwrap();
+        // Starved input is not counted
+        assert_eq!(intcode.profiler().unwrap().steps(), 0);
+        intcode.push_input(5);
+        intcode.run().unwrap();
+        assert_eq!(intcode.last_output(), Some(40));
+        let profiler = intcode.take_profiler().unwrap();
+        assert_eq!(profiler.steps(), intcode.steps_executed());
+        assert_eq!(profiler.steps(), 12);
+        let opcodes: Vec<(isize, u64)> = profiler
+            .opcode_counts()
+            .iter()
+            .map(|(&o, &c)| (o, c))
+            .collect();
+        assert_eq!(
+            opcodes,
+            vec![(1, 3), (2, 3), (3, 1), (4, 1), (5, 3), (99, 1)]
+        );
+        assert_eq!(profiler.hottest(2), vec![(2, 3), (6, 3)]);
+        assert_eq!(profiler.address_hits()[&0], 1);
+        // One memory read per mul, add and jnz each pass, plus one for the output
+        assert_eq!(profiler.reads(), 10);
+        assert_eq!(profiler.writes(), 7);
+        assert_eq!(profiler.inputs(), 1);
+        assert_eq!(profiler.outputs(), 1);
+        assert_eq!(profiler.mode_count(MemoryMode::PositionMode), 17);
+        assert_eq!(profiler.mode_count(MemoryMode::ImmediateMode), 9);
+        assert_eq!(profiler.mode_count(MemoryMode::RelativeMode), 0);
+    }
+    #[test]
+    fn dump_lists_every_counter() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![109, 1, 204, -1, 99]);
+        intcode.set_profiler(Some(Profiler::new()));
+        intcode.run().unwrap();
+        assert_eq!(
+            intcode.profiler().unwrap().dump(),
+            "steps 3\nreads 1\nwrites 0\ninputs 0\noutputs 1\noverflows 0\npolicy wrapping\n\
+             opcode 4 1\nopcode 9 1\nopcode 99 1\n\
+             mode P 0\nmode I 1\nmode R 1\n\
+             addr 0 1\naddr 2 1\naddr 4 1\n"
+        );
+        let report = intcode.profiler().unwrap().report();
+        assert!(report.starts_with(
+            "3 steps, 1 reads, 0 writes, 0 inputs, 1 outputs\n0 overflows, wrapping overflow policy\n"
+        ));
+        assert!(report.contains("   out          1  33.33%"));
+    }
+    #[test]
+    fn overflows_are_counted_under_the_current_policy() {
+        let mut intcode = Intcode::new();
+        intcode.set_overflow_policy(OverflowPolicy::Saturating);
+        intcode.load_program(vec![1102, i64::MAX, 3, 7, 4, 7, 99, 0]);
+        intcode.set_profiler(Some(Profiler::new()));
+        intcode.run().unwrap();
+        let profiler = intcode.profiler().unwrap();
+        assert_eq!(profiler.overflows(), 1);
+        assert_eq!(profiler.overflow_policy(), OverflowPolicy::Saturating);
+        assert!(profiler.dump().contains("overflows 1\npolicy saturating\n"));
+    }
+}
@@ -0,0 +1,311 @@
+use super::intcode::{Intcode, IntcodeError, IntcodeState};
+use super::word::Word;
+use std::fmt;
+
+pub type MachineId = usize;
+
+// Steps a machine may take before the next one gets a turn
+pub const DEFAULT_QUANTUM: usize = 1000;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BlockedMachine {
+    pub machine: MachineId,
+    pub ip: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SchedulerError {
+    Fault {
+        machine: MachineId,
+        error: IntcodeError,
+    },
+    // Nobody can make progress, every listed machine waits on an empty queue
+    Deadlock {
+        blocked: Vec<BlockedMachine>,
+    },
+    BudgetExhausted {
+        steps: u64,
+    },
+    // Routed or fed by id, but never added
+    UnknownMachine {
+        machine: MachineId,
+    },
+}
+
+impl fmt::Display for SchedulerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SchedulerError::Fault { machine, error } => {
+                write!(f, "machine {} faulted: {}", machine, error)
+            }
+            SchedulerError::Deadlock { blocked } => {
+                write!(f, "deadlock:")?;
+                for (i, entry) in blocked.iter().enumerate() {
+                    let separator = if 0 == i { " " } else { ", " };
+                    write!(
+                        f,
+                        "{}machine {} waiting at ip {}",
+                        separator, entry.machine, entry.ip
+                    )?;
+                }
+                Ok(())
+            }
+            SchedulerError::BudgetExhausted { steps } => {
+                write!(f, "machines still running after {} steps", steps)
+            }
+            SchedulerError::UnknownMachine { machine } => write!(f, "no machine {}", machine),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerError {}
+
+// Runs many machines round-robin on one thread. Each machine's outputs are
+// either routed into another machine's input queue or left in its own buffer.
+#[derive(Debug)]
+pub struct Scheduler<W: Word = i64> {
+    machines: Vec<Intcode<W>>,
+    routes: Vec<Option<MachineId>>,
+    last_outputs: Vec<Option<W>>,
+    quantum: usize,
+    budget: Option<u64>,
+}
+
+impl<W: Word> Default for Scheduler<W> {
+    fn default() -> Scheduler<W> {
+        Scheduler {
+            machines: Vec::new(),
+            routes: Vec::new(),
+            last_outputs: Vec::new(),
+            quantum: DEFAULT_QUANTUM,
+            budget: None,
+        }
+    }
+}
+
+impl<W: Word> Scheduler<W> {
+    pub fn new() -> Scheduler<W> {
+        Scheduler::default()
+    }
+    pub fn add(&mut self, machine: Intcode<W>) -> MachineId {
+        self.machines.push(machine);
+        self.routes.push(None);
+        self.last_outputs.push(None);
+        self.machines.len() - 1
+    }
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+    // Send everything `from` outputs to the input queue of `to`, None keeps it local
+    pub fn route(&mut self, from: MachineId, to: Option<MachineId>) -> Result<(), SchedulerError> {
+        self.known(from)?;
+        if let Some(to) = to {
+            self.known(to)?;
+        }
+        self.routes[from] = to;
+        Ok(())
+    }
+    pub fn set_quantum(&mut self, quantum: usize) {
+        self.quantum = quantum.max(1);
+    }
+    // Total steps across all machines before run gives up, None runs forever
+    pub fn set_budget(&mut self, budget: Option<u64>) {
+        self.budget = budget;
+    }
+    pub fn machine(&self, id: MachineId) -> Option<&Intcode<W>> {
+        self.machines.get(id)
+    }
+    pub fn machine_mut(&mut self, id: MachineId) -> Option<&mut Intcode<W>> {
+        self.machines.get_mut(id)
+    }
+    pub fn push_input(&mut self, id: MachineId, value: W) -> Result<(), SchedulerError> {
+        self.known(id)?;
+        self.machines[id].push_input(value);
+        Ok(())
+    }
+    // Most recent value the machine emitted, routed or not. None until it
+    // outputs something, or if there is no such machine.
+    pub fn last_output(&self, id: MachineId) -> Option<W> {
+        self.last_outputs.get(id).copied().flatten()
+    }
+    pub fn total_steps(&self) -> u64 {
+        self.machines.iter().map(|m| m.steps_executed()).sum()
+    }
+    // Round-robin until every machine has halted. Machines stuck waiting for
+    // input that nobody will send are reported as a deadlock; feed them and
+    // call run again to carry on.
+    pub fn run(&mut self) -> Result<(), SchedulerError> {
+        let mut steps = 0;
+        loop {
+            let mut progress = false;
+            for id in 0..self.machines.len() {
+                let mut quantum = self.quantum;
+                if let Some(budget) = self.budget {
+                    if steps >= budget {
+                        return Err(SchedulerError::BudgetExhausted { steps });
+                    }
+                    quantum = quantum.min((budget - steps) as usize);
+                }
+                let before = self.machines[id].steps_executed();
+                if let Err(error) = self.machines[id].run_steps(quantum) {
+                    return Err(SchedulerError::Fault { machine: id, error });
+                }
+                let taken = self.machines[id].steps_executed() - before;
+                steps += taken;
+                progress |= taken > 0;
+                self.deliver(id);
+            }
+            if self
+                .machines
+                .iter()
+                .all(|m| IntcodeState::Halted == m.get_state())
+            {
+                return Ok(());
+            }
+            if !progress {
+                return Err(SchedulerError::Deadlock {
+                    blocked: self.blocked(),
+                });
+            }
+        }
+    }
+    fn known(&self, id: MachineId) -> Result<(), SchedulerError> {
+        if id < self.machines.len() {
+            Ok(())
+        } else {
+            Err(SchedulerError::UnknownMachine { machine: id })
+        }
+    }
+    fn deliver(&mut self, from: MachineId) {
+        if let Some(last) = self.machines[from].last_output() {
+            self.last_outputs[from] = Some(last);
+        }
+        // Unrouted outputs stay in the machine's buffer for the caller to collect
+        if let Some(to) = self.routes[from] {
+            let outputs = self.machines[from].take_outputs();
+            self.machines[to].push_inputs(outputs);
+        }
+    }
+    fn blocked(&self) -> Vec<BlockedMachine> {
+        self.machines
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| IntcodeState::WaitingForInput == m.get_state())
+            .map(|(machine, m)| BlockedMachine {
+                machine,
+                ip: m.get_ip(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod scheduler_tests {
+    use super::*;
+
+    fn machine(program: Vec<i64>, inputs: Vec<i64>) -> Intcode {
+        let mut intcode = Intcode::new();
+        intcode.load_program(program);
+        intcode.push_inputs(inputs);
+        intcode
+    }
+
+    #[test]
+    fn day_07_feedback_loop() {
+        let program = vec![
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1,
+            28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        let mut scheduler = Scheduler::new();
+        for phase in [9, 8, 7, 6, 5] {
+            scheduler.add(machine(program.clone(), vec![phase]));
+        }
+        for id in 0..5 {
+            scheduler.route(id, Some((id + 1) % 5)).unwrap();
+        }
+        scheduler.push_input(0, 0).unwrap();
+        assert_eq!(scheduler.run(), Ok(()));
+        assert_eq!(scheduler.last_output(4), Some(139629729));
+    }
+    #[test]
+    fn unrouted_outputs_stay_with_the_machine() {
+        let mut scheduler = Scheduler::new();
+        let doubler = scheduler.add(machine(vec![3, 9, 102, 2, 9, 9, 4, 9, 99, 0], vec![]));
+        let source = scheduler.add(machine(vec![104, 21, 99], vec![]));
+        scheduler.route(source, Some(doubler)).unwrap();
+        assert_eq!(scheduler.run(), Ok(()));
+        assert_eq!(scheduler.machine(doubler).unwrap().pending_outputs(), &[42]);
+        assert!(scheduler
+            .machine(source)
+            .unwrap()
+            .pending_outputs()
+            .is_empty());
+    }
+    #[test]
+    fn machines_waiting_on_each_other_deadlock() {
+        // Both read before they write, so neither ever sends anything
+        let echo = vec![3, 7, 4, 7, 1105, 1, 0, 0];
+        let mut scheduler = Scheduler::new();
+        let a = scheduler.add(machine(echo.clone(), vec![]));
+        let b = scheduler.add(machine(vec![104, 1, 99], vec![]));
+        let c = scheduler.add(machine(echo, vec![]));
+        scheduler.route(a, Some(c)).unwrap();
+        scheduler.route(c, Some(a)).unwrap();
+        let error = scheduler.run().unwrap_err();
+        assert_eq!(
+            error,
+            SchedulerError::Deadlock {
+                blocked: vec![
+                    BlockedMachine { machine: a, ip: 0 },
+                    BlockedMachine { machine: c, ip: 0 },
+                ]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "deadlock: machine 0 waiting at ip 0, machine 2 waiting at ip 0"
+        );
+        assert_eq!(
+            scheduler.machine(b).unwrap().get_state(),
+            IntcodeState::Halted
+        );
+        // Breaking the deadlock from outside lets the pair carry on
+        scheduler.push_input(a, 5).unwrap();
+        scheduler.set_budget(Some(100));
+        assert_eq!(
+            scheduler.run(),
+            Err(SchedulerError::BudgetExhausted { steps: 100 })
+        );
+        assert_eq!(scheduler.last_output(c), Some(5));
+    }
+    #[test]
+    fn faults_name_the_machine() {
+        let mut scheduler = Scheduler::new();
+        scheduler.add(machine(vec![99], vec![]));
+        scheduler.add(machine(vec![42], vec![]));
+        assert_eq!(
+            scheduler.run(),
+            Err(SchedulerError::Fault {
+                machine: 1,
+                error: IntcodeError::UnknownOpcode { addr: 0, value: 42 }
+            })
+        );
+    }
+    #[test]
+    fn unknown_machines_are_errors() {
+        let mut scheduler = Scheduler::new();
+        let only = scheduler.add(machine(vec![99], vec![]));
+        let unknown = SchedulerError::UnknownMachine { machine: 1 };
+        assert_eq!(scheduler.route(1, Some(only)), Err(unknown.clone()));
+        assert_eq!(scheduler.route(only, Some(1)), Err(unknown.clone()));
+        assert_eq!(scheduler.push_input(1, 5), Err(unknown.clone()));
+        assert_eq!(unknown.to_string(), "no machine 1");
+        assert!(scheduler.machine(1).is_none());
+        assert_eq!(scheduler.last_output(1), None);
+        // Nothing was half applied
+        assert_eq!(scheduler.run(), Ok(()));
+    }
+}
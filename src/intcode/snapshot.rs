@@ -0,0 +1,480 @@
+use super::intcode::{Intcode, IntcodeError, IntcodeState, ModePolicy, OverflowPolicy};
+use super::word::Word;
+use std::fmt;
+use std::fs;
+use std::io;
+
+// Plain text, one field per line so snapshots can be diffed and edited:
+//
+//   intcode-snapshot 1
+//   ip 12
+//   relative_base 0
+//   steps 1
+//   state WaitingForInput
+//   overflow wrapping
+//   word i64
+//   modes lenient
+//   limit none
+//   touched 1023
+//   input 5,6
+//   output
+//   page 0 3,9,8,9,10,9,4,9,99,-1,8
+//   end
+//
+// Pages only list cells up to the last non-zero one, wide lines only cells
+// holding a value too wide for the word. Every other field is required.
+pub const SNAPSHOT_VERSION: u32 = 1;
+const MAGIC: &str = "intcode-snapshot";
+const FIELDS: [&str; 11] = [
+    "ip",
+    "relative_base",
+    "steps",
+    "state",
+    "overflow",
+    "word",
+    "modes",
+    "limit",
+    "touched",
+    "input",
+    "output",
+];
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    UnsupportedVersion(String),
+    Format { line: usize, message: String },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SnapshotError::Io(e) => write!(f, "snapshot io error: {}", e),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {}", version)
+            }
+            SnapshotError::Format { line, message } => {
+                write!(f, "bad snapshot at line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(e: io::Error) -> SnapshotError {
+        SnapshotError::Io(e)
+    }
+}
+
+fn join<W: Word>(values: impl IntoIterator<Item = W>) -> String {
+    let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+    values.join(",")
+}
+
+fn encode_state(state: &IntcodeState) -> String {
+    match state {
+        IntcodeState::Ready => "Ready".to_string(),
+        IntcodeState::Halted => "Halted".to_string(),
+        IntcodeState::WaitingForInput => "WaitingForInput".to_string(),
+        IntcodeState::OutputReady => "OutputReady".to_string(),
+        IntcodeState::Faulted(error) => format!("Faulted {}", encode_error(error)),
+    }
+}
+
+fn encode_error(error: &IntcodeError) -> String {
+    match error {
+        IntcodeError::UnknownOpcode { addr, value } => format!("UnknownOpcode {} {}", addr, value),
+        IntcodeError::InvalidMode { addr, value } => format!("InvalidMode {} {}", addr, value),
+        IntcodeError::NegativeAddress { addr, target } => {
+            format!("NegativeAddress {} {}", addr, target)
+        }
+        IntcodeError::AddressOverflow { addr, target } => {
+            format!("AddressOverflow {} {}", addr, target)
+        }
+        IntcodeError::AddressOutOfRange { addr, target } => {
+            format!("AddressOutOfRange {} {}", addr, target)
+        }
+        IntcodeError::InputUnderflow { addr } => format!("InputUnderflow {}", addr),
+        IntcodeError::ArithmeticOverflow { addr } => format!("ArithmeticOverflow {}", addr),
+        IntcodeError::ImmediateWrite { addr, value } => {
+            format!("ImmediateWrite {} {}", addr, value)
+        }
+        IntcodeError::UnusedMode { addr, value } => format!("UnusedMode {} {}", addr, value),
+    }
+}
+
+struct Parser {
+    line: usize,
+}
+
+impl Parser {
+    fn error(&self, message: String) -> SnapshotError {
+        SnapshotError::Format {
+            line: self.line,
+            message,
+        }
+    }
+    fn number<T: std::str::FromStr>(&self, text: Option<&str>) -> Result<T, SnapshotError> {
+        let text = text.ok_or_else(|| self.error("missing number".to_string()))?;
+        text.parse()
+            .map_err(|_| self.error(format!("bad number '{}'", text)))
+    }
+    fn list<W: Word>(&self, text: Option<&str>) -> Result<Vec<W>, SnapshotError> {
+        match text {
+            None | Some("") => Ok(Vec::new()),
+            Some(text) => text.split(',').map(|v| self.number(Some(v))).collect(),
+        }
+    }
+    fn policy(&self, text: Option<&str>) -> Result<OverflowPolicy, SnapshotError> {
+        Ok(match text {
+            Some("wrapping") => OverflowPolicy::Wrapping,
+            Some("saturating") => OverflowPolicy::Saturating,
+            Some("checked") => OverflowPolicy::Checked,
+            Some("widened") => OverflowPolicy::Widened,
+            _ => return Err(self.error(format!("bad overflow policy {:?}", text))),
+        })
+    }
+    fn modes(&self, text: Option<&str>) -> Result<ModePolicy, SnapshotError> {
+        Ok(match text {
+            Some("lenient") => ModePolicy::Lenient,
+            Some("strict") => ModePolicy::Strict,
+            _ => return Err(self.error(format!("bad mode policy {:?}", text))),
+        })
+    }
+    fn state(&self, words: &[&str]) -> Result<IntcodeState, SnapshotError> {
+        Ok(match words {
+            ["Ready"] => IntcodeState::Ready,
+            ["Halted"] => IntcodeState::Halted,
+            ["WaitingForInput"] => IntcodeState::WaitingForInput,
+            ["OutputReady"] => IntcodeState::OutputReady,
+            ["Faulted", error @ ..] => IntcodeState::Faulted(self.fault(error)?),
+            _ => return Err(self.error(format!("bad state '{}'", words.join(" ")))),
+        })
+    }
+    fn fault(&self, words: &[&str]) -> Result<IntcodeError, SnapshotError> {
+        let addr = self.number(words.get(1).copied())?;
+        Ok(match words[0] {
+            "UnknownOpcode" => IntcodeError::UnknownOpcode {
+                addr,
+                value: self.number(words.get(2).copied())?,
+            },
+            "InvalidMode" => IntcodeError::InvalidMode {
+                addr,
+                value: self.number(words.get(2).copied())?,
+            },
+            "NegativeAddress" => IntcodeError::NegativeAddress {
+                addr,
+                target: self.number(words.get(2).copied())?,
+            },
+            "AddressOverflow" => IntcodeError::AddressOverflow {
+                addr,
+                target: self.number(words.get(2).copied())?,
+            },
+            "AddressOutOfRange" => IntcodeError::AddressOutOfRange {
+                addr,
+                target: self.number(words.get(2).copied())?,
+            },
+            "InputUnderflow" => IntcodeError::InputUnderflow { addr },
+            "ArithmeticOverflow" => IntcodeError::ArithmeticOverflow { addr },
+            "ImmediateWrite" => IntcodeError::ImmediateWrite {
+                addr,
+                value: self.number(words.get(2).copied())?,
+            },
+            "UnusedMode" => IntcodeError::UnusedMode {
+                addr,
+                value: self.number(words.get(2).copied())?,
+            },
+            other => return Err(self.error(format!("unknown fault '{}'", other))),
+        })
+    }
+}
+
+impl<W: Word> Intcode<W> {
+    pub fn to_snapshot(&self) -> String {
+        let mut text = format!("{} {}\n", MAGIC, SNAPSHOT_VERSION);
+        text.push_str(&format!("ip {}\n", self.ip));
+        text.push_str(&format!("relative_base {}\n", self.relative_base));
+        text.push_str(&format!("steps {}\n", self.steps));
+        text.push_str(&format!("state {}\n", encode_state(&self.state)));
+        text.push_str(&format!("overflow {}\n", self.overflow_policy));
+        text.push_str(&format!("word {}\n", W::NAME));
+        text.push_str(&format!("modes {}\n", self.mode_policy));
+        match self.memory.get_limit() {
+            Some(limit) => text.push_str(&format!("limit {}\n", limit)),
+            None => text.push_str("limit none\n"),
+        }
+        match self.memory.highest_touched() {
+            Some(addr) => text.push_str(&format!("touched {}\n", addr)),
+            None => text.push_str("touched none\n"),
+        }
+        text.push_str(&format!("input {}\n", join(self.input.iter().copied())));
+        text.push_str(&format!("output {}\n", join(self.output.iter().copied())));
+        for (addr, cells) in self.memory.pages() {
+            let used = cells
+                .iter()
+                .rposition(|&v| W::ZERO != v)
+                .map_or(0, |last| last + 1);
+            if used > 0 {
+                text.push_str(&format!(
+                    "page {} {}\n",
+                    addr,
+                    join(cells[..used].iter().copied())
+                ));
+            }
+        }
+        for (addr, value) in self.memory.wide_cells() {
+            text.push_str(&format!("wide {} {}\n", addr, value));
+        }
+        text.push_str("end\n");
+        text
+    }
+    pub fn from_snapshot(text: &str) -> Result<Intcode<W>, SnapshotError> {
+        let mut parser = Parser { line: 0 };
+        let mut intcode = Intcode::default();
+        let mut limit = None;
+        let mut touched = None;
+        let mut wide = Vec::new();
+        let mut finished = false;
+        let mut seen = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            parser.line = index + 1;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            if finished {
+                return Err(parser.error("data after end".to_string()));
+            }
+            if 1 == parser.line {
+                match words.as_slice() {
+                    [MAGIC, version] if *version == SNAPSHOT_VERSION.to_string() => continue,
+                    [MAGIC, version] => {
+                        return Err(SnapshotError::UnsupportedVersion(version.to_string()))
+                    }
+                    _ => return Err(parser.error("not an intcode snapshot".to_string())),
+                }
+            }
+            seen.extend(words.first().copied());
+            match words.first().copied() {
+                Some("ip") => intcode.ip = parser.number(words.get(1).copied())?,
+                Some("relative_base") => {
+                    intcode.relative_base = parser.number(words.get(1).copied())?
+                }
+                Some("steps") => intcode.steps = parser.number(words.get(1).copied())?,
+                // Values would not round trip through a machine of another width
+                Some("word") if Some(&W::NAME) != words.get(1) => {
+                    return Err(parser.error(format!(
+                        "snapshot of {} words, expected {}",
+                        words.get(1).unwrap_or(&"none"),
+                        W::NAME
+                    )))
+                }
+                Some("word") => (),
+                Some("overflow") => {
+                    intcode.overflow_policy = parser.policy(words.get(1).copied())?
+                }
+                Some("modes") => intcode.mode_policy = parser.modes(words.get(1).copied())?,
+                Some("state") => intcode.state = parser.state(&words[1..])?,
+                Some("limit") if Some(&"none") == words.get(1) => limit = None,
+                Some("limit") => limit = Some(parser.number(words.get(1).copied())?),
+                Some("touched") if Some(&"none") == words.get(1) => touched = None,
+                Some("touched") => touched = Some(parser.number(words.get(1).copied())?),
+                Some("input") => intcode.input = parser.list(words.get(1).copied())?.into(),
+                Some("output") => intcode.output = parser.list(words.get(1).copied())?.into(),
+                Some("page") => {
+                    let addr: usize = parser.number(words.get(1).copied())?;
+                    for (offset, value) in
+                        parser.list(words.get(2).copied())?.into_iter().enumerate()
+                    {
+                        let cell = addr
+                            .checked_add(offset)
+                            .ok_or_else(|| parser.error(format!("page {} overflows", addr)))?;
+                        intcode.memory.write(cell, value);
+                    }
+                }
+                Some("wide") => {
+                    let addr = parser.number(words.get(1).copied())?;
+                    let value: i128 = parser.number(words.get(2).copied())?;
+                    if W::from_i128(value).is_some() {
+                        return Err(parser.error(format!("{} is not too wide for a word", value)));
+                    }
+                    wide.push((addr, value));
+                }
+                Some("end") => finished = true,
+                None => (),
+                Some(other) => return Err(parser.error(format!("unknown field '{}'", other))),
+            }
+        }
+        if !finished {
+            return Err(parser.error("truncated snapshot, missing end".to_string()));
+        }
+        if let Some(field) = FIELDS.iter().find(|field| !seen.contains(field)) {
+            return Err(parser.error(format!("missing field '{}'", field)));
+        }
+        // After the pages, writing them would have dropped the exact values
+        for (addr, value) in wide {
+            intcode.memory.write_wide(addr, value);
+        }
+        intcode.memory.set_limit(limit);
+        intcode.memory.set_highest_touched(touched);
+        Ok(intcode)
+    }
+    pub fn save_snapshot(&self, path: &str) -> Result<(), SnapshotError> {
+        fs::write(path, self.to_snapshot())?;
+        Ok(())
+    }
+    pub fn load_snapshot(path: &str) -> Result<Intcode<W>, SnapshotError> {
+        Intcode::from_snapshot(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+    use crate::intcode::memory::Memory;
+
+    fn cells(memory: &Memory<i64>) -> Vec<(usize, i64)> {
+        memory
+            .pages()
+            .into_iter()
+            .flat_map(|(addr, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &v)| 0 != v)
+                    .map(move |(offset, &v)| (addr + offset, v))
+            })
+            .collect()
+    }
+
+    fn paused_machine() -> Intcode {
+        let mut intcode = Intcode::new();
+        // Day 7 dual input example, paused waiting for the second input
+        intcode.load_program(vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ]);
+        intcode.push_input(4);
+        intcode.run().unwrap();
+        intcode
+    }
+
+    #[test]
+    fn snapshot_round_trips_a_paused_machine() {
+        let intcode = paused_machine();
+        let text = intcode.to_snapshot();
+        assert!(text.starts_with(
+            "intcode-snapshot 1\nip 2\nrelative_base 0\nsteps 1\nstate WaitingForInput\n"
+        ));
+        let mut restored: Intcode = Intcode::from_snapshot(&text).unwrap();
+        assert_eq!(restored.to_snapshot(), text);
+        restored.push_input(3);
+        assert_eq!(restored.run(), Ok(IntcodeState::Halted));
+        assert_eq!(restored.last_output(), Some(34));
+    }
+    #[test]
+    fn snapshot_keeps_io_buffers_and_faults() {
+        let mut intcode = Intcode::new();
+        intcode.set_memory_limit(Some(5000));
+        intcode.load_program(vec![109, -7, 104, 1, 1101, 20, 22, 4000, 42]);
+        intcode.push_inputs(vec![8, 9]);
+        assert!(intcode.run().is_err());
+        let restored: Intcode = Intcode::from_snapshot(&intcode.to_snapshot()).unwrap();
+        assert_eq!(restored.get_state(), intcode.get_state());
+        assert_eq!(restored.get_relative_base(), -7);
+        assert_eq!(restored.pending_inputs(), intcode.pending_inputs());
+        assert_eq!(restored.pending_outputs(), intcode.pending_outputs());
+        assert_eq!(restored.peek(4000).unwrap(), 42);
+        assert_eq!(
+            restored.highest_address_touched(),
+            intcode.highest_address_touched()
+        );
+        assert_eq!(cells(&restored.memory), cells(&intcode.memory));
+        assert_eq!(restored.memory.get_limit(), Some(5000));
+    }
+    #[test]
+    fn save_and_load_through_a_file() {
+        let intcode = paused_machine();
+        let path =
+            std::env::temp_dir().join(format!("intcode-snapshot-{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+        intcode.save_snapshot(path).unwrap();
+        let restored: Intcode = Intcode::load_snapshot(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(restored.to_snapshot(), intcode.to_snapshot());
+    }
+    #[test]
+    fn rejects_bad_snapshots() {
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot("intcode-snapshot 2\nend\n"),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot("hello\n"),
+            Err(SnapshotError::Format { line: 1, .. })
+        ));
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot("intcode-snapshot 1\nip twelve\nend\n"),
+            Err(SnapshotError::Format { line: 2, .. })
+        ));
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot("intcode-snapshot 1\nip 0\n"),
+            Err(SnapshotError::Format { .. })
+        ));
+        let missing = paused_machine().to_snapshot().replace("steps 1\n", "");
+        match Intcode::<i64>::from_snapshot(&missing) {
+            Err(SnapshotError::Format { message, .. }) => {
+                assert_eq!(message, "missing field 'steps'")
+            }
+            other => panic!("expected a missing field, got {:?}", other.map(|_| ())),
+        }
+    }
+    #[test]
+    fn rejects_snapshots_of_another_word_size() {
+        let text = paused_machine().to_snapshot();
+        assert!(text.contains("\nword i64\n"));
+        assert!(matches!(
+            Intcode::<i32>::from_snapshot(&text),
+            Err(SnapshotError::Format { line: 7, .. })
+        ));
+        let narrow = Intcode::<i32>::default().to_snapshot();
+        assert!(Intcode::<i32>::from_snapshot(&narrow).is_ok());
+    }
+    #[test]
+    fn snapshot_keeps_the_mode_policy_and_its_faults() {
+        let mut intcode = Intcode::new();
+        intcode.set_mode_policy(ModePolicy::Strict);
+        intcode.load_program(vec![11101, 1, 1, 5, 99, 0]);
+        assert!(intcode.run().is_err());
+        let text = intcode.to_snapshot();
+        assert!(text.contains("\nmodes strict\n"));
+        assert!(text.contains("\nstate Faulted ImmediateWrite 0 11101\n"));
+        let restored: Intcode = Intcode::from_snapshot(&text).unwrap();
+        assert_eq!(restored.mode_policy(), ModePolicy::Strict);
+        assert_eq!(restored.get_state(), intcode.get_state());
+    }
+    #[test]
+    fn rejects_pages_past_the_end_of_memory() {
+        let text = format!("intcode-snapshot 1\npage {} 1,2\nend\n", usize::MAX);
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot(&text),
+            Err(SnapshotError::Format { line: 2, .. })
+        ));
+    }
+    #[test]
+    fn wide_cells_round_trip() {
+        // [8] = [6] * [7]; out [8] + [8]
+        let mut intcode: Intcode = Intcode::new();
+        intcode.set_overflow_policy(OverflowPolicy::Widened);
+        intcode.load_program(vec![2, 6, 7, 8, 99, 0, i64::MAX, 3, 0]);
+        intcode.run().unwrap();
+        let text = intcode.to_snapshot();
+        assert!(text.contains("\nwide 8 27670116110564327421\nend\n"));
+        let restored: Intcode = Intcode::from_snapshot(&text).unwrap();
+        assert_eq!(restored.memory.wide(8), Some(i64::MAX as i128 * 3));
+        assert_eq!(restored.to_snapshot(), text);
+        let narrow = text.replace("wide 8 27670116110564327421", "wide 8 5");
+        assert!(matches!(
+            Intcode::<i64>::from_snapshot(&narrow),
+            Err(SnapshotError::Format { .. })
+        ));
+    }
+}
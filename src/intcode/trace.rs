@@ -0,0 +1,315 @@
+use super::disassembler::mnemonic;
+use super::intcode::{IntcodeError, MemoryMode};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::ops::Range;
+
+// Everything one executed instruction did
+#[derive(Debug, PartialEq, Clone)]
+pub struct TraceEvent {
+    pub step: u64,
+    pub ip: usize,
+    pub opcode: Option<isize>, // None when the instruction did not decode
+    pub modes: Vec<MemoryMode>,
+    pub operands: Vec<i128>, // Values read, or the destination address for writes
+    pub write: Option<(usize, i128)>,
+    pub input: Option<i128>,
+    pub output: Option<i128>,
+    pub fault: Option<IntcodeError>,
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.opcode.and_then(mnemonic).unwrap_or("???");
+        write!(f, "step={} ip={} {}", self.step, self.ip, name)?;
+        if !self.modes.is_empty() {
+            let modes: String = self
+                .modes
+                .iter()
+                .map(|mode| match mode {
+                    MemoryMode::PositionMode => 'P',
+                    MemoryMode::ImmediateMode => 'I',
+                    MemoryMode::RelativeMode => 'R',
+                })
+                .collect();
+            write!(f, " modes={}", modes)?;
+        }
+        if !self.operands.is_empty() {
+            let operands: Vec<String> = self.operands.iter().map(|v| v.to_string()).collect();
+            write!(f, " args={}", operands.join(","))?;
+        }
+        if let Some(value) = self.input {
+            write!(f, " in={}", value)?;
+        }
+        if let Some((addr, value)) = self.write {
+            write!(f, " write=[{}]<-{}", addr, value)?;
+        }
+        if let Some(value) = self.output {
+            write!(f, " out={}", value)?;
+        }
+        if let Some(fault) = &self.fault {
+            write!(f, " fault={}", fault)?;
+        }
+        Ok(())
+    }
+}
+
+enum TraceSink {
+    Buffer(Vec<TraceEvent>),
+    Writer(Box<dyn Write + Send>),
+}
+
+// Opt-in instruction recorder, attach with Intcode::set_tracer
+pub struct Tracer {
+    sink: TraceSink,
+    address_range: Option<Range<usize>>,
+    step_window: Option<Range<u64>>,
+    steps: u64,
+    current: Option<TraceEvent>,
+    error: Option<io::Error>,
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sink = match &self.sink {
+            TraceSink::Buffer(events) => format!("Buffer({} events)", events.len()),
+            TraceSink::Writer(_) => "Writer".to_string(),
+        };
+        f.debug_struct("Tracer")
+            .field("sink", &sink)
+            .field("address_range", &self.address_range)
+            .field("step_window", &self.step_window)
+            .field("steps", &self.steps)
+            .finish()
+    }
+}
+
+impl Tracer {
+    pub fn to_buffer() -> Tracer {
+        Tracer::with_sink(TraceSink::Buffer(Vec::new()))
+    }
+    pub fn to_writer(writer: impl Write + Send + 'static) -> Tracer {
+        Tracer::with_sink(TraceSink::Writer(Box::new(writer)))
+    }
+    pub fn to_file(path: &str) -> io::Result<Tracer> {
+        Ok(Tracer::to_writer(BufWriter::new(File::create(path)?)))
+    }
+    fn with_sink(sink: TraceSink) -> Tracer {
+        Tracer {
+            sink,
+            address_range: None,
+            step_window: None,
+            steps: 0,
+            current: None,
+            error: None,
+        }
+    }
+    // Only record instructions whose ip falls in the range
+    pub fn set_address_range(&mut self, range: Option<Range<usize>>) {
+        self.address_range = range;
+    }
+    // Only record instructions whose step number falls in the window, steps count from 0
+    pub fn set_step_window(&mut self, window: Option<Range<u64>>) {
+        self.step_window = window;
+    }
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+    // Buffered events, always empty when tracing to a writer
+    pub fn events(&self) -> &[TraceEvent] {
+        match &self.sink {
+            TraceSink::Buffer(events) => events,
+            TraceSink::Writer(_) => &[],
+        }
+    }
+    // The first write failure, tracing to the writer stops after one
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &mut self.sink {
+            TraceSink::Writer(writer) => writer.flush(),
+            TraceSink::Buffer(_) => Ok(()),
+        }
+    }
+    pub(crate) fn begin(&mut self, ip: usize) {
+        self.current = Some(TraceEvent {
+            step: self.steps,
+            ip,
+            opcode: None,
+            modes: Vec::new(),
+            operands: Vec::new(),
+            write: None,
+            input: None,
+            output: None,
+            fault: None,
+        });
+    }
+    pub(crate) fn decoded(&mut self, opcode: isize, modes: &[MemoryMode]) {
+        if let Some(event) = &mut self.current {
+            event.opcode = Some(opcode);
+            event.modes = modes.to_vec();
+        }
+    }
+    pub(crate) fn operand(&mut self, value: i128) {
+        if let Some(event) = &mut self.current {
+            event.operands.push(value);
+        }
+    }
+    pub(crate) fn write(&mut self, addr: usize, value: i128) {
+        if let Some(event) = &mut self.current {
+            event.operands.push(addr as i128);
+            event.write = Some((addr, value));
+        }
+    }
+    pub(crate) fn input(&mut self, value: i128) {
+        if let Some(event) = &mut self.current {
+            event.input = Some(value);
+        }
+    }
+    pub(crate) fn output(&mut self, value: i128) {
+        if let Some(event) = &mut self.current {
+            event.output = Some(value);
+        }
+    }
+    // The instruction never ran, e.g. an input with nothing queued
+    pub(crate) fn discard(&mut self) {
+        self.current = None;
+    }
+    pub(crate) fn finish(&mut self, fault: Option<&IntcodeError>) {
+        let mut event = match self.current.take() {
+            Some(event) => event,
+            None => return,
+        };
+        self.steps += 1;
+        event.fault = fault.cloned();
+        let in_range = self
+            .address_range
+            .as_ref()
+            .is_none_or(|range| range.contains(&event.ip));
+        let in_window = self
+            .step_window
+            .as_ref()
+            .is_none_or(|window| window.contains(&event.step));
+        if !(in_range && in_window) {
+            return;
+        }
+        match &mut self.sink {
+            TraceSink::Buffer(events) => events.push(event),
+            TraceSink::Writer(writer) => {
+                if self.error.is_none() {
+                    if let Err(e) = writeln!(writer, "{}", event) {
+                        self.error = Some(e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod trace_tests {
+    use super::*;
+    use crate::intcode::intcode::Intcode;
+    use std::sync::{Arc, Mutex};
+
+    // Writer that can still be read after the tracer has taken ownership
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn buffer_records_every_instruction() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]);
+        intcode.set_tracer(Some(Tracer::to_buffer()));
+        intcode.run().unwrap();
+        // Starved input is not an executed instruction
+        assert_eq!(intcode.tracer().unwrap().steps(), 0);
+        intcode.push_input(8);
+        intcode.run().unwrap();
+        let tracer = intcode.take_tracer().unwrap();
+        let lines: Vec<String> = tracer.events().iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "step=0 ip=0 in modes=P args=9 in=8 write=[9]<-8",
+                "step=1 ip=2 eq modes=PPP args=8,8,9 write=[9]<-1",
+                "step=2 ip=6 out modes=P args=1 out=1",
+                "step=3 ip=8 hlt",
+            ]
+        );
+    }
+    #[test]
+    fn faults_are_traced() {
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![1101, 20, 22, 4, 0]);
+        intcode.set_tracer(Some(Tracer::to_buffer()));
+        // Writes 42 over the next instruction
+        assert!(intcode.run().is_err());
+        let tracer = intcode.take_tracer().unwrap();
+        let last = tracer.events().last().unwrap();
+        assert_eq!(last.ip, 4);
+        assert_eq!(last.opcode, None);
+        assert_eq!(
+            last.fault,
+            Some(IntcodeError::UnknownOpcode { addr: 4, value: 42 })
+        );
+    }
+    #[test]
+    fn filters_limit_what_is_recorded() {
+        let program = vec![104, 1, 104, 2, 104, 3, 104, 4, 99];
+        let mut intcode = Intcode::new();
+        intcode.load_program(program.clone());
+        let mut tracer = Tracer::to_buffer();
+        tracer.set_address_range(Some(2..6));
+        intcode.set_tracer(Some(tracer));
+        intcode.run().unwrap();
+        let ips: Vec<usize> = intcode
+            .tracer()
+            .unwrap()
+            .events()
+            .iter()
+            .map(|e| e.ip)
+            .collect();
+        assert_eq!(ips, vec![2, 4]);
+
+        let mut intcode = Intcode::new();
+        intcode.load_program(program);
+        let mut tracer = Tracer::to_buffer();
+        tracer.set_step_window(Some(3..10));
+        intcode.set_tracer(Some(tracer));
+        intcode.run().unwrap();
+        let steps: Vec<u64> = intcode
+            .tracer()
+            .unwrap()
+            .events()
+            .iter()
+            .map(|e| e.step)
+            .collect();
+        assert_eq!(steps, vec![3, 4]);
+    }
+    #[test]
+    fn writer_gets_one_line_per_instruction() {
+        let buffer = SharedBuffer::default();
+        let mut intcode = Intcode::new();
+        intcode.load_program(vec![109, 1, 204, -1, 99]);
+        intcode.set_tracer(Some(Tracer::to_writer(buffer.clone())));
+        intcode.run().unwrap();
+        let text = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            text,
+            "step=0 ip=0 arb modes=I args=1\nstep=1 ip=2 out modes=R args=109 out=109\nstep=2 ip=4 hlt\n"
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use super::cfg::{BasicBlock, ControlFlowGraph};
+use super::disassembler::{Instruction, Operand};
+use super::intcode::{Intcode, IntcodeError, IntcodeState, MemoryMode, ModePolicy, OverflowPolicy};
+use super::memory::Memory;
+use super::word::{Arithmetic, Word};
+
+const INDENT: &str = "    ";
+
+// Rust for an operand the instruction reads, immediates become literals
+fn read(operand: &Operand) -> String {
+    match operand.mode {
+        MemoryMode::ImmediateMode => operand.value.to_string(),
+        MemoryMode::PositionMode => format!("n.load({})?", operand.value),
+        MemoryMode::RelativeMode => format!("n.load_relative({})?", operand.value),
+    }
+}
+
+// Binds a read to `name` unless it is a literal, returning what to use it as
+fn bind(lines: &mut Vec<String>, name: &str, operand: &Operand) -> String {
+    match operand.mode {
+        MemoryMode::ImmediateMode => read(operand),
+        _ => {
+            lines.push(format!("let {} = {};", name, read(operand)));
+            name.to_string()
+        }
+    }
+}
+
+// Where a block's compiled code leaves off
+struct Exit {
+    block: usize,
+    end: usize,
+    last: bool,
+}
+
+// Stores `value`, true if the write may change code still to run in the block
+fn write(lines: &mut Vec<String>, dest: &Operand, next: usize, exit: &Exit) -> bool {
+    match dest.mode {
+        MemoryMode::RelativeMode => {
+            lines.push(format!("n.store_relative({}, value)?;", dest.value));
+            true
+        }
+        // Immediate writes act as position mode
+        _ => {
+            lines.push(format!("n.store({}, value)?;", dest.value));
+            (next as i128..exit.end as i128).contains(&dest.value)
+        }
+    }
+}
+
+// Leaves the block for the dispatcher to check once its code may have changed
+fn leave_if_patched(lines: &mut Vec<String>, patched: bool, next: usize, exit: &Exit) {
+    if patched && !exit.last {
+        lines.push(format!("if !n.valid({}) {{", exit.block));
+        lines.push(format!("{}n.goto({});", INDENT, next));
+        lines.push(format!("{}return Ok(());", INDENT));
+        lines.push("}".to_string());
+    }
+}
+
+fn instruction<W: Word>(instruction: &Instruction, exit: &Exit, lines: &mut Vec<String>) {
+    let (addr, opcode, operands) = match instruction {
+        Instruction::Code {
+            addr,
+            opcode,
+            operands,
+        } => (*addr, *opcode, operands),
+        Instruction::Data { .. } => unreachable!("blocks only hold code"),
+    };
+    let next = addr + instruction.size();
+    lines.push(format!("// {}", instruction.to_string().trim_start()));
+    lines.push(format!("n.at({}, {});", addr, instruction.size()));
+    match opcode {
+        1 | 2 => {
+            let a = bind(lines, "a", &operands[0]);
+            let b = bind(lines, "b", &operands[1]);
+            let op = if 1 == opcode { "add" } else { "multiply" };
+            lines.push(format!("let value = n.{}({}, {})?;", op, a, b));
+            let patched = write(lines, &operands[2], next, exit);
+            leave_if_patched(lines, patched, next, exit);
+        }
+        3 => {
+            lines.push("let value = match n.input() {".to_string());
+            lines.push(format!("{}Some(value) => value,", INDENT));
+            lines.push(format!("{}None => return Ok(()),", INDENT));
+            lines.push("};".to_string());
+            let patched = write(lines, &operands[0], next, exit);
+            // Only consumed once the write has succeeded, like the interpreter
+            lines.push("n.consume_input();".to_string());
+            leave_if_patched(lines, patched, next, exit);
+        }
+        4 => {
+            let a = bind(lines, "a", &operands[0]);
+            lines.push(format!("n.output({});", a));
+        }
+        5 | 6 => {
+            let taken = match operands[0].mode {
+                MemoryMode::ImmediateMode => Some((5 == opcode) == (0 != operands[0].value)),
+                _ => None,
+            };
+            let a = bind(lines, "a", &operands[0]);
+            match taken {
+                // The graph always ends a block on a jump that is taken
+                Some(true) => {
+                    let b = bind(lines, "b", &operands[1]);
+                    lines.push(format!("n.jump({})", b));
+                }
+                Some(false) => {
+                    // Never taken, but reading the target can still fault
+                    if MemoryMode::ImmediateMode != operands[1].mode {
+                        lines.push(format!("{};", read(&operands[1])));
+                    }
+                    if exit.last {
+                        lines.push(format!("n.goto({});", next));
+                        lines.push("Ok(())".to_string());
+                    }
+                }
+                None => {
+                    let b = bind(lines, "b", &operands[1]);
+                    let test = if 5 == opcode { "!=" } else { "==" };
+                    lines.push(format!("if 0 {} {} {{", test, a));
+                    lines.push(format!("{}return n.jump({});", INDENT, b));
+                    lines.push("}".to_string());
+                    lines.push(format!("n.goto({});", next));
+                    lines.push("Ok(())".to_string());
+                }
+            }
+            return;
+        }
+        7 | 8 => {
+            let (left, right) = (&operands[0], &operands[1]);
+            // Comparing two literals is settled here
+            if MemoryMode::ImmediateMode == left.mode && MemoryMode::ImmediateMode == right.mode {
+                let holds = if 7 == opcode {
+                    left.value < right.value
+                } else {
+                    left.value == right.value
+                };
+                lines.push(format!("let value = {};", holds as u8));
+            } else {
+                let a = bind(lines, "a", left);
+                let b = bind(lines, "b", right);
+                let test = if 7 == opcode { "<" } else { "==" };
+                lines.push(format!(
+                    "let value = {}::from({} {} {});",
+                    W::NAME,
+                    a,
+                    test,
+                    b
+                ));
+            }
+            let patched = write(lines, &operands[2], next, exit);
+            leave_if_patched(lines, patched, next, exit);
+        }
+        9 => {
+            let a = bind(lines, "a", &operands[0]);
+            lines.push(format!("n.shift({})?;", a));
+        }
+        99 => {
+            lines.push("n.halt();".to_string());
+            lines.push("Ok(())".to_string());
+            return;
+        }
+        _ => unreachable!("unknown opcodes never decode"),
+    }
+    if exit.last {
+        lines.push(format!("n.goto({});", next));
+        lines.push("Ok(())".to_string());
+    }
+}
+
+fn block_fn<W: Word>(index: usize, block: &BasicBlock) -> String {
+    let mut lines = Vec::new();
+    let count = block.instructions.len();
+    for (k, step) in block.instructions.iter().enumerate() {
+        let exit = Exit {
+            block: index,
+            end: block.end(),
+            last: k + 1 == count,
+        };
+        instruction::<W>(step, &exit, &mut lines);
+    }
+    let mut text = format!(
+        "fn block_{}(n: &mut Native<{}>) -> Result<(), IntcodeError> {{\n",
+        block.start,
+        W::NAME
+    );
+    for line in lines {
+        text.push_str(INDENT);
+        text.push_str(&line);
+        text.push('\n');
+    }
+    text.push_str("}\n");
+    text
+}
+
+// Comma separated items wrapped to fit the line width
+fn wrapped(items: impl Iterator<Item = String>) -> String {
+    let mut text = String::new();
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && INDENT.len() + line.len() + item.len() + 2 > 100 {
+            text.push_str(&format!("{}{}\n", INDENT, line.trim_end()));
+            line.clear();
+        }
+        line.push_str(&item);
+        line.push_str(", ");
+    }
+    if !line.is_empty() {
+        text.push_str(&format!("{}{}\n", INDENT, line.trim_end()));
+    }
+    text
+}
+
+// Rust source for a type that runs `program` as native control flow. Blocks
+// the control flow graph finds become functions, the rest of the image, and
+// any block whose cells no longer hold the image, runs on the interpreter.
+// The source goes through the `advent_of_code_2019` crate for its runtime.
+pub fn transpile<W: Word>(program: &[W], name: &str) -> String {
+    let graph = ControlFlowGraph::explore(program);
+    let word = W::NAME;
+    let mut text = format!(
+        "// Generated by intcode_transpile from a {} cell image, regenerate rather than edit\n",
+        program.len()
+    );
+    text.push_str(
+        "use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};\n",
+    );
+    text.push_str("use advent_of_code_2019::intcode::transpiler::{Compiled, Native};\n");
+    text.push_str("use std::ops::{Deref, DerefMut};\n\n");
+    text.push_str(&format!(
+        "static IMAGE: [{}; {}] = [\n{}];\n\n",
+        word,
+        program.len(),
+        wrapped(program.iter().map(|value| value.to_string()))
+    ));
+    text.push_str("// First and one past the last cell of each compiled block\n");
+    text.push_str(&format!(
+        "static BLOCKS: [(usize, usize); {}] = [\n{}];\n\n",
+        graph.blocks.len(),
+        wrapped(
+            graph
+                .blocks
+                .values()
+                .map(|block| format!("({}, {})", block.start, block.end()))
+        )
+    ));
+    text.push_str("// Everything but run is the wrapped machine's, inputs and outputs included\n");
+    text.push_str("#[derive(Debug)]\n");
+    text.push_str(&format!(
+        "pub struct {name} {{\n\
+         \x20   intcode: Intcode<{word}>,\n\
+         \x20   compiled: Compiled<{word}>,\n\
+         }}\n\n",
+        name = name,
+        word = word
+    ));
+    text.push_str(&format!("impl {} {{\n", name));
+    text.push_str(&format!(
+        "    pub fn new() -> {name} {{\n\
+         \x20       let mut intcode = Intcode::default();\n\
+         \x20       intcode.load_program(IMAGE.to_vec());\n\
+         \x20       let compiled = Compiled::new(&IMAGE, &BLOCKS);\n\
+         \x20       {name} {{ intcode, compiled }}\n\
+         \x20   }}\n",
+        name = name
+    ));
+    text.push_str(
+        "    // Same contract as Intcode::run\n\
+         \x20   pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {\n\
+         \x20       if !Native::supports(&self.intcode) {\n\
+         \x20           return self.intcode.run();\n\
+         \x20       }\n\
+         \x20       let mut n = Native::new(&mut self.intcode, &mut self.compiled);\n\
+         \x20       while n.runnable()? {\n\
+         \x20           let result = match n.ip() {\n",
+    );
+    for (index, start) in graph.blocks.keys().enumerate() {
+        text.push_str(&format!(
+            "                {} if n.valid({}) => block_{}(&mut n),\n",
+            start, index, start
+        ));
+    }
+    text.push_str(
+        "                _ => n.interpret(),\n\
+         \x20           };\n\
+         \x20           n.settle(result)?;\n\
+         \x20       }\n\
+         \x20       Ok(n.state())\n\
+         \x20   }\n\
+         }\n\n",
+    );
+    text.push_str(&format!(
+        "impl Default for {name} {{\n\
+         \x20   fn default() -> {name} {{\n\
+         \x20       {name}::new()\n\
+         \x20   }}\n\
+         }}\n\n\
+         impl Deref for {name} {{\n\
+         \x20   type Target = Intcode<{word}>;\n\
+         \x20   fn deref(&self) -> &Intcode<{word}> {{\n\
+         \x20       &self.intcode\n\
+         \x20   }}\n\
+         }}\n\n\
+         impl DerefMut for {name} {{\n\
+         \x20   fn deref_mut(&mut self) -> &mut Intcode<{word}> {{\n\
+         \x20       &mut self.intcode\n\
+         \x20   }}\n\
+         }}\n",
+        name = name,
+        word = word
+    ));
+    for (index, block) in graph.blocks.values().enumerate() {
+        text.push('\n');
+        text.push_str(&block_fn::<W>(index, block));
+    }
+    text
+}
+
+// What a transpiled type keeps between runs. Compiled blocks bake in the
+// image, so each one only runs natively while its cells still hold it.
+#[derive(Debug)]
+pub struct Compiled<W: Word> {
+    image: &'static [W],
+    owners: Vec<Vec<usize>>, // Blocks covering each cell of the image
+    differs: Vec<bool>,      // Cells of the image memory no longer holds
+    mismatched: Vec<usize>,  // Differing cells in each block, it is valid at zero
+    generation: Option<u64>, // Of the memory the above was last brought up to date with
+}
+
+impl<W: Word> Compiled<W> {
+    pub fn new(image: &'static [W], blocks: &'static [(usize, usize)]) -> Compiled<W> {
+        let mut owners = vec![Vec::new(); image.len()];
+        for (index, &(start, end)) in blocks.iter().enumerate() {
+            owners[start..end]
+                .iter_mut()
+                .for_each(|cell| cell.push(index));
+        }
+        Compiled {
+            image,
+            owners,
+            differs: vec![false; image.len()],
+            mismatched: vec![0; blocks.len()],
+            generation: None,
+        }
+    }
+    fn valid(&self, index: usize) -> bool {
+        0 == self.mismatched[index]
+    }
+    // Brings one cell up to date, only the blocks it sits in are affected
+    fn check(&mut self, memory: &Memory<W>, addr: usize) {
+        if addr >= self.image.len() {
+            return;
+        }
+        let differs = Some(self.image[addr]) != memory.get(addr);
+        if differs != self.differs[addr] {
+            self.differs[addr] = differs;
+            for &index in &self.owners[addr] {
+                if differs {
+                    self.mismatched[index] += 1;
+                } else {
+                    self.mismatched[index] -= 1;
+                }
+            }
+        }
+    }
+    fn check_all(&mut self, memory: &Memory<W>) {
+        for addr in 0..self.image.len() {
+            self.check(memory, addr);
+        }
+    }
+}
+
+// The runtime transpiled code runs against. Writes during a run are checked
+// against the image as they land, anything written from outside between runs
+// has the whole image checked once.
+pub struct Native<'a, W: Word> {
+    intcode: &'a mut Intcode<W>,
+    compiled: &'a mut Compiled<W>,
+    retired: u64,         // Instructions run natively, counted into the machine on drop
+    reach: Option<usize>, // Last cell of any instruction run natively
+}
+
+impl<'a, W: Word> Native<'a, W> {
+    // Tracing, profiling, journaling, mode checks and wide cells need every
+    // instruction to go through step, machines using them only ever interpret
+    pub fn supports(intcode: &Intcode<W>) -> bool {
+        intcode.tracer().is_none()
+            && intcode.profiler().is_none()
+            && intcode.journal().is_none()
+            && intcode.mode_diagnostics().is_none()
+            && ModePolicy::Lenient == intcode.mode_policy()
+            && OverflowPolicy::Widened != intcode.overflow_policy()
+    }
+    pub fn new(intcode: &'a mut Intcode<W>, compiled: &'a mut Compiled<W>) -> Native<'a, W> {
+        let generation = intcode.memory.generation();
+        if Some(generation) != compiled.generation {
+            compiled.check_all(&intcode.memory);
+        }
+        Native {
+            intcode,
+            compiled,
+            retired: 0,
+            reach: None,
+        }
+    }
+    pub fn ip(&self) -> usize {
+        self.intcode.ip
+    }
+    pub fn valid(&self, index: usize) -> bool {
+        self.compiled.valid(index)
+    }
+    pub fn state(&self) -> IntcodeState {
+        self.intcode.get_state()
+    }
+    // Whether run carries on, following Intcode::run
+    pub fn runnable(&mut self) -> Result<bool, IntcodeError> {
+        match &self.intcode.state {
+            IntcodeState::Ready => Ok(true),
+            IntcodeState::OutputReady => {
+                self.intcode.state = IntcodeState::Ready;
+                Ok(true)
+            }
+            IntcodeState::Faulted(error) => Err(error.clone()),
+            _ => Ok(false),
+        }
+    }
+    // Faults the machine on an error from a compiled block, the interpreter
+    // has already done so for its own
+    pub fn settle(&mut self, result: Result<(), IntcodeError>) -> Result<(), IntcodeError> {
+        if let Err(error) = result {
+            if let IntcodeState::Faulted(_) = self.intcode.state {
+                return Err(error);
+            }
+            // The faulting instruction does not count as executed
+            self.retired -= 1;
+            self.intcode.state = IntcodeState::Faulted(error.clone());
+            return Err(error);
+        }
+        Ok(())
+    }
+    // One instruction through step, for anything without a valid block
+    pub fn interpret(&mut self) -> Result<(), IntcodeError> {
+        let generation = self.intcode.memory.generation();
+        let result = self.intcode.step();
+        let memory = &self.intcode.memory;
+        match memory.generation() - generation {
+            0 => (),
+            1 => self.compiled.check(memory, memory.last_write().unwrap()),
+            // Extension opcodes can write anywhere
+            _ => self.compiled.check_all(memory),
+        }
+        result
+    }
+    // Starts the instruction at `addr`, faults are reported against it. The
+    // interpreter would have fetched its cells, so they count as touched.
+    pub fn at(&mut self, addr: usize, size: usize) {
+        self.intcode.ip = addr;
+        self.retired += 1;
+        self.reach = self.reach.max(Some(addr + size - 1));
+    }
+    pub fn goto(&mut self, addr: usize) {
+        self.intcode.ip = addr;
+    }
+    pub fn jump(&mut self, target: W) -> Result<(), IntcodeError> {
+        self.intcode.ip = self.intcode.to_address(target)?;
+        Ok(())
+    }
+    pub fn load(&mut self, addr: W) -> Result<W, IntcodeError> {
+        let addr = self.intcode.to_address(addr)?;
+        self.intcode.fetch(addr)
+    }
+    pub fn load_relative(&mut self, offset: W) -> Result<W, IntcodeError> {
+        let addr = self.intcode.to_relative_address(offset)?;
+        self.intcode.fetch(addr)
+    }
+    pub fn store(&mut self, addr: W, value: W) -> Result<(), IntcodeError> {
+        let addr = self.intcode.to_address(addr)?;
+        self.intcode.store(addr, value)?;
+        self.compiled.check(&self.intcode.memory, addr);
+        Ok(())
+    }
+    pub fn store_relative(&mut self, offset: W, value: W) -> Result<(), IntcodeError> {
+        let addr = self.intcode.to_relative_address(offset)?;
+        self.intcode.store(addr, value)?;
+        self.compiled.check(&self.intcode.memory, addr);
+        Ok(())
+    }
+    // Arithmetic under the machine's overflow policy
+    pub fn add(&mut self, a: W, b: W) -> Result<W, IntcodeError> {
+        self.intcode.arithmetic(Arithmetic::Add, a, b)
+    }
+    pub fn multiply(&mut self, a: W, b: W) -> Result<W, IntcodeError> {
+        self.intcode.arithmetic(Arithmetic::Multiply, a, b)
+    }
+    // The next input, or None after leaving the machine waiting for one
+    pub fn input(&mut self) -> Option<W> {
+        let input = self.intcode.input.front().copied();
+        if input.is_none() {
+            self.retired -= 1;
+            self.intcode.state = IntcodeState::WaitingForInput;
+        }
+        input
+    }
+    pub fn consume_input(&mut self) {
+        self.intcode.input.pop_front();
+    }
+    pub fn output(&mut self, value: W) {
+        self.intcode.output.push_back(value);
+    }
+    pub fn shift(&mut self, offset: W) -> Result<(), IntcodeError> {
+        let ip = self.intcode.ip;
+        self.intcode.relative_base = self
+            .intcode
+            .relative_base
+            .checked(Arithmetic::Add, offset)
+            .ok_or(IntcodeError::ArithmeticOverflow { addr: ip })?;
+        Ok(())
+    }
+    pub fn halt(&mut self) {
+        self.intcode.state = IntcodeState::Halted;
+    }
+}
+
+impl<'a, W: Word> Drop for Native<'a, W> {
+    fn drop(&mut self) {
+        self.intcode.retire(self.retired);
+        let memory = &mut self.intcode.memory;
+        memory.set_highest_touched(memory.highest_touched().max(self.reach));
+        // Every write since the run began has been checked as it landed
+        self.compiled.generation = Some(memory.generation());
+    }
+}
+
+#[cfg(test)]
+mod transpiler_tests {
+    use super::*;
+    use crate::intcode::assembler::assemble;
+
+    #[test]
+    fn blocks_become_functions() {
+        let program = assemble(
+            "
+                    in [x]
+                    lt [x], #10, [t]
+                    jz [t], #big
+                    out #1
+                    hlt
+            big:    out [x]
+                    hlt
+            x:      .data 0
+            t:      .data 0
+            ",
+        )
+        .unwrap();
+        let source = transpile(&program, "Small");
+        assert!(source.contains(
+            "static BLOCKS: [(usize, usize); 3] = [\n    (0, 9), (9, 12), (12, 15),\n];\n"
+        ));
+        assert!(source.contains("                0 if n.valid(0) => block_0(&mut n),\n"));
+        assert!(source.contains(
+            "fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {\n\
+             \x20   // 0: in [15]\n\
+             \x20   n.at(0, 2);\n\
+             \x20   let value = match n.input() {\n\
+             \x20       Some(value) => value,\n\
+             \x20       None => return Ok(()),\n\
+             \x20   };\n\
+             \x20   n.store(15, value)?;\n\
+             \x20   n.consume_input();\n\
+             \x20   // 2: lt [15], #10, [16]\n\
+             \x20   n.at(2, 4);\n\
+             \x20   let a = n.load(15)?;\n\
+             \x20   let value = i64::from(a < 10);\n\
+             \x20   n.store(16, value)?;\n\
+             \x20   // 6: jz [16], #12\n\
+             \x20   n.at(6, 3);\n\
+             \x20   let a = n.load(16)?;\n\
+             \x20   if 0 == a {\n\
+             \x20       return n.jump(12);\n\
+             \x20   }\n\
+             \x20   n.goto(9);\n\
+             \x20   Ok(())\n\
+             }\n"
+        ));
+        // Narrower machines get their own word type
+        let narrow: Vec<i32> = program.iter().map(|&value| value as i32).collect();
+        assert!(transpile(&narrow, "Small").contains("static IMAGE: [i32; 17] = [\n"));
+    }
+}
@@ -0,0 +1,100 @@
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Arithmetic {
+    Add,
+    Multiply,
+}
+
+// The type of every memory cell, input and output of a machine. Anything that
+// only reports on a machine (errors, traces, listings) widens values to i128,
+// which holds every word type losslessly.
+pub trait Word:
+    Copy + Default + Eq + Ord + Hash + Debug + Display + FromStr + Send + Sync + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const NAME: &'static str;
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Option<Self>;
+    fn from_usize(value: usize) -> Option<Self>;
+    // Checked, negative or oversized values are not addresses
+    fn to_address(self) -> Option<usize>;
+    fn checked(self, op: Arithmetic, other: Self) -> Option<Self>;
+    fn wrapping(self, op: Arithmetic, other: Self) -> Self;
+    fn saturating(self, op: Arithmetic, other: Self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($word:ty),*) => {
+        $(
+            impl Word for $word {
+                const ZERO: $word = 0;
+                const ONE: $word = 1;
+                const MIN: $word = <$word>::MIN;
+                const MAX: $word = <$word>::MAX;
+                const NAME: &'static str = stringify!($word);
+                fn to_i128(self) -> i128 {
+                    i128::from(self)
+                }
+                fn from_i128(value: i128) -> Option<$word> {
+                    <$word>::try_from(value).ok()
+                }
+                fn from_usize(value: usize) -> Option<$word> {
+                    <$word>::try_from(value).ok()
+                }
+                fn to_address(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+                fn checked(self, op: Arithmetic, other: $word) -> Option<$word> {
+                    match op {
+                        Arithmetic::Add => self.checked_add(other),
+                        Arithmetic::Multiply => self.checked_mul(other),
+                    }
+                }
+                fn wrapping(self, op: Arithmetic, other: $word) -> $word {
+                    match op {
+                        Arithmetic::Add => self.wrapping_add(other),
+                        Arithmetic::Multiply => self.wrapping_mul(other),
+                    }
+                }
+                fn saturating(self, op: Arithmetic, other: $word) -> $word {
+                    match op {
+                        Arithmetic::Add => self.saturating_add(other),
+                        Arithmetic::Multiply => self.saturating_mul(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_word!(i32, i64, i128);
+
+#[cfg(test)]
+mod word_tests {
+    use super::*;
+
+    #[test]
+    fn addresses_are_checked() {
+        assert_eq!(5i32.to_address(), Some(5));
+        assert_eq!((-1i64).to_address(), None);
+        assert_eq!(i128::MAX.to_address(), None);
+        assert_eq!(i32::from_usize(1 << 40), None);
+        assert_eq!(i64::from_usize(1 << 40), Some(1 << 40));
+    }
+    #[test]
+    fn arithmetic_follows_the_word_width() {
+        assert_eq!(i32::MAX.checked(Arithmetic::Add, 1), None);
+        assert_eq!(i32::MAX.wrapping(Arithmetic::Add, 1), i32::MIN);
+        assert_eq!(i32::MAX.saturating(Arithmetic::Multiply, 2), i32::MAX);
+        assert_eq!((i32::MAX as i64).checked(Arithmetic::Add, 1), Some(1 << 31));
+        assert_eq!(i32::NAME, "i32");
+        assert_eq!(i128::from_i128(i128::MIN), Some(i128::MIN));
+    }
+}
@@ -1,3 +1,4 @@
+use advent_of_code_2019::{helpers, intcode};
 use colored::*;
 use std::time::Instant;
 mod day_01;
@@ -8,8 +9,6 @@ mod day_05;
 mod day_07;
 mod day_09;
 mod day_11;
-mod helpers;
-mod intcode;
 
 fn print_divider_green(day: String, p1_result: String, p2_result: String) {
     println!("{}", "-----------------------".bright_green());
@@ -0,0 +1,273 @@
+// Generated by intcode_transpile from a 137 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 137] = [
+    1, 0, 0, 3, 1, 1, 2, 3, 1, 3, 4, 3, 1, 5, 0, 3, 2, 1, 10, 19, 1, 19, 5, 23, 1, 6, 23, 27, 1,
+    27, 5, 31, 2, 31, 10, 35, 2, 35, 6, 39, 1, 39, 5, 43, 2, 43, 9, 47, 1, 47, 6, 51, 1, 13, 51,
+    55, 2, 9, 55, 59, 1, 59, 13, 63, 1, 6, 63, 67, 2, 67, 10, 71, 1, 9, 71, 75, 2, 75, 6, 79, 1,
+    79, 5, 83, 1, 83, 5, 87, 2, 9, 87, 91, 2, 9, 91, 95, 1, 95, 10, 99, 1, 9, 99, 103, 2, 103, 6,
+    107, 2, 9, 107, 111, 1, 111, 5, 115, 2, 6, 115, 119, 1, 5, 119, 123, 1, 123, 2, 127, 1, 127, 9,
+    0, 99, 2, 0, 14, 0,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 1] = [
+    (0, 133),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct Day02 {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl Day02 {
+    pub fn new() -> Day02 {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        Day02 { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for Day02 {
+    fn default() -> Day02 {
+        Day02::new()
+    }
+}
+
+impl Deref for Day02 {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for Day02 {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: add [0], [0], [3]
+    n.at(0, 4);
+    let a = n.load(0)?;
+    let b = n.load(0)?;
+    let value = n.add(a, b)?;
+    n.store(3, value)?;
+    // 4: add [1], [2], [3]
+    n.at(4, 4);
+    let a = n.load(1)?;
+    let b = n.load(2)?;
+    let value = n.add(a, b)?;
+    n.store(3, value)?;
+    // 8: add [3], [4], [3]
+    n.at(8, 4);
+    let a = n.load(3)?;
+    let b = n.load(4)?;
+    let value = n.add(a, b)?;
+    n.store(3, value)?;
+    // 12: add [5], [0], [3]
+    n.at(12, 4);
+    let a = n.load(5)?;
+    let b = n.load(0)?;
+    let value = n.add(a, b)?;
+    n.store(3, value)?;
+    // 16: mul [1], [10], [19]
+    n.at(16, 4);
+    let a = n.load(1)?;
+    let b = n.load(10)?;
+    let value = n.multiply(a, b)?;
+    n.store(19, value)?;
+    // 20: add [19], [5], [23]
+    n.at(20, 4);
+    let a = n.load(19)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(23, value)?;
+    // 24: add [6], [23], [27]
+    n.at(24, 4);
+    let a = n.load(6)?;
+    let b = n.load(23)?;
+    let value = n.add(a, b)?;
+    n.store(27, value)?;
+    // 28: add [27], [5], [31]
+    n.at(28, 4);
+    let a = n.load(27)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(31, value)?;
+    // 32: mul [31], [10], [35]
+    n.at(32, 4);
+    let a = n.load(31)?;
+    let b = n.load(10)?;
+    let value = n.multiply(a, b)?;
+    n.store(35, value)?;
+    // 36: mul [35], [6], [39]
+    n.at(36, 4);
+    let a = n.load(35)?;
+    let b = n.load(6)?;
+    let value = n.multiply(a, b)?;
+    n.store(39, value)?;
+    // 40: add [39], [5], [43]
+    n.at(40, 4);
+    let a = n.load(39)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(43, value)?;
+    // 44: mul [43], [9], [47]
+    n.at(44, 4);
+    let a = n.load(43)?;
+    let b = n.load(9)?;
+    let value = n.multiply(a, b)?;
+    n.store(47, value)?;
+    // 48: add [47], [6], [51]
+    n.at(48, 4);
+    let a = n.load(47)?;
+    let b = n.load(6)?;
+    let value = n.add(a, b)?;
+    n.store(51, value)?;
+    // 52: add [13], [51], [55]
+    n.at(52, 4);
+    let a = n.load(13)?;
+    let b = n.load(51)?;
+    let value = n.add(a, b)?;
+    n.store(55, value)?;
+    // 56: mul [9], [55], [59]
+    n.at(56, 4);
+    let a = n.load(9)?;
+    let b = n.load(55)?;
+    let value = n.multiply(a, b)?;
+    n.store(59, value)?;
+    // 60: add [59], [13], [63]
+    n.at(60, 4);
+    let a = n.load(59)?;
+    let b = n.load(13)?;
+    let value = n.add(a, b)?;
+    n.store(63, value)?;
+    // 64: add [6], [63], [67]
+    n.at(64, 4);
+    let a = n.load(6)?;
+    let b = n.load(63)?;
+    let value = n.add(a, b)?;
+    n.store(67, value)?;
+    // 68: mul [67], [10], [71]
+    n.at(68, 4);
+    let a = n.load(67)?;
+    let b = n.load(10)?;
+    let value = n.multiply(a, b)?;
+    n.store(71, value)?;
+    // 72: add [9], [71], [75]
+    n.at(72, 4);
+    let a = n.load(9)?;
+    let b = n.load(71)?;
+    let value = n.add(a, b)?;
+    n.store(75, value)?;
+    // 76: mul [75], [6], [79]
+    n.at(76, 4);
+    let a = n.load(75)?;
+    let b = n.load(6)?;
+    let value = n.multiply(a, b)?;
+    n.store(79, value)?;
+    // 80: add [79], [5], [83]
+    n.at(80, 4);
+    let a = n.load(79)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(83, value)?;
+    // 84: add [83], [5], [87]
+    n.at(84, 4);
+    let a = n.load(83)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(87, value)?;
+    // 88: mul [9], [87], [91]
+    n.at(88, 4);
+    let a = n.load(9)?;
+    let b = n.load(87)?;
+    let value = n.multiply(a, b)?;
+    n.store(91, value)?;
+    // 92: mul [9], [91], [95]
+    n.at(92, 4);
+    let a = n.load(9)?;
+    let b = n.load(91)?;
+    let value = n.multiply(a, b)?;
+    n.store(95, value)?;
+    // 96: add [95], [10], [99]
+    n.at(96, 4);
+    let a = n.load(95)?;
+    let b = n.load(10)?;
+    let value = n.add(a, b)?;
+    n.store(99, value)?;
+    // 100: add [9], [99], [103]
+    n.at(100, 4);
+    let a = n.load(9)?;
+    let b = n.load(99)?;
+    let value = n.add(a, b)?;
+    n.store(103, value)?;
+    // 104: mul [103], [6], [107]
+    n.at(104, 4);
+    let a = n.load(103)?;
+    let b = n.load(6)?;
+    let value = n.multiply(a, b)?;
+    n.store(107, value)?;
+    // 108: mul [9], [107], [111]
+    n.at(108, 4);
+    let a = n.load(9)?;
+    let b = n.load(107)?;
+    let value = n.multiply(a, b)?;
+    n.store(111, value)?;
+    // 112: add [111], [5], [115]
+    n.at(112, 4);
+    let a = n.load(111)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(115, value)?;
+    // 116: mul [6], [115], [119]
+    n.at(116, 4);
+    let a = n.load(6)?;
+    let b = n.load(115)?;
+    let value = n.multiply(a, b)?;
+    n.store(119, value)?;
+    // 120: add [5], [119], [123]
+    n.at(120, 4);
+    let a = n.load(5)?;
+    let b = n.load(119)?;
+    let value = n.add(a, b)?;
+    n.store(123, value)?;
+    // 124: add [123], [2], [127]
+    n.at(124, 4);
+    let a = n.load(123)?;
+    let b = n.load(2)?;
+    let value = n.add(a, b)?;
+    n.store(127, value)?;
+    // 128: add [127], [9], [0]
+    n.at(128, 4);
+    let a = n.load(127)?;
+    let b = n.load(9)?;
+    let value = n.add(a, b)?;
+    n.store(0, value)?;
+    // 132: hlt
+    n.at(132, 1);
+    n.halt();
+    Ok(())
+}
@@ -0,0 +1,1496 @@
+// Generated by intcode_transpile from a 678 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 678] = [
+    3, 225, 1, 225, 6, 6, 1100, 1, 238, 225, 104, 0, 1102, 7, 85, 225, 1102, 67, 12, 225, 102, 36,
+    65, 224, 1001, 224, -3096, 224, 4, 224, 1002, 223, 8, 223, 101, 4, 224, 224, 1, 224, 223, 223,
+    1001, 17, 31, 224, 1001, 224, -98, 224, 4, 224, 1002, 223, 8, 223, 101, 5, 224, 224, 1, 223,
+    224, 223, 1101, 86, 19, 225, 1101, 5, 27, 225, 1102, 18, 37, 225, 2, 125, 74, 224, 1001, 224,
+    -1406, 224, 4, 224, 102, 8, 223, 223, 101, 2, 224, 224, 1, 224, 223, 223, 1102, 13, 47, 225, 1,
+    99, 14, 224, 1001, 224, -98, 224, 4, 224, 102, 8, 223, 223, 1001, 224, 2, 224, 1, 224, 223,
+    223, 1101, 38, 88, 225, 1102, 91, 36, 224, 101, -3276, 224, 224, 4, 224, 1002, 223, 8, 223,
+    101, 3, 224, 224, 1, 224, 223, 223, 1101, 59, 76, 224, 1001, 224, -135, 224, 4, 224, 102, 8,
+    223, 223, 1001, 224, 6, 224, 1, 223, 224, 223, 101, 90, 195, 224, 1001, 224, -112, 224, 4, 224,
+    102, 8, 223, 223, 1001, 224, 7, 224, 1, 224, 223, 223, 1102, 22, 28, 225, 1002, 69, 47, 224,
+    1001, 224, -235, 224, 4, 224, 1002, 223, 8, 223, 101, 5, 224, 224, 1, 223, 224, 223, 4, 223,
+    99, 0, 0, 0, 677, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1105, 0, 99999, 1105, 227, 247, 1105, 1,
+    99999, 1005, 227, 99999, 1005, 0, 256, 1105, 1, 99999, 1106, 227, 99999, 1106, 0, 265, 1105, 1,
+    99999, 1006, 0, 99999, 1006, 227, 274, 1105, 1, 99999, 1105, 1, 280, 1105, 1, 99999, 1, 225,
+    225, 225, 1101, 294, 0, 0, 105, 1, 0, 1105, 1, 99999, 1106, 0, 300, 1105, 1, 99999, 1, 225,
+    225, 225, 1101, 314, 0, 0, 106, 0, 0, 1105, 1, 99999, 107, 226, 226, 224, 102, 2, 223, 223,
+    1006, 224, 329, 1001, 223, 1, 223, 1107, 677, 226, 224, 1002, 223, 2, 223, 1005, 224, 344, 101,
+    1, 223, 223, 108, 677, 226, 224, 102, 2, 223, 223, 1006, 224, 359, 101, 1, 223, 223, 7, 677,
+    226, 224, 102, 2, 223, 223, 1005, 224, 374, 101, 1, 223, 223, 1008, 677, 226, 224, 1002, 223,
+    2, 223, 1006, 224, 389, 1001, 223, 1, 223, 7, 226, 677, 224, 102, 2, 223, 223, 1005, 224, 404,
+    101, 1, 223, 223, 1007, 226, 226, 224, 102, 2, 223, 223, 1006, 224, 419, 101, 1, 223, 223, 7,
+    226, 226, 224, 102, 2, 223, 223, 1005, 224, 434, 1001, 223, 1, 223, 8, 226, 226, 224, 1002,
+    223, 2, 223, 1006, 224, 449, 101, 1, 223, 223, 1007, 677, 677, 224, 102, 2, 223, 223, 1006,
+    224, 464, 101, 1, 223, 223, 1007, 226, 677, 224, 1002, 223, 2, 223, 1006, 224, 479, 101, 1,
+    223, 223, 108, 226, 226, 224, 102, 2, 223, 223, 1005, 224, 494, 1001, 223, 1, 223, 1108, 677,
+    677, 224, 102, 2, 223, 223, 1005, 224, 509, 1001, 223, 1, 223, 107, 226, 677, 224, 1002, 223,
+    2, 223, 1005, 224, 524, 101, 1, 223, 223, 1108, 677, 226, 224, 1002, 223, 2, 223, 1005, 224,
+    539, 1001, 223, 1, 223, 1008, 677, 677, 224, 1002, 223, 2, 223, 1006, 224, 554, 101, 1, 223,
+    223, 1008, 226, 226, 224, 102, 2, 223, 223, 1005, 224, 569, 1001, 223, 1, 223, 8, 677, 226,
+    224, 102, 2, 223, 223, 1006, 224, 584, 101, 1, 223, 223, 107, 677, 677, 224, 102, 2, 223, 223,
+    1006, 224, 599, 101, 1, 223, 223, 8, 226, 677, 224, 102, 2, 223, 223, 1006, 224, 614, 101, 1,
+    223, 223, 1107, 226, 677, 224, 102, 2, 223, 223, 1006, 224, 629, 101, 1, 223, 223, 108, 677,
+    677, 224, 1002, 223, 2, 223, 1005, 224, 644, 1001, 223, 1, 223, 1107, 226, 226, 224, 102, 2,
+    223, 223, 1005, 224, 659, 101, 1, 223, 223, 1108, 226, 677, 224, 102, 2, 223, 223, 1005, 224,
+    674, 101, 1, 223, 223, 4, 223, 99, 226,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 80] = [
+    (0, 2), (2, 6), (4, 7), (5, 8), (7, 11), (12, 28), (13, 17), (28, 38), (32, 36), (38, 72),
+    (69, 72), (72, 76), (76, 86), (86, 90), (90, 102), (91, 95), (102, 106), (106, 223),
+    (238, 244), (247, 250), (250, 253), (253, 256), (256, 262), (265, 268), (268, 271), (271, 274),
+    (274, 277), (280, 291), (294, 297), (300, 311), (314, 325), (325, 329), (329, 340), (340, 344),
+    (344, 355), (355, 359), (359, 370), (370, 374), (374, 385), (385, 389), (389, 400), (400, 404),
+    (404, 415), (415, 419), (419, 430), (430, 434), (434, 445), (445, 449), (449, 460), (460, 464),
+    (464, 475), (475, 479), (479, 490), (490, 494), (494, 505), (505, 509), (509, 520), (520, 524),
+    (524, 535), (535, 539), (539, 550), (550, 554), (554, 565), (565, 569), (569, 580), (580, 584),
+    (584, 595), (595, 599), (599, 610), (610, 614), (611, 615), (614, 625), (625, 629), (629, 640),
+    (640, 644), (644, 655), (655, 659), (659, 670), (670, 674), (674, 677),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct Day05 {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl Day05 {
+    pub fn new() -> Day05 {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        Day05 { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                2 if n.valid(1) => block_2(&mut n),
+                4 if n.valid(2) => block_4(&mut n),
+                5 if n.valid(3) => block_5(&mut n),
+                7 if n.valid(4) => block_7(&mut n),
+                12 if n.valid(5) => block_12(&mut n),
+                13 if n.valid(6) => block_13(&mut n),
+                28 if n.valid(7) => block_28(&mut n),
+                32 if n.valid(8) => block_32(&mut n),
+                38 if n.valid(9) => block_38(&mut n),
+                69 if n.valid(10) => block_69(&mut n),
+                72 if n.valid(11) => block_72(&mut n),
+                76 if n.valid(12) => block_76(&mut n),
+                86 if n.valid(13) => block_86(&mut n),
+                90 if n.valid(14) => block_90(&mut n),
+                91 if n.valid(15) => block_91(&mut n),
+                102 if n.valid(16) => block_102(&mut n),
+                106 if n.valid(17) => block_106(&mut n),
+                238 if n.valid(18) => block_238(&mut n),
+                247 if n.valid(19) => block_247(&mut n),
+                250 if n.valid(20) => block_250(&mut n),
+                253 if n.valid(21) => block_253(&mut n),
+                256 if n.valid(22) => block_256(&mut n),
+                265 if n.valid(23) => block_265(&mut n),
+                268 if n.valid(24) => block_268(&mut n),
+                271 if n.valid(25) => block_271(&mut n),
+                274 if n.valid(26) => block_274(&mut n),
+                280 if n.valid(27) => block_280(&mut n),
+                294 if n.valid(28) => block_294(&mut n),
+                300 if n.valid(29) => block_300(&mut n),
+                314 if n.valid(30) => block_314(&mut n),
+                325 if n.valid(31) => block_325(&mut n),
+                329 if n.valid(32) => block_329(&mut n),
+                340 if n.valid(33) => block_340(&mut n),
+                344 if n.valid(34) => block_344(&mut n),
+                355 if n.valid(35) => block_355(&mut n),
+                359 if n.valid(36) => block_359(&mut n),
+                370 if n.valid(37) => block_370(&mut n),
+                374 if n.valid(38) => block_374(&mut n),
+                385 if n.valid(39) => block_385(&mut n),
+                389 if n.valid(40) => block_389(&mut n),
+                400 if n.valid(41) => block_400(&mut n),
+                404 if n.valid(42) => block_404(&mut n),
+                415 if n.valid(43) => block_415(&mut n),
+                419 if n.valid(44) => block_419(&mut n),
+                430 if n.valid(45) => block_430(&mut n),
+                434 if n.valid(46) => block_434(&mut n),
+                445 if n.valid(47) => block_445(&mut n),
+                449 if n.valid(48) => block_449(&mut n),
+                460 if n.valid(49) => block_460(&mut n),
+                464 if n.valid(50) => block_464(&mut n),
+                475 if n.valid(51) => block_475(&mut n),
+                479 if n.valid(52) => block_479(&mut n),
+                490 if n.valid(53) => block_490(&mut n),
+                494 if n.valid(54) => block_494(&mut n),
+                505 if n.valid(55) => block_505(&mut n),
+                509 if n.valid(56) => block_509(&mut n),
+                520 if n.valid(57) => block_520(&mut n),
+                524 if n.valid(58) => block_524(&mut n),
+                535 if n.valid(59) => block_535(&mut n),
+                539 if n.valid(60) => block_539(&mut n),
+                550 if n.valid(61) => block_550(&mut n),
+                554 if n.valid(62) => block_554(&mut n),
+                565 if n.valid(63) => block_565(&mut n),
+                569 if n.valid(64) => block_569(&mut n),
+                580 if n.valid(65) => block_580(&mut n),
+                584 if n.valid(66) => block_584(&mut n),
+                595 if n.valid(67) => block_595(&mut n),
+                599 if n.valid(68) => block_599(&mut n),
+                610 if n.valid(69) => block_610(&mut n),
+                611 if n.valid(70) => block_611(&mut n),
+                614 if n.valid(71) => block_614(&mut n),
+                625 if n.valid(72) => block_625(&mut n),
+                629 if n.valid(73) => block_629(&mut n),
+                640 if n.valid(74) => block_640(&mut n),
+                644 if n.valid(75) => block_644(&mut n),
+                655 if n.valid(76) => block_655(&mut n),
+                659 if n.valid(77) => block_659(&mut n),
+                670 if n.valid(78) => block_670(&mut n),
+                674 if n.valid(79) => block_674(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for Day05 {
+    fn default() -> Day05 {
+        Day05::new()
+    }
+}
+
+impl Deref for Day05 {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for Day05 {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: in [225]
+    n.at(0, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(225, value)?;
+    n.consume_input();
+    n.goto(2);
+    Ok(())
+}
+
+fn block_2(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 2: add [225], [6], [6]
+    n.at(2, 4);
+    let a = n.load(225)?;
+    let b = n.load(6)?;
+    let value = n.add(a, b)?;
+    n.store(6, value)?;
+    n.goto(6);
+    Ok(())
+}
+
+fn block_4(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 4: jz [6], [1100]
+    n.at(4, 3);
+    let a = n.load(6)?;
+    let b = n.load(1100)?;
+    if 0 == a {
+        return n.jump(b);
+    }
+    n.goto(7);
+    Ok(())
+}
+
+fn block_5(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 5: jz [1100], [1]
+    n.at(5, 3);
+    let a = n.load(1100)?;
+    let b = n.load(1)?;
+    if 0 == a {
+        return n.jump(b);
+    }
+    n.goto(8);
+    Ok(())
+}
+
+fn block_7(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 7: add [238], [225], [104]
+    n.at(7, 4);
+    let a = n.load(238)?;
+    let b = n.load(225)?;
+    let value = n.add(a, b)?;
+    n.store(104, value)?;
+    n.goto(11);
+    Ok(())
+}
+
+fn block_12(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 12: mul #7, #85, [225]
+    n.at(12, 4);
+    let value = n.multiply(7, 85)?;
+    n.store(225, value)?;
+    // 16: mul #67, #12, [225]
+    n.at(16, 4);
+    let value = n.multiply(67, 12)?;
+    n.store(225, value)?;
+    // 20: mul #36, [65], [224]
+    n.at(20, 4);
+    let b = n.load(65)?;
+    let value = n.multiply(36, b)?;
+    n.store(224, value)?;
+    // 24: add [224], #-3096, [224]
+    n.at(24, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -3096)?;
+    n.store(224, value)?;
+    n.goto(28);
+    Ok(())
+}
+
+fn block_13(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 13: lt [85], [225], [1102]
+    n.at(13, 4);
+    let a = n.load(85)?;
+    let b = n.load(225)?;
+    let value = i64::from(a < b);
+    n.store(1102, value)?;
+    n.goto(17);
+    Ok(())
+}
+
+fn block_28(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 28: out [224]
+    n.at(28, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 30: mul [223], #8, [223]
+    n.at(30, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 8)?;
+    n.store(223, value)?;
+    // 34: add #4, [224], [224]
+    n.at(34, 4);
+    let b = n.load(224)?;
+    let value = n.add(4, b)?;
+    n.store(224, value)?;
+    n.goto(38);
+    Ok(())
+}
+
+fn block_32(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 32: eq [223], [101], [4]
+    n.at(32, 4);
+    let a = n.load(223)?;
+    let b = n.load(101)?;
+    let value = i64::from(a == b);
+    n.store(4, value)?;
+    n.goto(36);
+    Ok(())
+}
+
+fn block_38(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 38: add [224], [223], [223]
+    n.at(38, 4);
+    let a = n.load(224)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 42: add [17], #31, [224]
+    n.at(42, 4);
+    let a = n.load(17)?;
+    let value = n.add(a, 31)?;
+    n.store(224, value)?;
+    // 46: add [224], #-98, [224]
+    n.at(46, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -98)?;
+    n.store(224, value)?;
+    // 50: out [224]
+    n.at(50, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 52: mul [223], #8, [223]
+    n.at(52, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 8)?;
+    n.store(223, value)?;
+    // 56: add #5, [224], [224]
+    n.at(56, 4);
+    let b = n.load(224)?;
+    let value = n.add(5, b)?;
+    n.store(224, value)?;
+    // 60: add [223], [224], [223]
+    n.at(60, 4);
+    let a = n.load(223)?;
+    let b = n.load(224)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 64: add #86, #19, [225]
+    n.at(64, 4);
+    let value = n.add(86, 19)?;
+    n.store(225, value)?;
+    // 68: add #5, #27, [225]
+    n.at(68, 4);
+    let value = n.add(5, 27)?;
+    n.store(225, value)?;
+    n.goto(72);
+    Ok(())
+}
+
+fn block_69(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 69: jnz [27], [225]
+    n.at(69, 3);
+    let a = n.load(27)?;
+    let b = n.load(225)?;
+    if 0 != a {
+        return n.jump(b);
+    }
+    n.goto(72);
+    Ok(())
+}
+
+fn block_72(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 72: mul #18, #37, [225]
+    n.at(72, 4);
+    let value = n.multiply(18, 37)?;
+    n.store(225, value)?;
+    n.goto(76);
+    Ok(())
+}
+
+fn block_76(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 76: mul [125], [74], [224]
+    n.at(76, 4);
+    let a = n.load(125)?;
+    let b = n.load(74)?;
+    let value = n.multiply(a, b)?;
+    n.store(224, value)?;
+    // 80: add [224], #-1406, [224]
+    n.at(80, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -1406)?;
+    n.store(224, value)?;
+    // 84: out [224]
+    n.at(84, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    n.goto(86);
+    Ok(())
+}
+
+fn block_86(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 86: mul #8, [223], [223]
+    n.at(86, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(8, b)?;
+    n.store(223, value)?;
+    n.goto(90);
+    Ok(())
+}
+
+fn block_90(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 90: add #2, [224], [224]
+    n.at(90, 4);
+    let b = n.load(224)?;
+    let value = n.add(2, b)?;
+    n.store(224, value)?;
+    // 94: add [224], [223], [223]
+    n.at(94, 4);
+    let a = n.load(224)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 98: mul #13, #47, [225]
+    n.at(98, 4);
+    let value = n.multiply(13, 47)?;
+    n.store(225, value)?;
+    n.goto(102);
+    Ok(())
+}
+
+fn block_91(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 91: mul [224], [224], [1]
+    n.at(91, 4);
+    let a = n.load(224)?;
+    let b = n.load(224)?;
+    let value = n.multiply(a, b)?;
+    n.store(1, value)?;
+    n.goto(95);
+    Ok(())
+}
+
+fn block_102(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 102: add [99], [14], [224]
+    n.at(102, 4);
+    let a = n.load(99)?;
+    let b = n.load(14)?;
+    let value = n.add(a, b)?;
+    n.store(224, value)?;
+    n.goto(106);
+    Ok(())
+}
+
+fn block_106(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 106: add [224], #-98, [224]
+    n.at(106, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -98)?;
+    n.store(224, value)?;
+    // 110: out [224]
+    n.at(110, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 112: mul #8, [223], [223]
+    n.at(112, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(8, b)?;
+    n.store(223, value)?;
+    // 116: add [224], #2, [224]
+    n.at(116, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, 2)?;
+    n.store(224, value)?;
+    // 120: add [224], [223], [223]
+    n.at(120, 4);
+    let a = n.load(224)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 124: add #38, #88, [225]
+    n.at(124, 4);
+    let value = n.add(38, 88)?;
+    n.store(225, value)?;
+    // 128: mul #91, #36, [224]
+    n.at(128, 4);
+    let value = n.multiply(91, 36)?;
+    n.store(224, value)?;
+    // 132: add #-3276, [224], [224]
+    n.at(132, 4);
+    let b = n.load(224)?;
+    let value = n.add(-3276, b)?;
+    n.store(224, value)?;
+    // 136: out [224]
+    n.at(136, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 138: mul [223], #8, [223]
+    n.at(138, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 8)?;
+    n.store(223, value)?;
+    // 142: add #3, [224], [224]
+    n.at(142, 4);
+    let b = n.load(224)?;
+    let value = n.add(3, b)?;
+    n.store(224, value)?;
+    // 146: add [224], [223], [223]
+    n.at(146, 4);
+    let a = n.load(224)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 150: add #59, #76, [224]
+    n.at(150, 4);
+    let value = n.add(59, 76)?;
+    n.store(224, value)?;
+    // 154: add [224], #-135, [224]
+    n.at(154, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -135)?;
+    n.store(224, value)?;
+    // 158: out [224]
+    n.at(158, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 160: mul #8, [223], [223]
+    n.at(160, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(8, b)?;
+    n.store(223, value)?;
+    // 164: add [224], #6, [224]
+    n.at(164, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, 6)?;
+    n.store(224, value)?;
+    // 168: add [223], [224], [223]
+    n.at(168, 4);
+    let a = n.load(223)?;
+    let b = n.load(224)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 172: add #90, [195], [224]
+    n.at(172, 4);
+    let b = n.load(195)?;
+    let value = n.add(90, b)?;
+    n.store(224, value)?;
+    // 176: add [224], #-112, [224]
+    n.at(176, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -112)?;
+    n.store(224, value)?;
+    // 180: out [224]
+    n.at(180, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 182: mul #8, [223], [223]
+    n.at(182, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(8, b)?;
+    n.store(223, value)?;
+    // 186: add [224], #7, [224]
+    n.at(186, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, 7)?;
+    n.store(224, value)?;
+    // 190: add [224], [223], [223]
+    n.at(190, 4);
+    let a = n.load(224)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 194: mul #22, #28, [225]
+    n.at(194, 4);
+    let value = n.multiply(22, 28)?;
+    n.store(225, value)?;
+    // 198: mul [69], #47, [224]
+    n.at(198, 4);
+    let a = n.load(69)?;
+    let value = n.multiply(a, 47)?;
+    n.store(224, value)?;
+    // 202: add [224], #-235, [224]
+    n.at(202, 4);
+    let a = n.load(224)?;
+    let value = n.add(a, -235)?;
+    n.store(224, value)?;
+    // 206: out [224]
+    n.at(206, 2);
+    let a = n.load(224)?;
+    n.output(a);
+    // 208: mul [223], #8, [223]
+    n.at(208, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 8)?;
+    n.store(223, value)?;
+    // 212: add #5, [224], [224]
+    n.at(212, 4);
+    let b = n.load(224)?;
+    let value = n.add(5, b)?;
+    n.store(224, value)?;
+    // 216: add [223], [224], [223]
+    n.at(216, 4);
+    let a = n.load(223)?;
+    let b = n.load(224)?;
+    let value = n.add(a, b)?;
+    n.store(223, value)?;
+    // 220: out [223]
+    n.at(220, 2);
+    let a = n.load(223)?;
+    n.output(a);
+    // 222: hlt
+    n.at(222, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_238(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 238: jnz #0, #99999
+    n.at(238, 3);
+    // 241: jnz #227, #247
+    n.at(241, 3);
+    n.jump(247)
+}
+
+fn block_247(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 247: jnz [227], #99999
+    n.at(247, 3);
+    let a = n.load(227)?;
+    if 0 != a {
+        return n.jump(99999);
+    }
+    n.goto(250);
+    Ok(())
+}
+
+fn block_250(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 250: jnz [0], #256
+    n.at(250, 3);
+    let a = n.load(0)?;
+    if 0 != a {
+        return n.jump(256);
+    }
+    n.goto(253);
+    Ok(())
+}
+
+fn block_253(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 253: jnz #1, #99999
+    n.at(253, 3);
+    n.jump(99999)
+}
+
+fn block_256(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 256: jz #227, #99999
+    n.at(256, 3);
+    // 259: jz #0, #265
+    n.at(259, 3);
+    n.jump(265)
+}
+
+fn block_265(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 265: jz [0], #99999
+    n.at(265, 3);
+    let a = n.load(0)?;
+    if 0 == a {
+        return n.jump(99999);
+    }
+    n.goto(268);
+    Ok(())
+}
+
+fn block_268(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 268: jz [227], #274
+    n.at(268, 3);
+    let a = n.load(227)?;
+    if 0 == a {
+        return n.jump(274);
+    }
+    n.goto(271);
+    Ok(())
+}
+
+fn block_271(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 271: jnz #1, #99999
+    n.at(271, 3);
+    n.jump(99999)
+}
+
+fn block_274(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 274: jnz #1, #280
+    n.at(274, 3);
+    n.jump(280)
+}
+
+fn block_280(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 280: add [225], [225], [225]
+    n.at(280, 4);
+    let a = n.load(225)?;
+    let b = n.load(225)?;
+    let value = n.add(a, b)?;
+    n.store(225, value)?;
+    // 284: add #294, #0, [0]
+    n.at(284, 4);
+    let value = n.add(294, 0)?;
+    n.store(0, value)?;
+    // 288: jnz #1, [0]
+    n.at(288, 3);
+    let b = n.load(0)?;
+    n.jump(b)
+}
+
+fn block_294(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 294: jz #0, #300
+    n.at(294, 3);
+    n.jump(300)
+}
+
+fn block_300(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 300: add [225], [225], [225]
+    n.at(300, 4);
+    let a = n.load(225)?;
+    let b = n.load(225)?;
+    let value = n.add(a, b)?;
+    n.store(225, value)?;
+    // 304: add #314, #0, [0]
+    n.at(304, 4);
+    let value = n.add(314, 0)?;
+    n.store(0, value)?;
+    // 308: jz #0, [0]
+    n.at(308, 3);
+    let b = n.load(0)?;
+    n.jump(b)
+}
+
+fn block_314(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 314: lt #226, [226], [224]
+    n.at(314, 4);
+    let b = n.load(226)?;
+    let value = i64::from(226 < b);
+    n.store(224, value)?;
+    // 318: mul #2, [223], [223]
+    n.at(318, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 322: jz [224], #329
+    n.at(322, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(329);
+    }
+    n.goto(325);
+    Ok(())
+}
+
+fn block_325(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 325: add [223], #1, [223]
+    n.at(325, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(329);
+    Ok(())
+}
+
+fn block_329(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 329: lt #677, #226, [224]
+    n.at(329, 4);
+    let value = 0;
+    n.store(224, value)?;
+    // 333: mul [223], #2, [223]
+    n.at(333, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 337: jnz [224], #344
+    n.at(337, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(344);
+    }
+    n.goto(340);
+    Ok(())
+}
+
+fn block_340(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 340: add #1, [223], [223]
+    n.at(340, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(344);
+    Ok(())
+}
+
+fn block_344(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 344: eq #677, [226], [224]
+    n.at(344, 4);
+    let b = n.load(226)?;
+    let value = i64::from(677 == b);
+    n.store(224, value)?;
+    // 348: mul #2, [223], [223]
+    n.at(348, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 352: jz [224], #359
+    n.at(352, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(359);
+    }
+    n.goto(355);
+    Ok(())
+}
+
+fn block_355(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 355: add #1, [223], [223]
+    n.at(355, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(359);
+    Ok(())
+}
+
+fn block_359(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 359: lt [677], [226], [224]
+    n.at(359, 4);
+    let a = n.load(677)?;
+    let b = n.load(226)?;
+    let value = i64::from(a < b);
+    n.store(224, value)?;
+    // 363: mul #2, [223], [223]
+    n.at(363, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 367: jnz [224], #374
+    n.at(367, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(374);
+    }
+    n.goto(370);
+    Ok(())
+}
+
+fn block_370(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 370: add #1, [223], [223]
+    n.at(370, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(374);
+    Ok(())
+}
+
+fn block_374(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 374: eq [677], #226, [224]
+    n.at(374, 4);
+    let a = n.load(677)?;
+    let value = i64::from(a == 226);
+    n.store(224, value)?;
+    // 378: mul [223], #2, [223]
+    n.at(378, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 382: jz [224], #389
+    n.at(382, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(389);
+    }
+    n.goto(385);
+    Ok(())
+}
+
+fn block_385(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 385: add [223], #1, [223]
+    n.at(385, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(389);
+    Ok(())
+}
+
+fn block_389(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 389: lt [226], [677], [224]
+    n.at(389, 4);
+    let a = n.load(226)?;
+    let b = n.load(677)?;
+    let value = i64::from(a < b);
+    n.store(224, value)?;
+    // 393: mul #2, [223], [223]
+    n.at(393, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 397: jnz [224], #404
+    n.at(397, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(404);
+    }
+    n.goto(400);
+    Ok(())
+}
+
+fn block_400(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 400: add #1, [223], [223]
+    n.at(400, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(404);
+    Ok(())
+}
+
+fn block_404(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 404: lt [226], #226, [224]
+    n.at(404, 4);
+    let a = n.load(226)?;
+    let value = i64::from(a < 226);
+    n.store(224, value)?;
+    // 408: mul #2, [223], [223]
+    n.at(408, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 412: jz [224], #419
+    n.at(412, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(419);
+    }
+    n.goto(415);
+    Ok(())
+}
+
+fn block_415(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 415: add #1, [223], [223]
+    n.at(415, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(419);
+    Ok(())
+}
+
+fn block_419(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 419: lt [226], [226], [224]
+    n.at(419, 4);
+    let a = n.load(226)?;
+    let b = n.load(226)?;
+    let value = i64::from(a < b);
+    n.store(224, value)?;
+    // 423: mul #2, [223], [223]
+    n.at(423, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 427: jnz [224], #434
+    n.at(427, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(434);
+    }
+    n.goto(430);
+    Ok(())
+}
+
+fn block_430(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 430: add [223], #1, [223]
+    n.at(430, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(434);
+    Ok(())
+}
+
+fn block_434(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 434: eq [226], [226], [224]
+    n.at(434, 4);
+    let a = n.load(226)?;
+    let b = n.load(226)?;
+    let value = i64::from(a == b);
+    n.store(224, value)?;
+    // 438: mul [223], #2, [223]
+    n.at(438, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 442: jz [224], #449
+    n.at(442, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(449);
+    }
+    n.goto(445);
+    Ok(())
+}
+
+fn block_445(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 445: add #1, [223], [223]
+    n.at(445, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(449);
+    Ok(())
+}
+
+fn block_449(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 449: lt [677], #677, [224]
+    n.at(449, 4);
+    let a = n.load(677)?;
+    let value = i64::from(a < 677);
+    n.store(224, value)?;
+    // 453: mul #2, [223], [223]
+    n.at(453, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 457: jz [224], #464
+    n.at(457, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(464);
+    }
+    n.goto(460);
+    Ok(())
+}
+
+fn block_460(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 460: add #1, [223], [223]
+    n.at(460, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(464);
+    Ok(())
+}
+
+fn block_464(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 464: lt [226], #677, [224]
+    n.at(464, 4);
+    let a = n.load(226)?;
+    let value = i64::from(a < 677);
+    n.store(224, value)?;
+    // 468: mul [223], #2, [223]
+    n.at(468, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 472: jz [224], #479
+    n.at(472, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(479);
+    }
+    n.goto(475);
+    Ok(())
+}
+
+fn block_475(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 475: add #1, [223], [223]
+    n.at(475, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(479);
+    Ok(())
+}
+
+fn block_479(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 479: eq #226, [226], [224]
+    n.at(479, 4);
+    let b = n.load(226)?;
+    let value = i64::from(226 == b);
+    n.store(224, value)?;
+    // 483: mul #2, [223], [223]
+    n.at(483, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 487: jnz [224], #494
+    n.at(487, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(494);
+    }
+    n.goto(490);
+    Ok(())
+}
+
+fn block_490(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 490: add [223], #1, [223]
+    n.at(490, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(494);
+    Ok(())
+}
+
+fn block_494(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 494: eq #677, #677, [224]
+    n.at(494, 4);
+    let value = 1;
+    n.store(224, value)?;
+    // 498: mul #2, [223], [223]
+    n.at(498, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 502: jnz [224], #509
+    n.at(502, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(509);
+    }
+    n.goto(505);
+    Ok(())
+}
+
+fn block_505(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 505: add [223], #1, [223]
+    n.at(505, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(509);
+    Ok(())
+}
+
+fn block_509(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 509: lt #226, [677], [224]
+    n.at(509, 4);
+    let b = n.load(677)?;
+    let value = i64::from(226 < b);
+    n.store(224, value)?;
+    // 513: mul [223], #2, [223]
+    n.at(513, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 517: jnz [224], #524
+    n.at(517, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(524);
+    }
+    n.goto(520);
+    Ok(())
+}
+
+fn block_520(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 520: add #1, [223], [223]
+    n.at(520, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(524);
+    Ok(())
+}
+
+fn block_524(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 524: eq #677, #226, [224]
+    n.at(524, 4);
+    let value = 0;
+    n.store(224, value)?;
+    // 528: mul [223], #2, [223]
+    n.at(528, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 532: jnz [224], #539
+    n.at(532, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(539);
+    }
+    n.goto(535);
+    Ok(())
+}
+
+fn block_535(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 535: add [223], #1, [223]
+    n.at(535, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(539);
+    Ok(())
+}
+
+fn block_539(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 539: eq [677], #677, [224]
+    n.at(539, 4);
+    let a = n.load(677)?;
+    let value = i64::from(a == 677);
+    n.store(224, value)?;
+    // 543: mul [223], #2, [223]
+    n.at(543, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 547: jz [224], #554
+    n.at(547, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(554);
+    }
+    n.goto(550);
+    Ok(())
+}
+
+fn block_550(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 550: add #1, [223], [223]
+    n.at(550, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(554);
+    Ok(())
+}
+
+fn block_554(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 554: eq [226], #226, [224]
+    n.at(554, 4);
+    let a = n.load(226)?;
+    let value = i64::from(a == 226);
+    n.store(224, value)?;
+    // 558: mul #2, [223], [223]
+    n.at(558, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 562: jnz [224], #569
+    n.at(562, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(569);
+    }
+    n.goto(565);
+    Ok(())
+}
+
+fn block_565(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 565: add [223], #1, [223]
+    n.at(565, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(569);
+    Ok(())
+}
+
+fn block_569(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 569: eq [677], [226], [224]
+    n.at(569, 4);
+    let a = n.load(677)?;
+    let b = n.load(226)?;
+    let value = i64::from(a == b);
+    n.store(224, value)?;
+    // 573: mul #2, [223], [223]
+    n.at(573, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 577: jz [224], #584
+    n.at(577, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(584);
+    }
+    n.goto(580);
+    Ok(())
+}
+
+fn block_580(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 580: add #1, [223], [223]
+    n.at(580, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(584);
+    Ok(())
+}
+
+fn block_584(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 584: lt #677, [677], [224]
+    n.at(584, 4);
+    let b = n.load(677)?;
+    let value = i64::from(677 < b);
+    n.store(224, value)?;
+    // 588: mul #2, [223], [223]
+    n.at(588, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 592: jz [224], #599
+    n.at(592, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(599);
+    }
+    n.goto(595);
+    Ok(())
+}
+
+fn block_595(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 595: add #1, [223], [223]
+    n.at(595, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(599);
+    Ok(())
+}
+
+fn block_599(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 599: eq [226], [677], [224]
+    n.at(599, 4);
+    let a = n.load(226)?;
+    let b = n.load(677)?;
+    let value = i64::from(a == b);
+    n.store(224, value)?;
+    // 603: mul #2, [223], [223]
+    n.at(603, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 607: jz [224], #614
+    n.at(607, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(614);
+    }
+    n.goto(610);
+    Ok(())
+}
+
+fn block_610(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 610: add #1, [223], [223]
+    n.at(610, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(614);
+    Ok(())
+}
+
+fn block_611(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 611: add [223], [223], [1107]
+    n.at(611, 4);
+    let a = n.load(223)?;
+    let b = n.load(223)?;
+    let value = n.add(a, b)?;
+    n.store(1107, value)?;
+    n.goto(615);
+    Ok(())
+}
+
+fn block_614(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 614: lt #226, #677, [224]
+    n.at(614, 4);
+    let value = 1;
+    n.store(224, value)?;
+    // 618: mul #2, [223], [223]
+    n.at(618, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 622: jz [224], #629
+    n.at(622, 3);
+    let a = n.load(224)?;
+    if 0 == a {
+        return n.jump(629);
+    }
+    n.goto(625);
+    Ok(())
+}
+
+fn block_625(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 625: add #1, [223], [223]
+    n.at(625, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(629);
+    Ok(())
+}
+
+fn block_629(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 629: eq #677, [677], [224]
+    n.at(629, 4);
+    let b = n.load(677)?;
+    let value = i64::from(677 == b);
+    n.store(224, value)?;
+    // 633: mul [223], #2, [223]
+    n.at(633, 4);
+    let a = n.load(223)?;
+    let value = n.multiply(a, 2)?;
+    n.store(223, value)?;
+    // 637: jnz [224], #644
+    n.at(637, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(644);
+    }
+    n.goto(640);
+    Ok(())
+}
+
+fn block_640(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 640: add [223], #1, [223]
+    n.at(640, 4);
+    let a = n.load(223)?;
+    let value = n.add(a, 1)?;
+    n.store(223, value)?;
+    n.goto(644);
+    Ok(())
+}
+
+fn block_644(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 644: lt #226, #226, [224]
+    n.at(644, 4);
+    let value = 0;
+    n.store(224, value)?;
+    // 648: mul #2, [223], [223]
+    n.at(648, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 652: jnz [224], #659
+    n.at(652, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(659);
+    }
+    n.goto(655);
+    Ok(())
+}
+
+fn block_655(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 655: add #1, [223], [223]
+    n.at(655, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(659);
+    Ok(())
+}
+
+fn block_659(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 659: eq #226, #677, [224]
+    n.at(659, 4);
+    let value = 0;
+    n.store(224, value)?;
+    // 663: mul #2, [223], [223]
+    n.at(663, 4);
+    let b = n.load(223)?;
+    let value = n.multiply(2, b)?;
+    n.store(223, value)?;
+    // 667: jnz [224], #674
+    n.at(667, 3);
+    let a = n.load(224)?;
+    if 0 != a {
+        return n.jump(674);
+    }
+    n.goto(670);
+    Ok(())
+}
+
+fn block_670(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 670: add #1, [223], [223]
+    n.at(670, 4);
+    let b = n.load(223)?;
+    let value = n.add(1, b)?;
+    n.store(223, value)?;
+    n.goto(674);
+    Ok(())
+}
+
+fn block_674(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 674: out [223]
+    n.at(674, 2);
+    let a = n.load(223)?;
+    n.output(a);
+    // 676: hlt
+    n.at(676, 1);
+    n.halt();
+    Ok(())
+}
@@ -0,0 +1,1631 @@
+// Generated by intcode_transpile from a 499 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 499] = [
+    3, 8, 1001, 8, 10, 8, 105, 1, 0, 0, 21, 30, 51, 72, 81, 94, 175, 256, 337, 418, 99999, 3, 9,
+    101, 5, 9, 9, 4, 9, 99, 3, 9, 1001, 9, 3, 9, 1002, 9, 2, 9, 1001, 9, 2, 9, 1002, 9, 5, 9, 4, 9,
+    99, 3, 9, 1002, 9, 4, 9, 101, 4, 9, 9, 102, 5, 9, 9, 101, 3, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 4,
+    9, 4, 9, 99, 3, 9, 102, 3, 9, 9, 1001, 9, 4, 9, 4, 9, 99, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101,
+    2, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9,
+    3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9,
+    9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 99, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3,
+    9, 1001, 9, 1, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 1002, 9, 2,
+    9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9,
+    102, 2, 9, 9, 4, 9, 99, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2,
+    9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9,
+    102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4,
+    9, 99, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9,
+    1001, 9, 1, 9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9,
+    4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 99, 3, 9,
+    101, 2, 9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4,
+    9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 1001,
+    9, 2, 9, 4, 9, 3, 9, 1001, 9, 1, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4, 9, 99,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 62] = [
+    (0, 2), (1, 5), (2, 9), (3, 11), (5, 9), (21, 30), (30, 51), (51, 72), (72, 81), (81, 94),
+    (94, 102), (99, 101), (101, 175), (102, 110), (110, 118), (118, 126), (126, 134), (134, 142),
+    (142, 150), (150, 158), (158, 166), (166, 175), (175, 183), (183, 191), (191, 199), (199, 207),
+    (207, 215), (215, 223), (223, 231), (231, 239), (239, 247), (247, 256), (256, 264), (264, 272),
+    (272, 280), (280, 288), (288, 296), (296, 304), (304, 312), (312, 320), (320, 328), (328, 337),
+    (337, 345), (345, 353), (353, 361), (361, 369), (369, 377), (377, 385), (385, 393), (393, 401),
+    (401, 409), (409, 418), (418, 426), (426, 434), (434, 442), (442, 450), (450, 458), (458, 466),
+    (466, 474), (474, 482), (482, 490), (490, 499),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct Day07 {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl Day07 {
+    pub fn new() -> Day07 {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        Day07 { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                1 if n.valid(1) => block_1(&mut n),
+                2 if n.valid(2) => block_2(&mut n),
+                3 if n.valid(3) => block_3(&mut n),
+                5 if n.valid(4) => block_5(&mut n),
+                21 if n.valid(5) => block_21(&mut n),
+                30 if n.valid(6) => block_30(&mut n),
+                51 if n.valid(7) => block_51(&mut n),
+                72 if n.valid(8) => block_72(&mut n),
+                81 if n.valid(9) => block_81(&mut n),
+                94 if n.valid(10) => block_94(&mut n),
+                99 if n.valid(11) => block_99(&mut n),
+                101 if n.valid(12) => block_101(&mut n),
+                102 if n.valid(13) => block_102(&mut n),
+                110 if n.valid(14) => block_110(&mut n),
+                118 if n.valid(15) => block_118(&mut n),
+                126 if n.valid(16) => block_126(&mut n),
+                134 if n.valid(17) => block_134(&mut n),
+                142 if n.valid(18) => block_142(&mut n),
+                150 if n.valid(19) => block_150(&mut n),
+                158 if n.valid(20) => block_158(&mut n),
+                166 if n.valid(21) => block_166(&mut n),
+                175 if n.valid(22) => block_175(&mut n),
+                183 if n.valid(23) => block_183(&mut n),
+                191 if n.valid(24) => block_191(&mut n),
+                199 if n.valid(25) => block_199(&mut n),
+                207 if n.valid(26) => block_207(&mut n),
+                215 if n.valid(27) => block_215(&mut n),
+                223 if n.valid(28) => block_223(&mut n),
+                231 if n.valid(29) => block_231(&mut n),
+                239 if n.valid(30) => block_239(&mut n),
+                247 if n.valid(31) => block_247(&mut n),
+                256 if n.valid(32) => block_256(&mut n),
+                264 if n.valid(33) => block_264(&mut n),
+                272 if n.valid(34) => block_272(&mut n),
+                280 if n.valid(35) => block_280(&mut n),
+                288 if n.valid(36) => block_288(&mut n),
+                296 if n.valid(37) => block_296(&mut n),
+                304 if n.valid(38) => block_304(&mut n),
+                312 if n.valid(39) => block_312(&mut n),
+                320 if n.valid(40) => block_320(&mut n),
+                328 if n.valid(41) => block_328(&mut n),
+                337 if n.valid(42) => block_337(&mut n),
+                345 if n.valid(43) => block_345(&mut n),
+                353 if n.valid(44) => block_353(&mut n),
+                361 if n.valid(45) => block_361(&mut n),
+                369 if n.valid(46) => block_369(&mut n),
+                377 if n.valid(47) => block_377(&mut n),
+                385 if n.valid(48) => block_385(&mut n),
+                393 if n.valid(49) => block_393(&mut n),
+                401 if n.valid(50) => block_401(&mut n),
+                409 if n.valid(51) => block_409(&mut n),
+                418 if n.valid(52) => block_418(&mut n),
+                426 if n.valid(53) => block_426(&mut n),
+                434 if n.valid(54) => block_434(&mut n),
+                442 if n.valid(55) => block_442(&mut n),
+                450 if n.valid(56) => block_450(&mut n),
+                458 if n.valid(57) => block_458(&mut n),
+                466 if n.valid(58) => block_466(&mut n),
+                474 if n.valid(59) => block_474(&mut n),
+                482 if n.valid(60) => block_482(&mut n),
+                490 if n.valid(61) => block_490(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for Day07 {
+    fn default() -> Day07 {
+        Day07::new()
+    }
+}
+
+impl Deref for Day07 {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for Day07 {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: in [8]
+    n.at(0, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    n.goto(2);
+    Ok(())
+}
+
+fn block_1(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 1: eq [1001], [8], [10]
+    n.at(1, 4);
+    let a = n.load(1001)?;
+    let b = n.load(8)?;
+    let value = i64::from(a == b);
+    n.store(10, value)?;
+    n.goto(5);
+    Ok(())
+}
+
+fn block_2(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 2: add [8], #10, [8]
+    n.at(2, 4);
+    let a = n.load(8)?;
+    let value = n.add(a, 10)?;
+    n.store(8, value)?;
+    if !n.valid(2) {
+        n.goto(6);
+        return Ok(());
+    }
+    // 6: jnz #1, [0]
+    n.at(6, 3);
+    let b = n.load(0)?;
+    n.jump(b)
+}
+
+fn block_3(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 3: eq [10], [8], [105]
+    n.at(3, 4);
+    let a = n.load(10)?;
+    let b = n.load(8)?;
+    let value = i64::from(a == b);
+    n.store(105, value)?;
+    // 7: add [0], [0], [21]
+    n.at(7, 4);
+    let a = n.load(0)?;
+    let b = n.load(0)?;
+    let value = n.add(a, b)?;
+    n.store(21, value)?;
+    n.goto(11);
+    Ok(())
+}
+
+fn block_5(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 5: eq [105], [1], [0]
+    n.at(5, 4);
+    let a = n.load(105)?;
+    let b = n.load(1)?;
+    let value = i64::from(a == b);
+    n.store(0, value)?;
+    n.goto(9);
+    Ok(())
+}
+
+fn block_21(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 21: in [9]
+    n.at(21, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 23: add #5, [9], [9]
+    n.at(23, 4);
+    let b = n.load(9)?;
+    let value = n.add(5, b)?;
+    n.store(9, value)?;
+    // 27: out [9]
+    n.at(27, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 29: hlt
+    n.at(29, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_30(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 30: in [9]
+    n.at(30, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 32: add [9], #3, [9]
+    n.at(32, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 3)?;
+    n.store(9, value)?;
+    // 36: mul [9], #2, [9]
+    n.at(36, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 40: add [9], #2, [9]
+    n.at(40, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 44: mul [9], #5, [9]
+    n.at(44, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 5)?;
+    n.store(9, value)?;
+    // 48: out [9]
+    n.at(48, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 50: hlt
+    n.at(50, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_51(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 51: in [9]
+    n.at(51, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 53: mul [9], #4, [9]
+    n.at(53, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 4)?;
+    n.store(9, value)?;
+    // 57: add #4, [9], [9]
+    n.at(57, 4);
+    let b = n.load(9)?;
+    let value = n.add(4, b)?;
+    n.store(9, value)?;
+    // 61: mul #5, [9], [9]
+    n.at(61, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(5, b)?;
+    n.store(9, value)?;
+    // 65: add #3, [9], [9]
+    n.at(65, 4);
+    let b = n.load(9)?;
+    let value = n.add(3, b)?;
+    n.store(9, value)?;
+    // 69: out [9]
+    n.at(69, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 71: hlt
+    n.at(71, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_72(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 72: in [9]
+    n.at(72, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 74: mul [9], #4, [9]
+    n.at(74, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 4)?;
+    n.store(9, value)?;
+    // 78: out [9]
+    n.at(78, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 80: hlt
+    n.at(80, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_81(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 81: in [9]
+    n.at(81, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 83: mul #3, [9], [9]
+    n.at(83, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(3, b)?;
+    n.store(9, value)?;
+    // 87: add [9], #4, [9]
+    n.at(87, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 4)?;
+    n.store(9, value)?;
+    // 91: out [9]
+    n.at(91, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 93: hlt
+    n.at(93, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_94(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 94: in [9]
+    n.at(94, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 96: add [9], #2, [9]
+    n.at(96, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 100: out [9]
+    n.at(100, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(102);
+    Ok(())
+}
+
+fn block_99(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 99: arb [4]
+    n.at(99, 2);
+    let a = n.load(4)?;
+    n.shift(a)?;
+    n.goto(101);
+    Ok(())
+}
+
+fn block_101(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 101: arb [3]
+    n.at(101, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 103: arb [101]
+    n.at(103, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 105: mul [9], [9], [4]
+    n.at(105, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.multiply(a, b)?;
+    n.store(4, value)?;
+    // 109: arb [3]
+    n.at(109, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 111: arb [101]
+    n.at(111, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 113: mul [9], [9], [4]
+    n.at(113, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.multiply(a, b)?;
+    n.store(4, value)?;
+    // 117: arb [3]
+    n.at(117, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 119: arb [1001]
+    n.at(119, 2);
+    let a = n.load(1001)?;
+    n.shift(a)?;
+    // 121: arb [1]
+    n.at(121, 2);
+    let a = n.load(1)?;
+    n.shift(a)?;
+    // 123: arb [4]
+    n.at(123, 2);
+    let a = n.load(4)?;
+    n.shift(a)?;
+    // 125: arb [3]
+    n.at(125, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 127: arb [101]
+    n.at(127, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 129: add [9], [9], [4]
+    n.at(129, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.add(a, b)?;
+    n.store(4, value)?;
+    // 133: arb [3]
+    n.at(133, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 135: arb [101]
+    n.at(135, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 137: add [9], [9], [4]
+    n.at(137, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.add(a, b)?;
+    n.store(4, value)?;
+    // 141: arb [3]
+    n.at(141, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 143: arb [101]
+    n.at(143, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 145: add [9], [9], [4]
+    n.at(145, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.add(a, b)?;
+    n.store(4, value)?;
+    // 149: arb [3]
+    n.at(149, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 151: arb [1002]
+    n.at(151, 2);
+    let a = n.load(1002)?;
+    n.shift(a)?;
+    // 153: arb [2]
+    n.at(153, 2);
+    let a = n.load(2)?;
+    n.shift(a)?;
+    // 155: arb [4]
+    n.at(155, 2);
+    let a = n.load(4)?;
+    n.shift(a)?;
+    // 157: arb [3]
+    n.at(157, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 159: arb [101]
+    n.at(159, 2);
+    let a = n.load(101)?;
+    n.shift(a)?;
+    // 161: add [9], [9], [4]
+    n.at(161, 4);
+    let a = n.load(9)?;
+    let b = n.load(9)?;
+    let value = n.add(a, b)?;
+    n.store(4, value)?;
+    // 165: arb [3]
+    n.at(165, 2);
+    let a = n.load(3)?;
+    n.shift(a)?;
+    // 167: arb [1002]
+    n.at(167, 2);
+    let a = n.load(1002)?;
+    n.shift(a)?;
+    // 169: arb [2]
+    n.at(169, 2);
+    let a = n.load(2)?;
+    n.shift(a)?;
+    // 171: arb [4]
+    n.at(171, 2);
+    let a = n.load(4)?;
+    n.shift(a)?;
+    // 173: arb [99]
+    n.at(173, 2);
+    let a = n.load(99)?;
+    n.shift(a)?;
+    n.goto(175);
+    Ok(())
+}
+
+fn block_102(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 102: in [9]
+    n.at(102, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 104: add #2, [9], [9]
+    n.at(104, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 108: out [9]
+    n.at(108, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(110);
+    Ok(())
+}
+
+fn block_110(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 110: in [9]
+    n.at(110, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 112: add #2, [9], [9]
+    n.at(112, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 116: out [9]
+    n.at(116, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(118);
+    Ok(())
+}
+
+fn block_118(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 118: in [9]
+    n.at(118, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 120: add [9], #1, [9]
+    n.at(120, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 124: out [9]
+    n.at(124, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(126);
+    Ok(())
+}
+
+fn block_126(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 126: in [9]
+    n.at(126, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 128: add #1, [9], [9]
+    n.at(128, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 132: out [9]
+    n.at(132, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(134);
+    Ok(())
+}
+
+fn block_134(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 134: in [9]
+    n.at(134, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 136: add #1, [9], [9]
+    n.at(136, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 140: out [9]
+    n.at(140, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(142);
+    Ok(())
+}
+
+fn block_142(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 142: in [9]
+    n.at(142, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 144: add #1, [9], [9]
+    n.at(144, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 148: out [9]
+    n.at(148, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(150);
+    Ok(())
+}
+
+fn block_150(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 150: in [9]
+    n.at(150, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 152: mul [9], #2, [9]
+    n.at(152, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 156: out [9]
+    n.at(156, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(158);
+    Ok(())
+}
+
+fn block_158(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 158: in [9]
+    n.at(158, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 160: add #1, [9], [9]
+    n.at(160, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 164: out [9]
+    n.at(164, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(166);
+    Ok(())
+}
+
+fn block_166(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 166: in [9]
+    n.at(166, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 168: mul [9], #2, [9]
+    n.at(168, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 172: out [9]
+    n.at(172, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 174: hlt
+    n.at(174, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_175(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 175: in [9]
+    n.at(175, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 177: mul #2, [9], [9]
+    n.at(177, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 181: out [9]
+    n.at(181, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(183);
+    Ok(())
+}
+
+fn block_183(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 183: in [9]
+    n.at(183, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 185: mul [9], #2, [9]
+    n.at(185, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 189: out [9]
+    n.at(189, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(191);
+    Ok(())
+}
+
+fn block_191(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 191: in [9]
+    n.at(191, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 193: add [9], #1, [9]
+    n.at(193, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 197: out [9]
+    n.at(197, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(199);
+    Ok(())
+}
+
+fn block_199(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 199: in [9]
+    n.at(199, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 201: mul #2, [9], [9]
+    n.at(201, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 205: out [9]
+    n.at(205, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(207);
+    Ok(())
+}
+
+fn block_207(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 207: in [9]
+    n.at(207, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 209: add [9], #2, [9]
+    n.at(209, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 213: out [9]
+    n.at(213, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(215);
+    Ok(())
+}
+
+fn block_215(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 215: in [9]
+    n.at(215, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 217: mul [9], #2, [9]
+    n.at(217, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 221: out [9]
+    n.at(221, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(223);
+    Ok(())
+}
+
+fn block_223(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 223: in [9]
+    n.at(223, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 225: add #1, [9], [9]
+    n.at(225, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 229: out [9]
+    n.at(229, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(231);
+    Ok(())
+}
+
+fn block_231(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 231: in [9]
+    n.at(231, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 233: add #1, [9], [9]
+    n.at(233, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 237: out [9]
+    n.at(237, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(239);
+    Ok(())
+}
+
+fn block_239(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 239: in [9]
+    n.at(239, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 241: add #2, [9], [9]
+    n.at(241, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 245: out [9]
+    n.at(245, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(247);
+    Ok(())
+}
+
+fn block_247(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 247: in [9]
+    n.at(247, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 249: mul #2, [9], [9]
+    n.at(249, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 253: out [9]
+    n.at(253, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 255: hlt
+    n.at(255, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_256(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 256: in [9]
+    n.at(256, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 258: mul #2, [9], [9]
+    n.at(258, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 262: out [9]
+    n.at(262, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(264);
+    Ok(())
+}
+
+fn block_264(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 264: in [9]
+    n.at(264, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 266: mul #2, [9], [9]
+    n.at(266, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 270: out [9]
+    n.at(270, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(272);
+    Ok(())
+}
+
+fn block_272(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 272: in [9]
+    n.at(272, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 274: mul [9], #2, [9]
+    n.at(274, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 278: out [9]
+    n.at(278, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(280);
+    Ok(())
+}
+
+fn block_280(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 280: in [9]
+    n.at(280, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 282: add [9], #1, [9]
+    n.at(282, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 286: out [9]
+    n.at(286, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(288);
+    Ok(())
+}
+
+fn block_288(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 288: in [9]
+    n.at(288, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 290: mul [9], #2, [9]
+    n.at(290, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 294: out [9]
+    n.at(294, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(296);
+    Ok(())
+}
+
+fn block_296(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 296: in [9]
+    n.at(296, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 298: mul #2, [9], [9]
+    n.at(298, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 302: out [9]
+    n.at(302, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(304);
+    Ok(())
+}
+
+fn block_304(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 304: in [9]
+    n.at(304, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 306: mul #2, [9], [9]
+    n.at(306, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 310: out [9]
+    n.at(310, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(312);
+    Ok(())
+}
+
+fn block_312(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 312: in [9]
+    n.at(312, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 314: mul #2, [9], [9]
+    n.at(314, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 318: out [9]
+    n.at(318, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(320);
+    Ok(())
+}
+
+fn block_320(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 320: in [9]
+    n.at(320, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 322: mul #2, [9], [9]
+    n.at(322, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 326: out [9]
+    n.at(326, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(328);
+    Ok(())
+}
+
+fn block_328(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 328: in [9]
+    n.at(328, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 330: mul #2, [9], [9]
+    n.at(330, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 334: out [9]
+    n.at(334, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 336: hlt
+    n.at(336, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_337(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 337: in [9]
+    n.at(337, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 339: add [9], #2, [9]
+    n.at(339, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 343: out [9]
+    n.at(343, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(345);
+    Ok(())
+}
+
+fn block_345(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 345: in [9]
+    n.at(345, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 347: add #1, [9], [9]
+    n.at(347, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 351: out [9]
+    n.at(351, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(353);
+    Ok(())
+}
+
+fn block_353(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 353: in [9]
+    n.at(353, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 355: add [9], #1, [9]
+    n.at(355, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 359: out [9]
+    n.at(359, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(361);
+    Ok(())
+}
+
+fn block_361(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 361: in [9]
+    n.at(361, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 363: add [9], #1, [9]
+    n.at(363, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 367: out [9]
+    n.at(367, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(369);
+    Ok(())
+}
+
+fn block_369(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 369: in [9]
+    n.at(369, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 371: mul #2, [9], [9]
+    n.at(371, 4);
+    let b = n.load(9)?;
+    let value = n.multiply(2, b)?;
+    n.store(9, value)?;
+    // 375: out [9]
+    n.at(375, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(377);
+    Ok(())
+}
+
+fn block_377(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 377: in [9]
+    n.at(377, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 379: add #1, [9], [9]
+    n.at(379, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 383: out [9]
+    n.at(383, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(385);
+    Ok(())
+}
+
+fn block_385(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 385: in [9]
+    n.at(385, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 387: add [9], #2, [9]
+    n.at(387, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 391: out [9]
+    n.at(391, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(393);
+    Ok(())
+}
+
+fn block_393(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 393: in [9]
+    n.at(393, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 395: mul [9], #2, [9]
+    n.at(395, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 399: out [9]
+    n.at(399, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(401);
+    Ok(())
+}
+
+fn block_401(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 401: in [9]
+    n.at(401, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 403: add [9], #1, [9]
+    n.at(403, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 407: out [9]
+    n.at(407, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(409);
+    Ok(())
+}
+
+fn block_409(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 409: in [9]
+    n.at(409, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 411: add #2, [9], [9]
+    n.at(411, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 415: out [9]
+    n.at(415, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 417: hlt
+    n.at(417, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_418(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 418: in [9]
+    n.at(418, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 420: add #2, [9], [9]
+    n.at(420, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 424: out [9]
+    n.at(424, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(426);
+    Ok(())
+}
+
+fn block_426(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 426: in [9]
+    n.at(426, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 428: add #2, [9], [9]
+    n.at(428, 4);
+    let b = n.load(9)?;
+    let value = n.add(2, b)?;
+    n.store(9, value)?;
+    // 432: out [9]
+    n.at(432, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(434);
+    Ok(())
+}
+
+fn block_434(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 434: in [9]
+    n.at(434, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 436: add #1, [9], [9]
+    n.at(436, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 440: out [9]
+    n.at(440, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(442);
+    Ok(())
+}
+
+fn block_442(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 442: in [9]
+    n.at(442, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 444: add [9], #1, [9]
+    n.at(444, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 448: out [9]
+    n.at(448, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(450);
+    Ok(())
+}
+
+fn block_450(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 450: in [9]
+    n.at(450, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 452: mul [9], #2, [9]
+    n.at(452, 4);
+    let a = n.load(9)?;
+    let value = n.multiply(a, 2)?;
+    n.store(9, value)?;
+    // 456: out [9]
+    n.at(456, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(458);
+    Ok(())
+}
+
+fn block_458(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 458: in [9]
+    n.at(458, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 460: add [9], #1, [9]
+    n.at(460, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 464: out [9]
+    n.at(464, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(466);
+    Ok(())
+}
+
+fn block_466(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 466: in [9]
+    n.at(466, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 468: add [9], #1, [9]
+    n.at(468, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 472: out [9]
+    n.at(472, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(474);
+    Ok(())
+}
+
+fn block_474(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 474: in [9]
+    n.at(474, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 476: add [9], #2, [9]
+    n.at(476, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 2)?;
+    n.store(9, value)?;
+    // 480: out [9]
+    n.at(480, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(482);
+    Ok(())
+}
+
+fn block_482(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 482: in [9]
+    n.at(482, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 484: add [9], #1, [9]
+    n.at(484, 4);
+    let a = n.load(9)?;
+    let value = n.add(a, 1)?;
+    n.store(9, value)?;
+    // 488: out [9]
+    n.at(488, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    n.goto(490);
+    Ok(())
+}
+
+fn block_490(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 490: in [9]
+    n.at(490, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(9, value)?;
+    n.consume_input();
+    // 492: add #1, [9], [9]
+    n.at(492, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 496: out [9]
+    n.at(496, 2);
+    let a = n.load(9)?;
+    n.output(a);
+    // 498: hlt
+    n.at(498, 1);
+    n.halt();
+    Ok(())
+}
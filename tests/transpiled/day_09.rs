@@ -0,0 +1,2344 @@
+// Generated by intcode_transpile from a 973 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 973] = [
+    1102, 34463338, 34463338, 63, 1007, 63, 34463338, 63, 1005, 63, 53, 1102, 1, 3, 1000, 109, 988,
+    209, 12, 9, 1000, 209, 6, 209, 3, 203, 0, 1008, 1000, 1, 63, 1005, 63, 65, 1008, 1000, 2, 63,
+    1005, 63, 904, 1008, 1000, 0, 63, 1005, 63, 58, 4, 25, 104, 0, 99, 4, 0, 104, 0, 99, 4, 17,
+    104, 0, 99, 0, 0, 1102, 1, 21, 1004, 1101, 28, 0, 1016, 1101, 0, 27, 1010, 1102, 36, 1, 1008,
+    1102, 33, 1, 1013, 1101, 0, 22, 1012, 1101, 0, 37, 1011, 1102, 34, 1, 1017, 1102, 466, 1, 1027,
+    1102, 1, 484, 1029, 1102, 1, 699, 1024, 1102, 1, 1, 1021, 1101, 0, 0, 1020, 1102, 1, 24, 1015,
+    1101, 0, 473, 1026, 1101, 653, 0, 1022, 1102, 26, 1, 1007, 1102, 25, 1, 1006, 1101, 0, 39,
+    1014, 1102, 646, 1, 1023, 1101, 690, 0, 1025, 1102, 1, 29, 1019, 1101, 32, 0, 1018, 1101, 30,
+    0, 1002, 1101, 0, 20, 1001, 1102, 1, 38, 1005, 1102, 1, 23, 1003, 1101, 0, 31, 1000, 1101, 35,
+    0, 1009, 1101, 0, 493, 1028, 109, 5, 1208, 0, 37, 63, 1005, 63, 201, 1001, 64, 1, 64, 1106, 0,
+    203, 4, 187, 1002, 64, 2, 64, 109, -4, 2107, 36, 8, 63, 1005, 63, 223, 1001, 64, 1, 64, 1105,
+    1, 225, 4, 209, 1002, 64, 2, 64, 109, 18, 21107, 40, 41, -9, 1005, 1010, 243, 4, 231, 1105, 1,
+    247, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 6, 21107, 41, 40, -9, 1005, 1016, 267, 1001, 64, 1,
+    64, 1106, 0, 269, 4, 253, 1002, 64, 2, 64, 109, -19, 21102, 42, 1, 5, 1008, 1011, 42, 63, 1005,
+    63, 291, 4, 275, 1105, 1, 295, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 15, 1205, 0, 309, 4, 301,
+    1105, 1, 313, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -27, 2101, 0, 9, 63, 1008, 63, 20, 63,
+    1005, 63, 333, 1106, 0, 339, 4, 319, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 19, 21102, 43, 1,
+    6, 1008, 1019, 45, 63, 1005, 63, 363, 1001, 64, 1, 64, 1105, 1, 365, 4, 345, 1002, 64, 2, 64,
+    109, 1, 21108, 44, 47, -3, 1005, 1011, 385, 1001, 64, 1, 64, 1106, 0, 387, 4, 371, 1002, 64, 2,
+    64, 109, -22, 1201, 9, 0, 63, 1008, 63, 21, 63, 1005, 63, 411, 1001, 64, 1, 64, 1106, 0, 413,
+    4, 393, 1002, 64, 2, 64, 109, 9, 1207, 0, 19, 63, 1005, 63, 433, 1001, 64, 1, 64, 1106, 0, 435,
+    4, 419, 1002, 64, 2, 64, 109, -9, 2107, 30, 8, 63, 1005, 63, 453, 4, 441, 1105, 1, 457, 1001,
+    64, 1, 64, 1002, 64, 2, 64, 109, 25, 2106, 0, 10, 1001, 64, 1, 64, 1106, 0, 475, 4, 463, 1002,
+    64, 2, 64, 109, 11, 2106, 0, 0, 4, 481, 1001, 64, 1, 64, 1105, 1, 493, 1002, 64, 2, 64, 109,
+    -18, 2108, 21, -6, 63, 1005, 63, 511, 4, 499, 1106, 0, 515, 1001, 64, 1, 64, 1002, 64, 2, 64,
+    109, -12, 2108, 18, 6, 63, 1005, 63, 535, 1001, 64, 1, 64, 1106, 0, 537, 4, 521, 1002, 64, 2,
+    64, 109, 19, 21101, 45, 0, -7, 1008, 1010, 45, 63, 1005, 63, 563, 4, 543, 1001, 64, 1, 64,
+    1105, 1, 563, 1002, 64, 2, 64, 109, -10, 1207, -5, 31, 63, 1005, 63, 581, 4, 569, 1106, 0, 585,
+    1001, 64, 1, 64, 1002, 64, 2, 64, 109, -8, 2102, 1, 5, 63, 1008, 63, 21, 63, 1005, 63, 611, 4,
+    591, 1001, 64, 1, 64, 1105, 1, 611, 1002, 64, 2, 64, 109, 5, 1201, 0, 0, 63, 1008, 63, 21, 63,
+    1005, 63, 633, 4, 617, 1106, 0, 637, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 13, 2105, 1, 6,
+    1001, 64, 1, 64, 1106, 0, 655, 4, 643, 1002, 64, 2, 64, 109, -7, 1202, -3, 1, 63, 1008, 63, 26,
+    63, 1005, 63, 681, 4, 661, 1001, 64, 1, 64, 1106, 0, 681, 1002, 64, 2, 64, 109, 12, 2105, 1, 2,
+    4, 687, 1001, 64, 1, 64, 1105, 1, 699, 1002, 64, 2, 64, 109, -28, 1208, 8, 30, 63, 1005, 63,
+    717, 4, 705, 1106, 0, 721, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, 10, 1202, 1, 1, 63, 1008, 63,
+    40, 63, 1005, 63, 745, 1001, 64, 1, 64, 1105, 1, 747, 4, 727, 1002, 64, 2, 64, 109, 10, 21108,
+    46, 46, -2, 1005, 1012, 765, 4, 753, 1105, 1, 769, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -2,
+    1205, 8, 781, 1106, 0, 787, 4, 775, 1001, 64, 1, 64, 1002, 64, 2, 64, 109, -9, 2101, 0, 0, 63,
+    1008, 63, 23, 63, 1005, 63, 809, 4, 793, 1105, 1, 813, 1001, 64, 1, 64, 1002, 64, 2, 64, 109,
+    9, 1206, 8, 831, 4, 819, 1001, 64, 1, 64, 1106, 0, 831, 1002, 64, 2, 64, 109, -9, 2102, 1, -2,
+    63, 1008, 63, 22, 63, 1005, 63, 855, 1001, 64, 1, 64, 1106, 0, 857, 4, 837, 1002, 64, 2, 64,
+    109, 4, 21101, 47, 0, 10, 1008, 1017, 50, 63, 1005, 63, 877, 1105, 1, 883, 4, 863, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, 18, 1206, -4, 895, 1105, 1, 901, 4, 889, 1001, 64, 1, 64, 4, 64, 99,
+    21101, 0, 27, 1, 21102, 915, 1, 0, 1106, 0, 922, 21201, 1, 56639, 1, 204, 1, 99, 109, 3, 1207,
+    -2, 3, 63, 1005, 63, 964, 21201, -2, -1, 1, 21102, 1, 942, 0, 1106, 0, 922, 22102, 1, 1, -1,
+    21201, -2, -3, 1, 21101, 0, 957, 0, 1106, 0, 922, 22201, 1, -1, -2, 1106, 0, 968, 22102, 1, -2,
+    -2, 109, -3, 2106, 0, 0,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 145] = [
+    (0, 4), (4, 8), (8, 11), (11, 19), (12, 16), (13, 19), (19, 21), (21, 23), (22, 25), (23, 25),
+    (24, 26), (25, 27), (27, 31), (29, 33), (31, 34), (34, 38), (36, 40), (38, 41), (41, 45),
+    (45, 48), (48, 50), (50, 53), (53, 58), (58, 63), (65, 109), (99, 103), (109, 194), (194, 201),
+    (201, 203), (203, 216), (216, 223), (223, 225), (225, 238), (238, 243), (243, 247), (247, 260),
+    (260, 267), (267, 269), (269, 286), (286, 291), (291, 295), (295, 304), (304, 309), (309, 313),
+    (313, 330), (330, 333), (333, 339), (339, 356), (356, 363), (363, 365), (365, 378), (378, 385),
+    (385, 387), (387, 404), (404, 411), (411, 413), (413, 426), (426, 433), (433, 435), (435, 448),
+    (448, 453), (453, 457), (457, 463), (463, 466), (466, 473), (473, 475), (475, 481), (481, 484),
+    (484, 493), (493, 499), (499, 506), (506, 511), (511, 515), (515, 521), (521, 528), (528, 535),
+    (535, 537), (537, 543), (543, 554), (554, 563), (563, 569), (569, 576), (576, 581), (581, 585),
+    (585, 591), (591, 602), (602, 611), (611, 617), (617, 628), (628, 633), (633, 637), (637, 643),
+    (643, 646), (646, 653), (653, 655), (655, 661), (661, 672), (672, 681), (681, 687), (687, 690),
+    (690, 699), (699, 705), (705, 712), (712, 717), (717, 721), (721, 727), (727, 738), (738, 745),
+    (745, 747), (747, 753), (753, 760), (760, 765), (765, 769), (769, 775), (775, 778), (778, 781),
+    (781, 787), (787, 793), (793, 804), (804, 809), (809, 813), (813, 819), (819, 822), (822, 831),
+    (831, 837), (837, 848), (848, 855), (855, 857), (857, 863), (863, 874), (874, 877), (877, 883),
+    (883, 889), (889, 892), (892, 895), (895, 901), (901, 904), (904, 915), (915, 922), (922, 931),
+    (931, 942), (942, 957), (957, 964), (964, 968), (968, 973),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct Day09 {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl Day09 {
+    pub fn new() -> Day09 {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        Day09 { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                4 if n.valid(1) => block_4(&mut n),
+                8 if n.valid(2) => block_8(&mut n),
+                11 if n.valid(3) => block_11(&mut n),
+                12 if n.valid(4) => block_12(&mut n),
+                13 if n.valid(5) => block_13(&mut n),
+                19 if n.valid(6) => block_19(&mut n),
+                21 if n.valid(7) => block_21(&mut n),
+                22 if n.valid(8) => block_22(&mut n),
+                23 if n.valid(9) => block_23(&mut n),
+                24 if n.valid(10) => block_24(&mut n),
+                25 if n.valid(11) => block_25(&mut n),
+                27 if n.valid(12) => block_27(&mut n),
+                29 if n.valid(13) => block_29(&mut n),
+                31 if n.valid(14) => block_31(&mut n),
+                34 if n.valid(15) => block_34(&mut n),
+                36 if n.valid(16) => block_36(&mut n),
+                38 if n.valid(17) => block_38(&mut n),
+                41 if n.valid(18) => block_41(&mut n),
+                45 if n.valid(19) => block_45(&mut n),
+                48 if n.valid(20) => block_48(&mut n),
+                50 if n.valid(21) => block_50(&mut n),
+                53 if n.valid(22) => block_53(&mut n),
+                58 if n.valid(23) => block_58(&mut n),
+                65 if n.valid(24) => block_65(&mut n),
+                99 if n.valid(25) => block_99(&mut n),
+                109 if n.valid(26) => block_109(&mut n),
+                194 if n.valid(27) => block_194(&mut n),
+                201 if n.valid(28) => block_201(&mut n),
+                203 if n.valid(29) => block_203(&mut n),
+                216 if n.valid(30) => block_216(&mut n),
+                223 if n.valid(31) => block_223(&mut n),
+                225 if n.valid(32) => block_225(&mut n),
+                238 if n.valid(33) => block_238(&mut n),
+                243 if n.valid(34) => block_243(&mut n),
+                247 if n.valid(35) => block_247(&mut n),
+                260 if n.valid(36) => block_260(&mut n),
+                267 if n.valid(37) => block_267(&mut n),
+                269 if n.valid(38) => block_269(&mut n),
+                286 if n.valid(39) => block_286(&mut n),
+                291 if n.valid(40) => block_291(&mut n),
+                295 if n.valid(41) => block_295(&mut n),
+                304 if n.valid(42) => block_304(&mut n),
+                309 if n.valid(43) => block_309(&mut n),
+                313 if n.valid(44) => block_313(&mut n),
+                330 if n.valid(45) => block_330(&mut n),
+                333 if n.valid(46) => block_333(&mut n),
+                339 if n.valid(47) => block_339(&mut n),
+                356 if n.valid(48) => block_356(&mut n),
+                363 if n.valid(49) => block_363(&mut n),
+                365 if n.valid(50) => block_365(&mut n),
+                378 if n.valid(51) => block_378(&mut n),
+                385 if n.valid(52) => block_385(&mut n),
+                387 if n.valid(53) => block_387(&mut n),
+                404 if n.valid(54) => block_404(&mut n),
+                411 if n.valid(55) => block_411(&mut n),
+                413 if n.valid(56) => block_413(&mut n),
+                426 if n.valid(57) => block_426(&mut n),
+                433 if n.valid(58) => block_433(&mut n),
+                435 if n.valid(59) => block_435(&mut n),
+                448 if n.valid(60) => block_448(&mut n),
+                453 if n.valid(61) => block_453(&mut n),
+                457 if n.valid(62) => block_457(&mut n),
+                463 if n.valid(63) => block_463(&mut n),
+                466 if n.valid(64) => block_466(&mut n),
+                473 if n.valid(65) => block_473(&mut n),
+                475 if n.valid(66) => block_475(&mut n),
+                481 if n.valid(67) => block_481(&mut n),
+                484 if n.valid(68) => block_484(&mut n),
+                493 if n.valid(69) => block_493(&mut n),
+                499 if n.valid(70) => block_499(&mut n),
+                506 if n.valid(71) => block_506(&mut n),
+                511 if n.valid(72) => block_511(&mut n),
+                515 if n.valid(73) => block_515(&mut n),
+                521 if n.valid(74) => block_521(&mut n),
+                528 if n.valid(75) => block_528(&mut n),
+                535 if n.valid(76) => block_535(&mut n),
+                537 if n.valid(77) => block_537(&mut n),
+                543 if n.valid(78) => block_543(&mut n),
+                554 if n.valid(79) => block_554(&mut n),
+                563 if n.valid(80) => block_563(&mut n),
+                569 if n.valid(81) => block_569(&mut n),
+                576 if n.valid(82) => block_576(&mut n),
+                581 if n.valid(83) => block_581(&mut n),
+                585 if n.valid(84) => block_585(&mut n),
+                591 if n.valid(85) => block_591(&mut n),
+                602 if n.valid(86) => block_602(&mut n),
+                611 if n.valid(87) => block_611(&mut n),
+                617 if n.valid(88) => block_617(&mut n),
+                628 if n.valid(89) => block_628(&mut n),
+                633 if n.valid(90) => block_633(&mut n),
+                637 if n.valid(91) => block_637(&mut n),
+                643 if n.valid(92) => block_643(&mut n),
+                646 if n.valid(93) => block_646(&mut n),
+                653 if n.valid(94) => block_653(&mut n),
+                655 if n.valid(95) => block_655(&mut n),
+                661 if n.valid(96) => block_661(&mut n),
+                672 if n.valid(97) => block_672(&mut n),
+                681 if n.valid(98) => block_681(&mut n),
+                687 if n.valid(99) => block_687(&mut n),
+                690 if n.valid(100) => block_690(&mut n),
+                699 if n.valid(101) => block_699(&mut n),
+                705 if n.valid(102) => block_705(&mut n),
+                712 if n.valid(103) => block_712(&mut n),
+                717 if n.valid(104) => block_717(&mut n),
+                721 if n.valid(105) => block_721(&mut n),
+                727 if n.valid(106) => block_727(&mut n),
+                738 if n.valid(107) => block_738(&mut n),
+                745 if n.valid(108) => block_745(&mut n),
+                747 if n.valid(109) => block_747(&mut n),
+                753 if n.valid(110) => block_753(&mut n),
+                760 if n.valid(111) => block_760(&mut n),
+                765 if n.valid(112) => block_765(&mut n),
+                769 if n.valid(113) => block_769(&mut n),
+                775 if n.valid(114) => block_775(&mut n),
+                778 if n.valid(115) => block_778(&mut n),
+                781 if n.valid(116) => block_781(&mut n),
+                787 if n.valid(117) => block_787(&mut n),
+                793 if n.valid(118) => block_793(&mut n),
+                804 if n.valid(119) => block_804(&mut n),
+                809 if n.valid(120) => block_809(&mut n),
+                813 if n.valid(121) => block_813(&mut n),
+                819 if n.valid(122) => block_819(&mut n),
+                822 if n.valid(123) => block_822(&mut n),
+                831 if n.valid(124) => block_831(&mut n),
+                837 if n.valid(125) => block_837(&mut n),
+                848 if n.valid(126) => block_848(&mut n),
+                855 if n.valid(127) => block_855(&mut n),
+                857 if n.valid(128) => block_857(&mut n),
+                863 if n.valid(129) => block_863(&mut n),
+                874 if n.valid(130) => block_874(&mut n),
+                877 if n.valid(131) => block_877(&mut n),
+                883 if n.valid(132) => block_883(&mut n),
+                889 if n.valid(133) => block_889(&mut n),
+                892 if n.valid(134) => block_892(&mut n),
+                895 if n.valid(135) => block_895(&mut n),
+                901 if n.valid(136) => block_901(&mut n),
+                904 if n.valid(137) => block_904(&mut n),
+                915 if n.valid(138) => block_915(&mut n),
+                922 if n.valid(139) => block_922(&mut n),
+                931 if n.valid(140) => block_931(&mut n),
+                942 if n.valid(141) => block_942(&mut n),
+                957 if n.valid(142) => block_957(&mut n),
+                964 if n.valid(143) => block_964(&mut n),
+                968 if n.valid(144) => block_968(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for Day09 {
+    fn default() -> Day09 {
+        Day09::new()
+    }
+}
+
+impl Deref for Day09 {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for Day09 {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: mul #34463338, #34463338, [63]
+    n.at(0, 4);
+    let value = n.multiply(34463338, 34463338)?;
+    n.store(63, value)?;
+    n.goto(4);
+    Ok(())
+}
+
+fn block_4(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 4: lt [63], #34463338, [63]
+    n.at(4, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a < 34463338);
+    n.store(63, value)?;
+    n.goto(8);
+    Ok(())
+}
+
+fn block_8(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 8: jnz [63], #53
+    n.at(8, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(53);
+    }
+    n.goto(11);
+    Ok(())
+}
+
+fn block_11(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 11: mul #1, #3, [1000]
+    n.at(11, 4);
+    let value = n.multiply(1, 3)?;
+    n.store(1000, value)?;
+    // 15: arb #988
+    n.at(15, 2);
+    n.shift(988)?;
+    // 17: arb [rb+12]
+    n.at(17, 2);
+    let a = n.load_relative(12)?;
+    n.shift(a)?;
+    n.goto(19);
+    Ok(())
+}
+
+fn block_12(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 12: add [3], [1000], [109]
+    n.at(12, 4);
+    let a = n.load(3)?;
+    let b = n.load(1000)?;
+    let value = n.add(a, b)?;
+    n.store(109, value)?;
+    n.goto(16);
+    Ok(())
+}
+
+fn block_13(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 13: in [1000]
+    n.at(13, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(1000, value)?;
+    n.consume_input();
+    // 15: arb #988
+    n.at(15, 2);
+    n.shift(988)?;
+    // 17: arb [rb+12]
+    n.at(17, 2);
+    let a = n.load_relative(12)?;
+    n.shift(a)?;
+    n.goto(19);
+    Ok(())
+}
+
+fn block_19(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 19: arb [1000]
+    n.at(19, 2);
+    let a = n.load(1000)?;
+    n.shift(a)?;
+    n.goto(21);
+    Ok(())
+}
+
+fn block_21(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 21: arb [rb+6]
+    n.at(21, 2);
+    let a = n.load_relative(6)?;
+    n.shift(a)?;
+    n.goto(23);
+    Ok(())
+}
+
+fn block_22(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 22: jz [209], [3]
+    n.at(22, 3);
+    let a = n.load(209)?;
+    let b = n.load(3)?;
+    if 0 == a {
+        return n.jump(b);
+    }
+    n.goto(25);
+    Ok(())
+}
+
+fn block_23(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 23: arb [rb+3]
+    n.at(23, 2);
+    let a = n.load_relative(3)?;
+    n.shift(a)?;
+    n.goto(25);
+    Ok(())
+}
+
+fn block_24(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 24: in [203]
+    n.at(24, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(203, value)?;
+    n.consume_input();
+    n.goto(26);
+    Ok(())
+}
+
+fn block_25(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 25: in [rb+0]
+    n.at(25, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store_relative(0, value)?;
+    n.consume_input();
+    n.goto(27);
+    Ok(())
+}
+
+fn block_27(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 27: eq [1000], #1, [63]
+    n.at(27, 4);
+    let a = n.load(1000)?;
+    let value = i64::from(a == 1);
+    n.store(63, value)?;
+    n.goto(31);
+    Ok(())
+}
+
+fn block_29(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 29: add [63], [1005], [63]
+    n.at(29, 4);
+    let a = n.load(63)?;
+    let b = n.load(1005)?;
+    let value = n.add(a, b)?;
+    n.store(63, value)?;
+    n.goto(33);
+    Ok(())
+}
+
+fn block_31(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 31: jnz [63], #65
+    n.at(31, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(65);
+    }
+    n.goto(34);
+    Ok(())
+}
+
+fn block_34(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 34: eq [1000], #2, [63]
+    n.at(34, 4);
+    let a = n.load(1000)?;
+    let value = i64::from(a == 2);
+    n.store(63, value)?;
+    n.goto(38);
+    Ok(())
+}
+
+fn block_36(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 36: mul [63], [1005], [63]
+    n.at(36, 4);
+    let a = n.load(63)?;
+    let b = n.load(1005)?;
+    let value = n.multiply(a, b)?;
+    n.store(63, value)?;
+    n.goto(40);
+    Ok(())
+}
+
+fn block_38(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 38: jnz [63], #904
+    n.at(38, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(904);
+    }
+    n.goto(41);
+    Ok(())
+}
+
+fn block_41(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 41: eq [1000], #0, [63]
+    n.at(41, 4);
+    let a = n.load(1000)?;
+    let value = i64::from(a == 0);
+    n.store(63, value)?;
+    n.goto(45);
+    Ok(())
+}
+
+fn block_45(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 45: jnz [63], #58
+    n.at(45, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(58);
+    }
+    n.goto(48);
+    Ok(())
+}
+
+fn block_48(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 48: out [25]
+    n.at(48, 2);
+    let a = n.load(25)?;
+    n.output(a);
+    n.goto(50);
+    Ok(())
+}
+
+fn block_50(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 50: out #0
+    n.at(50, 2);
+    n.output(0);
+    // 52: hlt
+    n.at(52, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_53(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 53: out [0]
+    n.at(53, 2);
+    let a = n.load(0)?;
+    n.output(a);
+    // 55: out #0
+    n.at(55, 2);
+    n.output(0);
+    // 57: hlt
+    n.at(57, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_58(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 58: out [17]
+    n.at(58, 2);
+    let a = n.load(17)?;
+    n.output(a);
+    // 60: out #0
+    n.at(60, 2);
+    n.output(0);
+    // 62: hlt
+    n.at(62, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_65(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 65: mul #1, #21, [1004]
+    n.at(65, 4);
+    let value = n.multiply(1, 21)?;
+    n.store(1004, value)?;
+    // 69: add #28, #0, [1016]
+    n.at(69, 4);
+    let value = n.add(28, 0)?;
+    n.store(1016, value)?;
+    // 73: add #0, #27, [1010]
+    n.at(73, 4);
+    let value = n.add(0, 27)?;
+    n.store(1010, value)?;
+    // 77: mul #36, #1, [1008]
+    n.at(77, 4);
+    let value = n.multiply(36, 1)?;
+    n.store(1008, value)?;
+    // 81: mul #33, #1, [1013]
+    n.at(81, 4);
+    let value = n.multiply(33, 1)?;
+    n.store(1013, value)?;
+    // 85: add #0, #22, [1012]
+    n.at(85, 4);
+    let value = n.add(0, 22)?;
+    n.store(1012, value)?;
+    // 89: add #0, #37, [1011]
+    n.at(89, 4);
+    let value = n.add(0, 37)?;
+    n.store(1011, value)?;
+    // 93: mul #34, #1, [1017]
+    n.at(93, 4);
+    let value = n.multiply(34, 1)?;
+    n.store(1017, value)?;
+    // 97: mul #466, #1, [1027]
+    n.at(97, 4);
+    let value = n.multiply(466, 1)?;
+    n.store(1027, value)?;
+    // 101: mul #1, #484, [1029]
+    n.at(101, 4);
+    let value = n.multiply(1, 484)?;
+    n.store(1029, value)?;
+    // 105: mul #1, #699, [1024]
+    n.at(105, 4);
+    let value = n.multiply(1, 699)?;
+    n.store(1024, value)?;
+    n.goto(109);
+    Ok(())
+}
+
+fn block_99(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 99: add [1027], [1102], [1]
+    n.at(99, 4);
+    let a = n.load(1027)?;
+    let b = n.load(1102)?;
+    let value = n.add(a, b)?;
+    n.store(1, value)?;
+    n.goto(103);
+    Ok(())
+}
+
+fn block_109(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 109: mul #1, #1, [1021]
+    n.at(109, 4);
+    let value = n.multiply(1, 1)?;
+    n.store(1021, value)?;
+    // 113: add #0, #0, [1020]
+    n.at(113, 4);
+    let value = n.add(0, 0)?;
+    n.store(1020, value)?;
+    // 117: mul #1, #24, [1015]
+    n.at(117, 4);
+    let value = n.multiply(1, 24)?;
+    n.store(1015, value)?;
+    // 121: add #0, #473, [1026]
+    n.at(121, 4);
+    let value = n.add(0, 473)?;
+    n.store(1026, value)?;
+    // 125: add #653, #0, [1022]
+    n.at(125, 4);
+    let value = n.add(653, 0)?;
+    n.store(1022, value)?;
+    // 129: mul #26, #1, [1007]
+    n.at(129, 4);
+    let value = n.multiply(26, 1)?;
+    n.store(1007, value)?;
+    // 133: mul #25, #1, [1006]
+    n.at(133, 4);
+    let value = n.multiply(25, 1)?;
+    n.store(1006, value)?;
+    // 137: add #0, #39, [1014]
+    n.at(137, 4);
+    let value = n.add(0, 39)?;
+    n.store(1014, value)?;
+    // 141: mul #646, #1, [1023]
+    n.at(141, 4);
+    let value = n.multiply(646, 1)?;
+    n.store(1023, value)?;
+    // 145: add #690, #0, [1025]
+    n.at(145, 4);
+    let value = n.add(690, 0)?;
+    n.store(1025, value)?;
+    // 149: mul #1, #29, [1019]
+    n.at(149, 4);
+    let value = n.multiply(1, 29)?;
+    n.store(1019, value)?;
+    // 153: add #32, #0, [1018]
+    n.at(153, 4);
+    let value = n.add(32, 0)?;
+    n.store(1018, value)?;
+    // 157: add #30, #0, [1002]
+    n.at(157, 4);
+    let value = n.add(30, 0)?;
+    n.store(1002, value)?;
+    // 161: add #0, #20, [1001]
+    n.at(161, 4);
+    let value = n.add(0, 20)?;
+    n.store(1001, value)?;
+    // 165: mul #1, #38, [1005]
+    n.at(165, 4);
+    let value = n.multiply(1, 38)?;
+    n.store(1005, value)?;
+    // 169: mul #1, #23, [1003]
+    n.at(169, 4);
+    let value = n.multiply(1, 23)?;
+    n.store(1003, value)?;
+    // 173: add #0, #31, [1000]
+    n.at(173, 4);
+    let value = n.add(0, 31)?;
+    n.store(1000, value)?;
+    // 177: add #35, #0, [1009]
+    n.at(177, 4);
+    let value = n.add(35, 0)?;
+    n.store(1009, value)?;
+    // 181: add #0, #493, [1028]
+    n.at(181, 4);
+    let value = n.add(0, 493)?;
+    n.store(1028, value)?;
+    // 185: arb #5
+    n.at(185, 2);
+    n.shift(5)?;
+    // 187: eq [rb+0], #37, [63]
+    n.at(187, 4);
+    let a = n.load_relative(0)?;
+    let value = i64::from(a == 37);
+    n.store(63, value)?;
+    // 191: jnz [63], #201
+    n.at(191, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(201);
+    }
+    n.goto(194);
+    Ok(())
+}
+
+fn block_194(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 194: add [64], #1, [64]
+    n.at(194, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 198: jz #0, #203
+    n.at(198, 3);
+    n.jump(203)
+}
+
+fn block_201(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 201: out [187]
+    n.at(201, 2);
+    let a = n.load(187)?;
+    n.output(a);
+    n.goto(203);
+    Ok(())
+}
+
+fn block_203(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 203: mul [64], #2, [64]
+    n.at(203, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 207: arb #-4
+    n.at(207, 2);
+    n.shift(-4)?;
+    // 209: lt #36, [rb+8], [63]
+    n.at(209, 4);
+    let b = n.load_relative(8)?;
+    let value = i64::from(36 < b);
+    n.store(63, value)?;
+    // 213: jnz [63], #223
+    n.at(213, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(223);
+    }
+    n.goto(216);
+    Ok(())
+}
+
+fn block_216(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 216: add [64], #1, [64]
+    n.at(216, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 220: jnz #1, #225
+    n.at(220, 3);
+    n.jump(225)
+}
+
+fn block_223(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 223: out [209]
+    n.at(223, 2);
+    let a = n.load(209)?;
+    n.output(a);
+    n.goto(225);
+    Ok(())
+}
+
+fn block_225(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 225: mul [64], #2, [64]
+    n.at(225, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 229: arb #18
+    n.at(229, 2);
+    n.shift(18)?;
+    // 231: lt #40, #41, [rb-9]
+    n.at(231, 4);
+    let value = 1;
+    n.store_relative(-9, value)?;
+    if !n.valid(32) {
+        n.goto(235);
+        return Ok(());
+    }
+    // 235: jnz [1010], #243
+    n.at(235, 3);
+    let a = n.load(1010)?;
+    if 0 != a {
+        return n.jump(243);
+    }
+    n.goto(238);
+    Ok(())
+}
+
+fn block_238(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 238: out [231]
+    n.at(238, 2);
+    let a = n.load(231)?;
+    n.output(a);
+    // 240: jnz #1, #247
+    n.at(240, 3);
+    n.jump(247)
+}
+
+fn block_243(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 243: add [64], #1, [64]
+    n.at(243, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(247);
+    Ok(())
+}
+
+fn block_247(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 247: mul [64], #2, [64]
+    n.at(247, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 251: arb #6
+    n.at(251, 2);
+    n.shift(6)?;
+    // 253: lt #41, #40, [rb-9]
+    n.at(253, 4);
+    let value = 0;
+    n.store_relative(-9, value)?;
+    if !n.valid(35) {
+        n.goto(257);
+        return Ok(());
+    }
+    // 257: jnz [1016], #267
+    n.at(257, 3);
+    let a = n.load(1016)?;
+    if 0 != a {
+        return n.jump(267);
+    }
+    n.goto(260);
+    Ok(())
+}
+
+fn block_260(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 260: add [64], #1, [64]
+    n.at(260, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 264: jz #0, #269
+    n.at(264, 3);
+    n.jump(269)
+}
+
+fn block_267(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 267: out [253]
+    n.at(267, 2);
+    let a = n.load(253)?;
+    n.output(a);
+    n.goto(269);
+    Ok(())
+}
+
+fn block_269(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 269: mul [64], #2, [64]
+    n.at(269, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 273: arb #-19
+    n.at(273, 2);
+    n.shift(-19)?;
+    // 275: mul #42, #1, [rb+5]
+    n.at(275, 4);
+    let value = n.multiply(42, 1)?;
+    n.store_relative(5, value)?;
+    if !n.valid(38) {
+        n.goto(279);
+        return Ok(());
+    }
+    // 279: eq [1011], #42, [63]
+    n.at(279, 4);
+    let a = n.load(1011)?;
+    let value = i64::from(a == 42);
+    n.store(63, value)?;
+    // 283: jnz [63], #291
+    n.at(283, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(291);
+    }
+    n.goto(286);
+    Ok(())
+}
+
+fn block_286(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 286: out [275]
+    n.at(286, 2);
+    let a = n.load(275)?;
+    n.output(a);
+    // 288: jnz #1, #295
+    n.at(288, 3);
+    n.jump(295)
+}
+
+fn block_291(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 291: add [64], #1, [64]
+    n.at(291, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(295);
+    Ok(())
+}
+
+fn block_295(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 295: mul [64], #2, [64]
+    n.at(295, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 299: arb #15
+    n.at(299, 2);
+    n.shift(15)?;
+    // 301: jnz [rb+0], #309
+    n.at(301, 3);
+    let a = n.load_relative(0)?;
+    if 0 != a {
+        return n.jump(309);
+    }
+    n.goto(304);
+    Ok(())
+}
+
+fn block_304(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 304: out [301]
+    n.at(304, 2);
+    let a = n.load(301)?;
+    n.output(a);
+    // 306: jnz #1, #313
+    n.at(306, 3);
+    n.jump(313)
+}
+
+fn block_309(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 309: add [64], #1, [64]
+    n.at(309, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(313);
+    Ok(())
+}
+
+fn block_313(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 313: mul [64], #2, [64]
+    n.at(313, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 317: arb #-27
+    n.at(317, 2);
+    n.shift(-27)?;
+    // 319: add #0, [rb+9], [63]
+    n.at(319, 4);
+    let b = n.load_relative(9)?;
+    let value = n.add(0, b)?;
+    n.store(63, value)?;
+    // 323: eq [63], #20, [63]
+    n.at(323, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 20);
+    n.store(63, value)?;
+    // 327: jnz [63], #333
+    n.at(327, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(333);
+    }
+    n.goto(330);
+    Ok(())
+}
+
+fn block_330(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 330: jz #0, #339
+    n.at(330, 3);
+    n.jump(339)
+}
+
+fn block_333(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 333: out [319]
+    n.at(333, 2);
+    let a = n.load(319)?;
+    n.output(a);
+    // 335: add [64], #1, [64]
+    n.at(335, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(339);
+    Ok(())
+}
+
+fn block_339(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 339: mul [64], #2, [64]
+    n.at(339, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 343: arb #19
+    n.at(343, 2);
+    n.shift(19)?;
+    // 345: mul #43, #1, [rb+6]
+    n.at(345, 4);
+    let value = n.multiply(43, 1)?;
+    n.store_relative(6, value)?;
+    if !n.valid(47) {
+        n.goto(349);
+        return Ok(());
+    }
+    // 349: eq [1019], #45, [63]
+    n.at(349, 4);
+    let a = n.load(1019)?;
+    let value = i64::from(a == 45);
+    n.store(63, value)?;
+    // 353: jnz [63], #363
+    n.at(353, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(363);
+    }
+    n.goto(356);
+    Ok(())
+}
+
+fn block_356(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 356: add [64], #1, [64]
+    n.at(356, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 360: jnz #1, #365
+    n.at(360, 3);
+    n.jump(365)
+}
+
+fn block_363(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 363: out [345]
+    n.at(363, 2);
+    let a = n.load(345)?;
+    n.output(a);
+    n.goto(365);
+    Ok(())
+}
+
+fn block_365(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 365: mul [64], #2, [64]
+    n.at(365, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 369: arb #1
+    n.at(369, 2);
+    n.shift(1)?;
+    // 371: eq #44, #47, [rb-3]
+    n.at(371, 4);
+    let value = 0;
+    n.store_relative(-3, value)?;
+    if !n.valid(50) {
+        n.goto(375);
+        return Ok(());
+    }
+    // 375: jnz [1011], #385
+    n.at(375, 3);
+    let a = n.load(1011)?;
+    if 0 != a {
+        return n.jump(385);
+    }
+    n.goto(378);
+    Ok(())
+}
+
+fn block_378(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 378: add [64], #1, [64]
+    n.at(378, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 382: jz #0, #387
+    n.at(382, 3);
+    n.jump(387)
+}
+
+fn block_385(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 385: out [371]
+    n.at(385, 2);
+    let a = n.load(371)?;
+    n.output(a);
+    n.goto(387);
+    Ok(())
+}
+
+fn block_387(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 387: mul [64], #2, [64]
+    n.at(387, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 391: arb #-22
+    n.at(391, 2);
+    n.shift(-22)?;
+    // 393: add [rb+9], #0, [63]
+    n.at(393, 4);
+    let a = n.load_relative(9)?;
+    let value = n.add(a, 0)?;
+    n.store(63, value)?;
+    // 397: eq [63], #21, [63]
+    n.at(397, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 21);
+    n.store(63, value)?;
+    // 401: jnz [63], #411
+    n.at(401, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(411);
+    }
+    n.goto(404);
+    Ok(())
+}
+
+fn block_404(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 404: add [64], #1, [64]
+    n.at(404, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 408: jz #0, #413
+    n.at(408, 3);
+    n.jump(413)
+}
+
+fn block_411(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 411: out [393]
+    n.at(411, 2);
+    let a = n.load(393)?;
+    n.output(a);
+    n.goto(413);
+    Ok(())
+}
+
+fn block_413(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 413: mul [64], #2, [64]
+    n.at(413, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 417: arb #9
+    n.at(417, 2);
+    n.shift(9)?;
+    // 419: lt [rb+0], #19, [63]
+    n.at(419, 4);
+    let a = n.load_relative(0)?;
+    let value = i64::from(a < 19);
+    n.store(63, value)?;
+    // 423: jnz [63], #433
+    n.at(423, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(433);
+    }
+    n.goto(426);
+    Ok(())
+}
+
+fn block_426(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 426: add [64], #1, [64]
+    n.at(426, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 430: jz #0, #435
+    n.at(430, 3);
+    n.jump(435)
+}
+
+fn block_433(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 433: out [419]
+    n.at(433, 2);
+    let a = n.load(419)?;
+    n.output(a);
+    n.goto(435);
+    Ok(())
+}
+
+fn block_435(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 435: mul [64], #2, [64]
+    n.at(435, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 439: arb #-9
+    n.at(439, 2);
+    n.shift(-9)?;
+    // 441: lt #30, [rb+8], [63]
+    n.at(441, 4);
+    let b = n.load_relative(8)?;
+    let value = i64::from(30 < b);
+    n.store(63, value)?;
+    // 445: jnz [63], #453
+    n.at(445, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(453);
+    }
+    n.goto(448);
+    Ok(())
+}
+
+fn block_448(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 448: out [441]
+    n.at(448, 2);
+    let a = n.load(441)?;
+    n.output(a);
+    // 450: jnz #1, #457
+    n.at(450, 3);
+    n.jump(457)
+}
+
+fn block_453(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 453: add [64], #1, [64]
+    n.at(453, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(457);
+    Ok(())
+}
+
+fn block_457(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 457: mul [64], #2, [64]
+    n.at(457, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 461: arb #25
+    n.at(461, 2);
+    n.shift(25)?;
+    n.goto(463);
+    Ok(())
+}
+
+fn block_463(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 463: jz #0, [rb+10]
+    n.at(463, 3);
+    let b = n.load_relative(10)?;
+    n.jump(b)
+}
+
+fn block_466(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 466: add [64], #1, [64]
+    n.at(466, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 470: jz #0, #475
+    n.at(470, 3);
+    n.jump(475)
+}
+
+fn block_473(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 473: out [463]
+    n.at(473, 2);
+    let a = n.load(463)?;
+    n.output(a);
+    n.goto(475);
+    Ok(())
+}
+
+fn block_475(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 475: mul [64], #2, [64]
+    n.at(475, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 479: arb #11
+    n.at(479, 2);
+    n.shift(11)?;
+    n.goto(481);
+    Ok(())
+}
+
+fn block_481(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 481: jz #0, [rb+0]
+    n.at(481, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
+
+fn block_484(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 484: out [481]
+    n.at(484, 2);
+    let a = n.load(481)?;
+    n.output(a);
+    // 486: add [64], #1, [64]
+    n.at(486, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 490: jnz #1, #493
+    n.at(490, 3);
+    n.jump(493)
+}
+
+fn block_493(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 493: mul [64], #2, [64]
+    n.at(493, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 497: arb #-18
+    n.at(497, 2);
+    n.shift(-18)?;
+    n.goto(499);
+    Ok(())
+}
+
+fn block_499(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 499: eq #21, [rb-6], [63]
+    n.at(499, 4);
+    let b = n.load_relative(-6)?;
+    let value = i64::from(21 == b);
+    n.store(63, value)?;
+    // 503: jnz [63], #511
+    n.at(503, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(511);
+    }
+    n.goto(506);
+    Ok(())
+}
+
+fn block_506(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 506: out [499]
+    n.at(506, 2);
+    let a = n.load(499)?;
+    n.output(a);
+    // 508: jz #0, #515
+    n.at(508, 3);
+    n.jump(515)
+}
+
+fn block_511(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 511: add [64], #1, [64]
+    n.at(511, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(515);
+    Ok(())
+}
+
+fn block_515(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 515: mul [64], #2, [64]
+    n.at(515, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 519: arb #-12
+    n.at(519, 2);
+    n.shift(-12)?;
+    n.goto(521);
+    Ok(())
+}
+
+fn block_521(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 521: eq #18, [rb+6], [63]
+    n.at(521, 4);
+    let b = n.load_relative(6)?;
+    let value = i64::from(18 == b);
+    n.store(63, value)?;
+    // 525: jnz [63], #535
+    n.at(525, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(535);
+    }
+    n.goto(528);
+    Ok(())
+}
+
+fn block_528(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 528: add [64], #1, [64]
+    n.at(528, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 532: jz #0, #537
+    n.at(532, 3);
+    n.jump(537)
+}
+
+fn block_535(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 535: out [521]
+    n.at(535, 2);
+    let a = n.load(521)?;
+    n.output(a);
+    n.goto(537);
+    Ok(())
+}
+
+fn block_537(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 537: mul [64], #2, [64]
+    n.at(537, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 541: arb #19
+    n.at(541, 2);
+    n.shift(19)?;
+    n.goto(543);
+    Ok(())
+}
+
+fn block_543(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 543: add #45, #0, [rb-7]
+    n.at(543, 4);
+    let value = n.add(45, 0)?;
+    n.store_relative(-7, value)?;
+    if !n.valid(78) {
+        n.goto(547);
+        return Ok(());
+    }
+    // 547: eq [1010], #45, [63]
+    n.at(547, 4);
+    let a = n.load(1010)?;
+    let value = i64::from(a == 45);
+    n.store(63, value)?;
+    // 551: jnz [63], #563
+    n.at(551, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(563);
+    }
+    n.goto(554);
+    Ok(())
+}
+
+fn block_554(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 554: out [543]
+    n.at(554, 2);
+    let a = n.load(543)?;
+    n.output(a);
+    // 556: add [64], #1, [64]
+    n.at(556, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 560: jnz #1, #563
+    n.at(560, 3);
+    n.jump(563)
+}
+
+fn block_563(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 563: mul [64], #2, [64]
+    n.at(563, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 567: arb #-10
+    n.at(567, 2);
+    n.shift(-10)?;
+    n.goto(569);
+    Ok(())
+}
+
+fn block_569(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 569: lt [rb-5], #31, [63]
+    n.at(569, 4);
+    let a = n.load_relative(-5)?;
+    let value = i64::from(a < 31);
+    n.store(63, value)?;
+    // 573: jnz [63], #581
+    n.at(573, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(581);
+    }
+    n.goto(576);
+    Ok(())
+}
+
+fn block_576(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 576: out [569]
+    n.at(576, 2);
+    let a = n.load(569)?;
+    n.output(a);
+    // 578: jz #0, #585
+    n.at(578, 3);
+    n.jump(585)
+}
+
+fn block_581(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 581: add [64], #1, [64]
+    n.at(581, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(585);
+    Ok(())
+}
+
+fn block_585(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 585: mul [64], #2, [64]
+    n.at(585, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 589: arb #-8
+    n.at(589, 2);
+    n.shift(-8)?;
+    n.goto(591);
+    Ok(())
+}
+
+fn block_591(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 591: mul #1, [rb+5], [63]
+    n.at(591, 4);
+    let b = n.load_relative(5)?;
+    let value = n.multiply(1, b)?;
+    n.store(63, value)?;
+    // 595: eq [63], #21, [63]
+    n.at(595, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 21);
+    n.store(63, value)?;
+    // 599: jnz [63], #611
+    n.at(599, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(611);
+    }
+    n.goto(602);
+    Ok(())
+}
+
+fn block_602(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 602: out [591]
+    n.at(602, 2);
+    let a = n.load(591)?;
+    n.output(a);
+    // 604: add [64], #1, [64]
+    n.at(604, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 608: jnz #1, #611
+    n.at(608, 3);
+    n.jump(611)
+}
+
+fn block_611(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 611: mul [64], #2, [64]
+    n.at(611, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 615: arb #5
+    n.at(615, 2);
+    n.shift(5)?;
+    n.goto(617);
+    Ok(())
+}
+
+fn block_617(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 617: add [rb+0], #0, [63]
+    n.at(617, 4);
+    let a = n.load_relative(0)?;
+    let value = n.add(a, 0)?;
+    n.store(63, value)?;
+    // 621: eq [63], #21, [63]
+    n.at(621, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 21);
+    n.store(63, value)?;
+    // 625: jnz [63], #633
+    n.at(625, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(633);
+    }
+    n.goto(628);
+    Ok(())
+}
+
+fn block_628(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 628: out [617]
+    n.at(628, 2);
+    let a = n.load(617)?;
+    n.output(a);
+    // 630: jz #0, #637
+    n.at(630, 3);
+    n.jump(637)
+}
+
+fn block_633(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 633: add [64], #1, [64]
+    n.at(633, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(637);
+    Ok(())
+}
+
+fn block_637(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 637: mul [64], #2, [64]
+    n.at(637, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 641: arb #13
+    n.at(641, 2);
+    n.shift(13)?;
+    n.goto(643);
+    Ok(())
+}
+
+fn block_643(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 643: jnz #1, [rb+6]
+    n.at(643, 3);
+    let b = n.load_relative(6)?;
+    n.jump(b)
+}
+
+fn block_646(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 646: add [64], #1, [64]
+    n.at(646, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 650: jz #0, #655
+    n.at(650, 3);
+    n.jump(655)
+}
+
+fn block_653(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 653: out [643]
+    n.at(653, 2);
+    let a = n.load(643)?;
+    n.output(a);
+    n.goto(655);
+    Ok(())
+}
+
+fn block_655(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 655: mul [64], #2, [64]
+    n.at(655, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 659: arb #-7
+    n.at(659, 2);
+    n.shift(-7)?;
+    n.goto(661);
+    Ok(())
+}
+
+fn block_661(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 661: mul [rb-3], #1, [63]
+    n.at(661, 4);
+    let a = n.load_relative(-3)?;
+    let value = n.multiply(a, 1)?;
+    n.store(63, value)?;
+    // 665: eq [63], #26, [63]
+    n.at(665, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 26);
+    n.store(63, value)?;
+    // 669: jnz [63], #681
+    n.at(669, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(681);
+    }
+    n.goto(672);
+    Ok(())
+}
+
+fn block_672(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 672: out [661]
+    n.at(672, 2);
+    let a = n.load(661)?;
+    n.output(a);
+    // 674: add [64], #1, [64]
+    n.at(674, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 678: jz #0, #681
+    n.at(678, 3);
+    n.jump(681)
+}
+
+fn block_681(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 681: mul [64], #2, [64]
+    n.at(681, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 685: arb #12
+    n.at(685, 2);
+    n.shift(12)?;
+    n.goto(687);
+    Ok(())
+}
+
+fn block_687(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 687: jnz #1, [rb+2]
+    n.at(687, 3);
+    let b = n.load_relative(2)?;
+    n.jump(b)
+}
+
+fn block_690(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 690: out [687]
+    n.at(690, 2);
+    let a = n.load(687)?;
+    n.output(a);
+    // 692: add [64], #1, [64]
+    n.at(692, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 696: jnz #1, #699
+    n.at(696, 3);
+    n.jump(699)
+}
+
+fn block_699(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 699: mul [64], #2, [64]
+    n.at(699, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 703: arb #-28
+    n.at(703, 2);
+    n.shift(-28)?;
+    n.goto(705);
+    Ok(())
+}
+
+fn block_705(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 705: eq [rb+8], #30, [63]
+    n.at(705, 4);
+    let a = n.load_relative(8)?;
+    let value = i64::from(a == 30);
+    n.store(63, value)?;
+    // 709: jnz [63], #717
+    n.at(709, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(717);
+    }
+    n.goto(712);
+    Ok(())
+}
+
+fn block_712(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 712: out [705]
+    n.at(712, 2);
+    let a = n.load(705)?;
+    n.output(a);
+    // 714: jz #0, #721
+    n.at(714, 3);
+    n.jump(721)
+}
+
+fn block_717(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 717: add [64], #1, [64]
+    n.at(717, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(721);
+    Ok(())
+}
+
+fn block_721(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 721: mul [64], #2, [64]
+    n.at(721, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 725: arb #10
+    n.at(725, 2);
+    n.shift(10)?;
+    n.goto(727);
+    Ok(())
+}
+
+fn block_727(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 727: mul [rb+1], #1, [63]
+    n.at(727, 4);
+    let a = n.load_relative(1)?;
+    let value = n.multiply(a, 1)?;
+    n.store(63, value)?;
+    // 731: eq [63], #40, [63]
+    n.at(731, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 40);
+    n.store(63, value)?;
+    // 735: jnz [63], #745
+    n.at(735, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(745);
+    }
+    n.goto(738);
+    Ok(())
+}
+
+fn block_738(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 738: add [64], #1, [64]
+    n.at(738, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 742: jnz #1, #747
+    n.at(742, 3);
+    n.jump(747)
+}
+
+fn block_745(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 745: out [727]
+    n.at(745, 2);
+    let a = n.load(727)?;
+    n.output(a);
+    n.goto(747);
+    Ok(())
+}
+
+fn block_747(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 747: mul [64], #2, [64]
+    n.at(747, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 751: arb #10
+    n.at(751, 2);
+    n.shift(10)?;
+    n.goto(753);
+    Ok(())
+}
+
+fn block_753(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 753: eq #46, #46, [rb-2]
+    n.at(753, 4);
+    let value = 1;
+    n.store_relative(-2, value)?;
+    if !n.valid(110) {
+        n.goto(757);
+        return Ok(());
+    }
+    // 757: jnz [1012], #765
+    n.at(757, 3);
+    let a = n.load(1012)?;
+    if 0 != a {
+        return n.jump(765);
+    }
+    n.goto(760);
+    Ok(())
+}
+
+fn block_760(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 760: out [753]
+    n.at(760, 2);
+    let a = n.load(753)?;
+    n.output(a);
+    // 762: jnz #1, #769
+    n.at(762, 3);
+    n.jump(769)
+}
+
+fn block_765(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 765: add [64], #1, [64]
+    n.at(765, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(769);
+    Ok(())
+}
+
+fn block_769(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 769: mul [64], #2, [64]
+    n.at(769, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 773: arb #-2
+    n.at(773, 2);
+    n.shift(-2)?;
+    n.goto(775);
+    Ok(())
+}
+
+fn block_775(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 775: jnz [rb+8], #781
+    n.at(775, 3);
+    let a = n.load_relative(8)?;
+    if 0 != a {
+        return n.jump(781);
+    }
+    n.goto(778);
+    Ok(())
+}
+
+fn block_778(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 778: jz #0, #787
+    n.at(778, 3);
+    n.jump(787)
+}
+
+fn block_781(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 781: out [775]
+    n.at(781, 2);
+    let a = n.load(775)?;
+    n.output(a);
+    // 783: add [64], #1, [64]
+    n.at(783, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(787);
+    Ok(())
+}
+
+fn block_787(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 787: mul [64], #2, [64]
+    n.at(787, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 791: arb #-9
+    n.at(791, 2);
+    n.shift(-9)?;
+    n.goto(793);
+    Ok(())
+}
+
+fn block_793(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 793: add #0, [rb+0], [63]
+    n.at(793, 4);
+    let b = n.load_relative(0)?;
+    let value = n.add(0, b)?;
+    n.store(63, value)?;
+    // 797: eq [63], #23, [63]
+    n.at(797, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 23);
+    n.store(63, value)?;
+    // 801: jnz [63], #809
+    n.at(801, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(809);
+    }
+    n.goto(804);
+    Ok(())
+}
+
+fn block_804(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 804: out [793]
+    n.at(804, 2);
+    let a = n.load(793)?;
+    n.output(a);
+    // 806: jnz #1, #813
+    n.at(806, 3);
+    n.jump(813)
+}
+
+fn block_809(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 809: add [64], #1, [64]
+    n.at(809, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(813);
+    Ok(())
+}
+
+fn block_813(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 813: mul [64], #2, [64]
+    n.at(813, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 817: arb #9
+    n.at(817, 2);
+    n.shift(9)?;
+    n.goto(819);
+    Ok(())
+}
+
+fn block_819(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 819: jz [rb+8], #831
+    n.at(819, 3);
+    let a = n.load_relative(8)?;
+    if 0 == a {
+        return n.jump(831);
+    }
+    n.goto(822);
+    Ok(())
+}
+
+fn block_822(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 822: out [819]
+    n.at(822, 2);
+    let a = n.load(819)?;
+    n.output(a);
+    // 824: add [64], #1, [64]
+    n.at(824, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 828: jz #0, #831
+    n.at(828, 3);
+    n.jump(831)
+}
+
+fn block_831(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 831: mul [64], #2, [64]
+    n.at(831, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 835: arb #-9
+    n.at(835, 2);
+    n.shift(-9)?;
+    n.goto(837);
+    Ok(())
+}
+
+fn block_837(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 837: mul #1, [rb-2], [63]
+    n.at(837, 4);
+    let b = n.load_relative(-2)?;
+    let value = n.multiply(1, b)?;
+    n.store(63, value)?;
+    // 841: eq [63], #22, [63]
+    n.at(841, 4);
+    let a = n.load(63)?;
+    let value = i64::from(a == 22);
+    n.store(63, value)?;
+    // 845: jnz [63], #855
+    n.at(845, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(855);
+    }
+    n.goto(848);
+    Ok(())
+}
+
+fn block_848(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 848: add [64], #1, [64]
+    n.at(848, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    // 852: jz #0, #857
+    n.at(852, 3);
+    n.jump(857)
+}
+
+fn block_855(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 855: out [837]
+    n.at(855, 2);
+    let a = n.load(837)?;
+    n.output(a);
+    n.goto(857);
+    Ok(())
+}
+
+fn block_857(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 857: mul [64], #2, [64]
+    n.at(857, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 861: arb #4
+    n.at(861, 2);
+    n.shift(4)?;
+    n.goto(863);
+    Ok(())
+}
+
+fn block_863(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 863: add #47, #0, [rb+10]
+    n.at(863, 4);
+    let value = n.add(47, 0)?;
+    n.store_relative(10, value)?;
+    if !n.valid(129) {
+        n.goto(867);
+        return Ok(());
+    }
+    // 867: eq [1017], #50, [63]
+    n.at(867, 4);
+    let a = n.load(1017)?;
+    let value = i64::from(a == 50);
+    n.store(63, value)?;
+    // 871: jnz [63], #877
+    n.at(871, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(877);
+    }
+    n.goto(874);
+    Ok(())
+}
+
+fn block_874(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 874: jnz #1, #883
+    n.at(874, 3);
+    n.jump(883)
+}
+
+fn block_877(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 877: out [863]
+    n.at(877, 2);
+    let a = n.load(863)?;
+    n.output(a);
+    // 879: add [64], #1, [64]
+    n.at(879, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(883);
+    Ok(())
+}
+
+fn block_883(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 883: mul [64], #2, [64]
+    n.at(883, 4);
+    let a = n.load(64)?;
+    let value = n.multiply(a, 2)?;
+    n.store(64, value)?;
+    // 887: arb #18
+    n.at(887, 2);
+    n.shift(18)?;
+    n.goto(889);
+    Ok(())
+}
+
+fn block_889(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 889: jz [rb-4], #895
+    n.at(889, 3);
+    let a = n.load_relative(-4)?;
+    if 0 == a {
+        return n.jump(895);
+    }
+    n.goto(892);
+    Ok(())
+}
+
+fn block_892(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 892: jnz #1, #901
+    n.at(892, 3);
+    n.jump(901)
+}
+
+fn block_895(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 895: out [889]
+    n.at(895, 2);
+    let a = n.load(889)?;
+    n.output(a);
+    // 897: add [64], #1, [64]
+    n.at(897, 4);
+    let a = n.load(64)?;
+    let value = n.add(a, 1)?;
+    n.store(64, value)?;
+    n.goto(901);
+    Ok(())
+}
+
+fn block_901(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 901: out [64]
+    n.at(901, 2);
+    let a = n.load(64)?;
+    n.output(a);
+    // 903: hlt
+    n.at(903, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_904(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 904: add #0, #27, [rb+1]
+    n.at(904, 4);
+    let value = n.add(0, 27)?;
+    n.store_relative(1, value)?;
+    if !n.valid(137) {
+        n.goto(908);
+        return Ok(());
+    }
+    // 908: mul #915, #1, [rb+0]
+    n.at(908, 4);
+    let value = n.multiply(915, 1)?;
+    n.store_relative(0, value)?;
+    if !n.valid(137) {
+        n.goto(912);
+        return Ok(());
+    }
+    // 912: jz #0, #922
+    n.at(912, 3);
+    n.jump(922)
+}
+
+fn block_915(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 915: add [rb+1], #56639, [rb+1]
+    n.at(915, 4);
+    let a = n.load_relative(1)?;
+    let value = n.add(a, 56639)?;
+    n.store_relative(1, value)?;
+    if !n.valid(138) {
+        n.goto(919);
+        return Ok(());
+    }
+    // 919: out [rb+1]
+    n.at(919, 2);
+    let a = n.load_relative(1)?;
+    n.output(a);
+    // 921: hlt
+    n.at(921, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_922(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 922: arb #3
+    n.at(922, 2);
+    n.shift(3)?;
+    // 924: lt [rb-2], #3, [63]
+    n.at(924, 4);
+    let a = n.load_relative(-2)?;
+    let value = i64::from(a < 3);
+    n.store(63, value)?;
+    // 928: jnz [63], #964
+    n.at(928, 3);
+    let a = n.load(63)?;
+    if 0 != a {
+        return n.jump(964);
+    }
+    n.goto(931);
+    Ok(())
+}
+
+fn block_931(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 931: add [rb-2], #-1, [rb+1]
+    n.at(931, 4);
+    let a = n.load_relative(-2)?;
+    let value = n.add(a, -1)?;
+    n.store_relative(1, value)?;
+    if !n.valid(140) {
+        n.goto(935);
+        return Ok(());
+    }
+    // 935: mul #1, #942, [rb+0]
+    n.at(935, 4);
+    let value = n.multiply(1, 942)?;
+    n.store_relative(0, value)?;
+    if !n.valid(140) {
+        n.goto(939);
+        return Ok(());
+    }
+    // 939: jz #0, #922
+    n.at(939, 3);
+    n.jump(922)
+}
+
+fn block_942(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 942: mul #1, [rb+1], [rb-1]
+    n.at(942, 4);
+    let b = n.load_relative(1)?;
+    let value = n.multiply(1, b)?;
+    n.store_relative(-1, value)?;
+    if !n.valid(141) {
+        n.goto(946);
+        return Ok(());
+    }
+    // 946: add [rb-2], #-3, [rb+1]
+    n.at(946, 4);
+    let a = n.load_relative(-2)?;
+    let value = n.add(a, -3)?;
+    n.store_relative(1, value)?;
+    if !n.valid(141) {
+        n.goto(950);
+        return Ok(());
+    }
+    // 950: add #0, #957, [rb+0]
+    n.at(950, 4);
+    let value = n.add(0, 957)?;
+    n.store_relative(0, value)?;
+    if !n.valid(141) {
+        n.goto(954);
+        return Ok(());
+    }
+    // 954: jz #0, #922
+    n.at(954, 3);
+    n.jump(922)
+}
+
+fn block_957(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 957: add [rb+1], [rb-1], [rb-2]
+    n.at(957, 4);
+    let a = n.load_relative(1)?;
+    let b = n.load_relative(-1)?;
+    let value = n.add(a, b)?;
+    n.store_relative(-2, value)?;
+    if !n.valid(142) {
+        n.goto(961);
+        return Ok(());
+    }
+    // 961: jz #0, #968
+    n.at(961, 3);
+    n.jump(968)
+}
+
+fn block_964(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 964: mul #1, [rb-2], [rb-2]
+    n.at(964, 4);
+    let b = n.load_relative(-2)?;
+    let value = n.multiply(1, b)?;
+    n.store_relative(-2, value)?;
+    n.goto(968);
+    Ok(())
+}
+
+fn block_968(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 968: arb #-3
+    n.at(968, 2);
+    n.shift(-3)?;
+    // 970: jz #0, [rb+0]
+    n.at(970, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
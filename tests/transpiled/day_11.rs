@@ -0,0 +1,1528 @@
+// Generated by intcode_transpile from a 642 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 642] = [
+    3, 8, 1005, 8, 320, 1106, 0, 11, 0, 0, 0, 104, 1, 104, 0, 3, 8, 102, -1, 8, 10, 101, 1, 10, 10,
+    4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 29, 2, 101, 10, 10, 3, 8, 102, -1, 8, 10, 1001, 10,
+    1, 10, 4, 10, 108, 1, 8, 10, 4, 10, 101, 0, 8, 54, 2, 3, 16, 10, 3, 8, 1002, 8, -1, 10, 101, 1,
+    10, 10, 4, 10, 1008, 8, 0, 10, 4, 10, 102, 1, 8, 81, 1006, 0, 75, 3, 8, 1002, 8, -1, 10, 1001,
+    10, 1, 10, 4, 10, 108, 0, 8, 10, 4, 10, 101, 0, 8, 105, 3, 8, 102, -1, 8, 10, 1001, 10, 1, 10,
+    4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 128, 3, 8, 1002, 8, -1, 10, 1001, 10, 1, 10, 4, 10,
+    108, 0, 8, 10, 4, 10, 102, 1, 8, 149, 1, 105, 5, 10, 1, 105, 20, 10, 3, 8, 102, -1, 8, 10, 101,
+    1, 10, 10, 4, 10, 108, 0, 8, 10, 4, 10, 1002, 8, 1, 179, 1, 101, 1, 10, 2, 109, 8, 10, 1006, 0,
+    74, 3, 8, 1002, 8, -1, 10, 101, 1, 10, 10, 4, 10, 1008, 8, 1, 10, 4, 10, 1001, 8, 0, 213, 1006,
+    0, 60, 2, 1105, 9, 10, 1, 1005, 11, 10, 3, 8, 1002, 8, -1, 10, 101, 1, 10, 10, 4, 10, 108, 1,
+    8, 10, 4, 10, 1002, 8, 1, 245, 1, 6, 20, 10, 1, 1103, 11, 10, 2, 6, 11, 10, 2, 1103, 0, 10, 3,
+    8, 1002, 8, -1, 10, 101, 1, 10, 10, 4, 10, 1008, 8, 0, 10, 4, 10, 1002, 8, 1, 284, 2, 1103, 12,
+    10, 2, 1104, 14, 10, 2, 1004, 12, 10, 2, 1009, 4, 10, 101, 1, 9, 9, 1007, 9, 968, 10, 1005, 10,
+    15, 99, 109, 642, 104, 0, 104, 1, 21102, 1, 48063419288, 1, 21102, 1, 337, 0, 1105, 1, 441,
+    21101, 0, 846927340300, 1, 21101, 0, 348, 0, 1105, 1, 441, 3, 10, 104, 0, 104, 1, 3, 10, 104,
+    0, 104, 0, 3, 10, 104, 0, 104, 1, 3, 10, 104, 0, 104, 1, 3, 10, 104, 0, 104, 0, 3, 10, 104, 0,
+    104, 1, 21102, 1, 235245104151, 1, 21102, 395, 1, 0, 1105, 1, 441, 21102, 29032123584, 1, 1,
+    21101, 0, 406, 0, 1105, 1, 441, 3, 10, 104, 0, 104, 0, 3, 10, 104, 0, 104, 0, 21101, 0,
+    709047878500, 1, 21101, 429, 0, 0, 1106, 0, 441, 21101, 868402070284, 0, 1, 21102, 1, 440, 0,
+    1105, 1, 441, 99, 109, 2, 22102, 1, -1, 1, 21101, 40, 0, 2, 21101, 0, 472, 3, 21102, 462, 1, 0,
+    1105, 1, 505, 109, -2, 2106, 0, 0, 0, 1, 0, 0, 1, 109, 2, 3, 10, 204, -1, 1001, 467, 468, 483,
+    4, 0, 1001, 467, 1, 467, 108, 4, 467, 10, 1006, 10, 499, 1102, 1, 0, 467, 109, -2, 2106, 0, 0,
+    0, 109, 4, 2101, 0, -1, 504, 1207, -3, 0, 10, 1006, 10, 522, 21101, 0, 0, -3, 22101, 0, -3, 1,
+    21202, -2, 1, 2, 21101, 1, 0, 3, 21102, 541, 1, 0, 1106, 0, 546, 109, -4, 2106, 0, 0, 109, 5,
+    1207, -3, 1, 10, 1006, 10, 569, 2207, -4, -2, 10, 1006, 10, 569, 21202, -4, 1, -4, 1105, 1,
+    637, 22102, 1, -4, 1, 21201, -3, -1, 2, 21202, -2, 2, 3, 21101, 588, 0, 0, 1105, 1, 546, 22102,
+    1, 1, -4, 21101, 0, 1, -1, 2207, -4, -2, 10, 1006, 10, 607, 21101, 0, 0, -1, 22202, -2, -1, -2,
+    2107, 0, -3, 10, 1006, 10, 629, 21201, -1, 0, 1, 21102, 629, 1, 0, 106, 0, 504, 21202, -2, -1,
+    -2, 22201, -4, -2, -4, 109, -5, 2105, 1, 0,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 56] = [
+    (0, 2), (1, 5), (2, 5), (3, 7), (5, 8), (11, 15), (15, 41), (41, 67), (67, 92), (74, 78),
+    (92, 104), (104, 108), (106, 114), (108, 114), (114, 136), (136, 166), (166, 199), (199, 224),
+    (224, 232), (232, 270), (270, 319), (319, 320), (320, 337), (337, 348), (348, 354), (354, 360),
+    (360, 366), (366, 372), (372, 378), (378, 395), (395, 406), (406, 412), (412, 429), (429, 440),
+    (440, 441), (441, 462), (462, 467), (468, 472), (472, 474), (474, 495), (495, 499), (499, 504),
+    (505, 518), (518, 522), (522, 541), (541, 546), (546, 555), (555, 562), (562, 569), (569, 588),
+    (588, 603), (603, 607), (607, 618), (618, 629), (629, 637), (637, 642),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct Day11 {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl Day11 {
+    pub fn new() -> Day11 {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        Day11 { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                1 if n.valid(1) => block_1(&mut n),
+                2 if n.valid(2) => block_2(&mut n),
+                3 if n.valid(3) => block_3(&mut n),
+                5 if n.valid(4) => block_5(&mut n),
+                11 if n.valid(5) => block_11(&mut n),
+                15 if n.valid(6) => block_15(&mut n),
+                41 if n.valid(7) => block_41(&mut n),
+                67 if n.valid(8) => block_67(&mut n),
+                74 if n.valid(9) => block_74(&mut n),
+                92 if n.valid(10) => block_92(&mut n),
+                104 if n.valid(11) => block_104(&mut n),
+                106 if n.valid(12) => block_106(&mut n),
+                108 if n.valid(13) => block_108(&mut n),
+                114 if n.valid(14) => block_114(&mut n),
+                136 if n.valid(15) => block_136(&mut n),
+                166 if n.valid(16) => block_166(&mut n),
+                199 if n.valid(17) => block_199(&mut n),
+                224 if n.valid(18) => block_224(&mut n),
+                232 if n.valid(19) => block_232(&mut n),
+                270 if n.valid(20) => block_270(&mut n),
+                319 if n.valid(21) => block_319(&mut n),
+                320 if n.valid(22) => block_320(&mut n),
+                337 if n.valid(23) => block_337(&mut n),
+                348 if n.valid(24) => block_348(&mut n),
+                354 if n.valid(25) => block_354(&mut n),
+                360 if n.valid(26) => block_360(&mut n),
+                366 if n.valid(27) => block_366(&mut n),
+                372 if n.valid(28) => block_372(&mut n),
+                378 if n.valid(29) => block_378(&mut n),
+                395 if n.valid(30) => block_395(&mut n),
+                406 if n.valid(31) => block_406(&mut n),
+                412 if n.valid(32) => block_412(&mut n),
+                429 if n.valid(33) => block_429(&mut n),
+                440 if n.valid(34) => block_440(&mut n),
+                441 if n.valid(35) => block_441(&mut n),
+                462 if n.valid(36) => block_462(&mut n),
+                468 if n.valid(37) => block_468(&mut n),
+                472 if n.valid(38) => block_472(&mut n),
+                474 if n.valid(39) => block_474(&mut n),
+                495 if n.valid(40) => block_495(&mut n),
+                499 if n.valid(41) => block_499(&mut n),
+                505 if n.valid(42) => block_505(&mut n),
+                518 if n.valid(43) => block_518(&mut n),
+                522 if n.valid(44) => block_522(&mut n),
+                541 if n.valid(45) => block_541(&mut n),
+                546 if n.valid(46) => block_546(&mut n),
+                555 if n.valid(47) => block_555(&mut n),
+                562 if n.valid(48) => block_562(&mut n),
+                569 if n.valid(49) => block_569(&mut n),
+                588 if n.valid(50) => block_588(&mut n),
+                603 if n.valid(51) => block_603(&mut n),
+                607 if n.valid(52) => block_607(&mut n),
+                618 if n.valid(53) => block_618(&mut n),
+                629 if n.valid(54) => block_629(&mut n),
+                637 if n.valid(55) => block_637(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for Day11 {
+    fn default() -> Day11 {
+        Day11::new()
+    }
+}
+
+impl Deref for Day11 {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for Day11 {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: in [8]
+    n.at(0, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    n.goto(2);
+    Ok(())
+}
+
+fn block_1(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 1: eq [1005], [8], [320]
+    n.at(1, 4);
+    let a = n.load(1005)?;
+    let b = n.load(8)?;
+    let value = i64::from(a == b);
+    n.store(320, value)?;
+    n.goto(5);
+    Ok(())
+}
+
+fn block_2(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 2: jnz [8], #320
+    n.at(2, 3);
+    let a = n.load(8)?;
+    if 0 != a {
+        return n.jump(320);
+    }
+    n.goto(5);
+    Ok(())
+}
+
+fn block_3(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 3: eq [320], [1106], [0]
+    n.at(3, 4);
+    let a = n.load(320)?;
+    let b = n.load(1106)?;
+    let value = i64::from(a == b);
+    n.store(0, value)?;
+    n.goto(7);
+    Ok(())
+}
+
+fn block_5(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 5: jz #0, #11
+    n.at(5, 3);
+    n.jump(11)
+}
+
+fn block_11(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 11: out #1
+    n.at(11, 2);
+    n.output(1);
+    // 13: out #0
+    n.at(13, 2);
+    n.output(0);
+    n.goto(15);
+    Ok(())
+}
+
+fn block_15(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 15: in [8]
+    n.at(15, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 17: mul #-1, [8], [10]
+    n.at(17, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(-1, b)?;
+    n.store(10, value)?;
+    // 21: add #1, [10], [10]
+    n.at(21, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 25: out [10]
+    n.at(25, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 27: eq [8], #1, [10]
+    n.at(27, 4);
+    let a = n.load(8)?;
+    let value = i64::from(a == 1);
+    n.store(10, value)?;
+    // 31: out [10]
+    n.at(31, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 33: add [8], #0, [29]
+    n.at(33, 4);
+    let a = n.load(8)?;
+    let value = n.add(a, 0)?;
+    n.store(29, value)?;
+    // 37: mul [101], [10], [10]
+    n.at(37, 4);
+    let a = n.load(101)?;
+    let b = n.load(10)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    n.goto(41);
+    Ok(())
+}
+
+fn block_41(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 41: in [8]
+    n.at(41, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 43: mul #-1, [8], [10]
+    n.at(43, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(-1, b)?;
+    n.store(10, value)?;
+    // 47: add [10], #1, [10]
+    n.at(47, 4);
+    let a = n.load(10)?;
+    let value = n.add(a, 1)?;
+    n.store(10, value)?;
+    // 51: out [10]
+    n.at(51, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 53: eq #1, [8], [10]
+    n.at(53, 4);
+    let b = n.load(8)?;
+    let value = i64::from(1 == b);
+    n.store(10, value)?;
+    // 57: out [10]
+    n.at(57, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 59: add #0, [8], [54]
+    n.at(59, 4);
+    let b = n.load(8)?;
+    let value = n.add(0, b)?;
+    n.store(54, value)?;
+    // 63: mul [3], [16], [10]
+    n.at(63, 4);
+    let a = n.load(3)?;
+    let b = n.load(16)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    n.goto(67);
+    Ok(())
+}
+
+fn block_67(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 67: in [8]
+    n.at(67, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 69: mul [8], #-1, [10]
+    n.at(69, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 73: add #1, [10], [10]
+    n.at(73, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 77: out [10]
+    n.at(77, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 79: eq [8], #0, [10]
+    n.at(79, 4);
+    let a = n.load(8)?;
+    let value = i64::from(a == 0);
+    n.store(10, value)?;
+    // 83: out [10]
+    n.at(83, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 85: mul #1, [8], [81]
+    n.at(85, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(1, b)?;
+    n.store(81, value)?;
+    // 89: jz [0], #75
+    n.at(89, 3);
+    let a = n.load(0)?;
+    if 0 == a {
+        return n.jump(75);
+    }
+    n.goto(92);
+    Ok(())
+}
+
+fn block_74(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 74: add [10], [10], [4]
+    n.at(74, 4);
+    let a = n.load(10)?;
+    let b = n.load(10)?;
+    let value = n.add(a, b)?;
+    n.store(4, value)?;
+    n.goto(78);
+    Ok(())
+}
+
+fn block_92(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 92: in [8]
+    n.at(92, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 94: mul [8], #-1, [10]
+    n.at(94, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 98: add [10], #1, [10]
+    n.at(98, 4);
+    let a = n.load(10)?;
+    let value = n.add(a, 1)?;
+    n.store(10, value)?;
+    // 102: out [10]
+    n.at(102, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    n.goto(104);
+    Ok(())
+}
+
+fn block_104(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 104: eq #0, [8], [10]
+    n.at(104, 4);
+    let b = n.load(8)?;
+    let value = i64::from(0 == b);
+    n.store(10, value)?;
+    n.goto(108);
+    Ok(())
+}
+
+fn block_106(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 106: eq [10], [4], [10]
+    n.at(106, 4);
+    let a = n.load(10)?;
+    let b = n.load(4)?;
+    let value = i64::from(a == b);
+    n.store(10, value)?;
+    // 110: add #0, [8], [105]
+    n.at(110, 4);
+    let b = n.load(8)?;
+    let value = n.add(0, b)?;
+    n.store(105, value)?;
+    n.goto(114);
+    Ok(())
+}
+
+fn block_108(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 108: out [10]
+    n.at(108, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 110: add #0, [8], [105]
+    n.at(110, 4);
+    let b = n.load(8)?;
+    let value = n.add(0, b)?;
+    n.store(105, value)?;
+    n.goto(114);
+    Ok(())
+}
+
+fn block_114(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 114: in [8]
+    n.at(114, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 116: mul #-1, [8], [10]
+    n.at(116, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(-1, b)?;
+    n.store(10, value)?;
+    // 120: add [10], #1, [10]
+    n.at(120, 4);
+    let a = n.load(10)?;
+    let value = n.add(a, 1)?;
+    n.store(10, value)?;
+    // 124: out [10]
+    n.at(124, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 126: eq [8], #1, [10]
+    n.at(126, 4);
+    let a = n.load(8)?;
+    let value = i64::from(a == 1);
+    n.store(10, value)?;
+    // 130: out [10]
+    n.at(130, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 132: add [8], #0, [128]
+    n.at(132, 4);
+    let a = n.load(8)?;
+    let value = n.add(a, 0)?;
+    n.store(128, value)?;
+    n.goto(136);
+    Ok(())
+}
+
+fn block_136(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 136: in [8]
+    n.at(136, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 138: mul [8], #-1, [10]
+    n.at(138, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 142: add [10], #1, [10]
+    n.at(142, 4);
+    let a = n.load(10)?;
+    let value = n.add(a, 1)?;
+    n.store(10, value)?;
+    // 146: out [10]
+    n.at(146, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 148: eq #0, [8], [10]
+    n.at(148, 4);
+    let b = n.load(8)?;
+    let value = i64::from(0 == b);
+    n.store(10, value)?;
+    // 152: out [10]
+    n.at(152, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 154: mul #1, [8], [149]
+    n.at(154, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(1, b)?;
+    n.store(149, value)?;
+    // 158: add [105], [5], [10]
+    n.at(158, 4);
+    let a = n.load(105)?;
+    let b = n.load(5)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    // 162: add [105], [20], [10]
+    n.at(162, 4);
+    let a = n.load(105)?;
+    let b = n.load(20)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    n.goto(166);
+    Ok(())
+}
+
+fn block_166(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 166: in [8]
+    n.at(166, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 168: mul #-1, [8], [10]
+    n.at(168, 4);
+    let b = n.load(8)?;
+    let value = n.multiply(-1, b)?;
+    n.store(10, value)?;
+    // 172: add #1, [10], [10]
+    n.at(172, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 176: out [10]
+    n.at(176, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 178: eq #0, [8], [10]
+    n.at(178, 4);
+    let b = n.load(8)?;
+    let value = i64::from(0 == b);
+    n.store(10, value)?;
+    // 182: out [10]
+    n.at(182, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 184: mul [8], #1, [179]
+    n.at(184, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, 1)?;
+    n.store(179, value)?;
+    // 188: add [101], [1], [10]
+    n.at(188, 4);
+    let a = n.load(101)?;
+    let b = n.load(1)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    // 192: mul [109], [8], [10]
+    n.at(192, 4);
+    let a = n.load(109)?;
+    let b = n.load(8)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 196: jz [0], #74
+    n.at(196, 3);
+    let a = n.load(0)?;
+    if 0 == a {
+        return n.jump(74);
+    }
+    n.goto(199);
+    Ok(())
+}
+
+fn block_199(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 199: in [8]
+    n.at(199, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 201: mul [8], #-1, [10]
+    n.at(201, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 205: add #1, [10], [10]
+    n.at(205, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 209: out [10]
+    n.at(209, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 211: eq [8], #1, [10]
+    n.at(211, 4);
+    let a = n.load(8)?;
+    let value = i64::from(a == 1);
+    n.store(10, value)?;
+    // 215: out [10]
+    n.at(215, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 217: add [8], #0, [213]
+    n.at(217, 4);
+    let a = n.load(8)?;
+    let value = n.add(a, 0)?;
+    n.store(213, value)?;
+    // 221: jz [0], #60
+    n.at(221, 3);
+    let a = n.load(0)?;
+    if 0 == a {
+        return n.jump(60);
+    }
+    n.goto(224);
+    Ok(())
+}
+
+fn block_224(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 224: mul [1105], [9], [10]
+    n.at(224, 4);
+    let a = n.load(1105)?;
+    let b = n.load(9)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 228: add [1005], [11], [10]
+    n.at(228, 4);
+    let a = n.load(1005)?;
+    let b = n.load(11)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    n.goto(232);
+    Ok(())
+}
+
+fn block_232(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 232: in [8]
+    n.at(232, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 234: mul [8], #-1, [10]
+    n.at(234, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 238: add #1, [10], [10]
+    n.at(238, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 242: out [10]
+    n.at(242, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 244: eq #1, [8], [10]
+    n.at(244, 4);
+    let b = n.load(8)?;
+    let value = i64::from(1 == b);
+    n.store(10, value)?;
+    // 248: out [10]
+    n.at(248, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 250: mul [8], #1, [245]
+    n.at(250, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, 1)?;
+    n.store(245, value)?;
+    // 254: add [6], [20], [10]
+    n.at(254, 4);
+    let a = n.load(6)?;
+    let b = n.load(20)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    // 258: add [1103], [11], [10]
+    n.at(258, 4);
+    let a = n.load(1103)?;
+    let b = n.load(11)?;
+    let value = n.add(a, b)?;
+    n.store(10, value)?;
+    // 262: mul [6], [11], [10]
+    n.at(262, 4);
+    let a = n.load(6)?;
+    let b = n.load(11)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 266: mul [1103], [0], [10]
+    n.at(266, 4);
+    let a = n.load(1103)?;
+    let b = n.load(0)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    n.goto(270);
+    Ok(())
+}
+
+fn block_270(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 270: in [8]
+    n.at(270, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(8, value)?;
+    n.consume_input();
+    // 272: mul [8], #-1, [10]
+    n.at(272, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, -1)?;
+    n.store(10, value)?;
+    // 276: add #1, [10], [10]
+    n.at(276, 4);
+    let b = n.load(10)?;
+    let value = n.add(1, b)?;
+    n.store(10, value)?;
+    // 280: out [10]
+    n.at(280, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 282: eq [8], #0, [10]
+    n.at(282, 4);
+    let a = n.load(8)?;
+    let value = i64::from(a == 0);
+    n.store(10, value)?;
+    // 286: out [10]
+    n.at(286, 2);
+    let a = n.load(10)?;
+    n.output(a);
+    // 288: mul [8], #1, [284]
+    n.at(288, 4);
+    let a = n.load(8)?;
+    let value = n.multiply(a, 1)?;
+    n.store(284, value)?;
+    // 292: mul [1103], [12], [10]
+    n.at(292, 4);
+    let a = n.load(1103)?;
+    let b = n.load(12)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 296: mul [1104], [14], [10]
+    n.at(296, 4);
+    let a = n.load(1104)?;
+    let b = n.load(14)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 300: mul [1004], [12], [10]
+    n.at(300, 4);
+    let a = n.load(1004)?;
+    let b = n.load(12)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 304: mul [1009], [4], [10]
+    n.at(304, 4);
+    let a = n.load(1009)?;
+    let b = n.load(4)?;
+    let value = n.multiply(a, b)?;
+    n.store(10, value)?;
+    // 308: add #1, [9], [9]
+    n.at(308, 4);
+    let b = n.load(9)?;
+    let value = n.add(1, b)?;
+    n.store(9, value)?;
+    // 312: lt [9], #968, [10]
+    n.at(312, 4);
+    let a = n.load(9)?;
+    let value = i64::from(a < 968);
+    n.store(10, value)?;
+    // 316: jnz [10], #15
+    n.at(316, 3);
+    let a = n.load(10)?;
+    if 0 != a {
+        return n.jump(15);
+    }
+    n.goto(319);
+    Ok(())
+}
+
+fn block_319(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 319: hlt
+    n.at(319, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_320(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 320: arb #642
+    n.at(320, 2);
+    n.shift(642)?;
+    // 322: out #0
+    n.at(322, 2);
+    n.output(0);
+    // 324: out #1
+    n.at(324, 2);
+    n.output(1);
+    // 326: mul #1, #48063419288, [rb+1]
+    n.at(326, 4);
+    let value = n.multiply(1, 48063419288)?;
+    n.store_relative(1, value)?;
+    if !n.valid(22) {
+        n.goto(330);
+        return Ok(());
+    }
+    // 330: mul #1, #337, [rb+0]
+    n.at(330, 4);
+    let value = n.multiply(1, 337)?;
+    n.store_relative(0, value)?;
+    if !n.valid(22) {
+        n.goto(334);
+        return Ok(());
+    }
+    // 334: jnz #1, #441
+    n.at(334, 3);
+    n.jump(441)
+}
+
+fn block_337(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 337: add #0, #846927340300, [rb+1]
+    n.at(337, 4);
+    let value = n.add(0, 846927340300)?;
+    n.store_relative(1, value)?;
+    if !n.valid(23) {
+        n.goto(341);
+        return Ok(());
+    }
+    // 341: add #0, #348, [rb+0]
+    n.at(341, 4);
+    let value = n.add(0, 348)?;
+    n.store_relative(0, value)?;
+    if !n.valid(23) {
+        n.goto(345);
+        return Ok(());
+    }
+    // 345: jnz #1, #441
+    n.at(345, 3);
+    n.jump(441)
+}
+
+fn block_348(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 348: in [10]
+    n.at(348, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 350: out #0
+    n.at(350, 2);
+    n.output(0);
+    // 352: out #1
+    n.at(352, 2);
+    n.output(1);
+    n.goto(354);
+    Ok(())
+}
+
+fn block_354(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 354: in [10]
+    n.at(354, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 356: out #0
+    n.at(356, 2);
+    n.output(0);
+    // 358: out #0
+    n.at(358, 2);
+    n.output(0);
+    n.goto(360);
+    Ok(())
+}
+
+fn block_360(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 360: in [10]
+    n.at(360, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 362: out #0
+    n.at(362, 2);
+    n.output(0);
+    // 364: out #1
+    n.at(364, 2);
+    n.output(1);
+    n.goto(366);
+    Ok(())
+}
+
+fn block_366(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 366: in [10]
+    n.at(366, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 368: out #0
+    n.at(368, 2);
+    n.output(0);
+    // 370: out #1
+    n.at(370, 2);
+    n.output(1);
+    n.goto(372);
+    Ok(())
+}
+
+fn block_372(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 372: in [10]
+    n.at(372, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 374: out #0
+    n.at(374, 2);
+    n.output(0);
+    // 376: out #0
+    n.at(376, 2);
+    n.output(0);
+    n.goto(378);
+    Ok(())
+}
+
+fn block_378(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 378: in [10]
+    n.at(378, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 380: out #0
+    n.at(380, 2);
+    n.output(0);
+    // 382: out #1
+    n.at(382, 2);
+    n.output(1);
+    // 384: mul #1, #235245104151, [rb+1]
+    n.at(384, 4);
+    let value = n.multiply(1, 235245104151)?;
+    n.store_relative(1, value)?;
+    if !n.valid(29) {
+        n.goto(388);
+        return Ok(());
+    }
+    // 388: mul #395, #1, [rb+0]
+    n.at(388, 4);
+    let value = n.multiply(395, 1)?;
+    n.store_relative(0, value)?;
+    if !n.valid(29) {
+        n.goto(392);
+        return Ok(());
+    }
+    // 392: jnz #1, #441
+    n.at(392, 3);
+    n.jump(441)
+}
+
+fn block_395(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 395: mul #29032123584, #1, [rb+1]
+    n.at(395, 4);
+    let value = n.multiply(29032123584, 1)?;
+    n.store_relative(1, value)?;
+    if !n.valid(30) {
+        n.goto(399);
+        return Ok(());
+    }
+    // 399: add #0, #406, [rb+0]
+    n.at(399, 4);
+    let value = n.add(0, 406)?;
+    n.store_relative(0, value)?;
+    if !n.valid(30) {
+        n.goto(403);
+        return Ok(());
+    }
+    // 403: jnz #1, #441
+    n.at(403, 3);
+    n.jump(441)
+}
+
+fn block_406(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 406: in [10]
+    n.at(406, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 408: out #0
+    n.at(408, 2);
+    n.output(0);
+    // 410: out #0
+    n.at(410, 2);
+    n.output(0);
+    n.goto(412);
+    Ok(())
+}
+
+fn block_412(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 412: in [10]
+    n.at(412, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 414: out #0
+    n.at(414, 2);
+    n.output(0);
+    // 416: out #0
+    n.at(416, 2);
+    n.output(0);
+    // 418: add #0, #709047878500, [rb+1]
+    n.at(418, 4);
+    let value = n.add(0, 709047878500)?;
+    n.store_relative(1, value)?;
+    if !n.valid(32) {
+        n.goto(422);
+        return Ok(());
+    }
+    // 422: add #429, #0, [rb+0]
+    n.at(422, 4);
+    let value = n.add(429, 0)?;
+    n.store_relative(0, value)?;
+    if !n.valid(32) {
+        n.goto(426);
+        return Ok(());
+    }
+    // 426: jz #0, #441
+    n.at(426, 3);
+    n.jump(441)
+}
+
+fn block_429(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 429: add #868402070284, #0, [rb+1]
+    n.at(429, 4);
+    let value = n.add(868402070284, 0)?;
+    n.store_relative(1, value)?;
+    if !n.valid(33) {
+        n.goto(433);
+        return Ok(());
+    }
+    // 433: mul #1, #440, [rb+0]
+    n.at(433, 4);
+    let value = n.multiply(1, 440)?;
+    n.store_relative(0, value)?;
+    if !n.valid(33) {
+        n.goto(437);
+        return Ok(());
+    }
+    // 437: jnz #1, #441
+    n.at(437, 3);
+    n.jump(441)
+}
+
+fn block_440(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 440: hlt
+    n.at(440, 1);
+    n.halt();
+    Ok(())
+}
+
+fn block_441(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 441: arb #2
+    n.at(441, 2);
+    n.shift(2)?;
+    // 443: mul #1, [rb-1], [rb+1]
+    n.at(443, 4);
+    let b = n.load_relative(-1)?;
+    let value = n.multiply(1, b)?;
+    n.store_relative(1, value)?;
+    if !n.valid(35) {
+        n.goto(447);
+        return Ok(());
+    }
+    // 447: add #40, #0, [rb+2]
+    n.at(447, 4);
+    let value = n.add(40, 0)?;
+    n.store_relative(2, value)?;
+    if !n.valid(35) {
+        n.goto(451);
+        return Ok(());
+    }
+    // 451: add #0, #472, [rb+3]
+    n.at(451, 4);
+    let value = n.add(0, 472)?;
+    n.store_relative(3, value)?;
+    if !n.valid(35) {
+        n.goto(455);
+        return Ok(());
+    }
+    // 455: mul #462, #1, [rb+0]
+    n.at(455, 4);
+    let value = n.multiply(462, 1)?;
+    n.store_relative(0, value)?;
+    if !n.valid(35) {
+        n.goto(459);
+        return Ok(());
+    }
+    // 459: jnz #1, #505
+    n.at(459, 3);
+    n.jump(505)
+}
+
+fn block_462(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 462: arb #-2
+    n.at(462, 2);
+    n.shift(-2)?;
+    // 464: jz #0, [rb+0]
+    n.at(464, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
+
+fn block_468(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 468: add [0], [0], [1]
+    n.at(468, 4);
+    let a = n.load(0)?;
+    let b = n.load(0)?;
+    let value = n.add(a, b)?;
+    n.store(1, value)?;
+    n.goto(472);
+    Ok(())
+}
+
+fn block_472(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 472: arb #2
+    n.at(472, 2);
+    n.shift(2)?;
+    n.goto(474);
+    Ok(())
+}
+
+fn block_474(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 474: in [10]
+    n.at(474, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(10, value)?;
+    n.consume_input();
+    // 476: out [rb-1]
+    n.at(476, 2);
+    let a = n.load_relative(-1)?;
+    n.output(a);
+    // 478: add [467], #468, [483]
+    n.at(478, 4);
+    let a = n.load(467)?;
+    let value = n.add(a, 468)?;
+    n.store(483, value)?;
+    if !n.valid(39) {
+        n.goto(482);
+        return Ok(());
+    }
+    // 482: out [0]
+    n.at(482, 2);
+    let a = n.load(0)?;
+    n.output(a);
+    // 484: add [467], #1, [467]
+    n.at(484, 4);
+    let a = n.load(467)?;
+    let value = n.add(a, 1)?;
+    n.store(467, value)?;
+    // 488: eq #4, [467], [10]
+    n.at(488, 4);
+    let b = n.load(467)?;
+    let value = i64::from(4 == b);
+    n.store(10, value)?;
+    // 492: jz [10], #499
+    n.at(492, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(499);
+    }
+    n.goto(495);
+    Ok(())
+}
+
+fn block_495(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 495: mul #1, #0, [467]
+    n.at(495, 4);
+    let value = n.multiply(1, 0)?;
+    n.store(467, value)?;
+    n.goto(499);
+    Ok(())
+}
+
+fn block_499(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 499: arb #-2
+    n.at(499, 2);
+    n.shift(-2)?;
+    // 501: jz #0, [rb+0]
+    n.at(501, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
+
+fn block_505(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 505: arb #4
+    n.at(505, 2);
+    n.shift(4)?;
+    // 507: add #0, [rb-1], [504]
+    n.at(507, 4);
+    let b = n.load_relative(-1)?;
+    let value = n.add(0, b)?;
+    n.store(504, value)?;
+    // 511: lt [rb-3], #0, [10]
+    n.at(511, 4);
+    let a = n.load_relative(-3)?;
+    let value = i64::from(a < 0);
+    n.store(10, value)?;
+    // 515: jz [10], #522
+    n.at(515, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(522);
+    }
+    n.goto(518);
+    Ok(())
+}
+
+fn block_518(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 518: add #0, #0, [rb-3]
+    n.at(518, 4);
+    let value = n.add(0, 0)?;
+    n.store_relative(-3, value)?;
+    n.goto(522);
+    Ok(())
+}
+
+fn block_522(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 522: add #0, [rb-3], [rb+1]
+    n.at(522, 4);
+    let b = n.load_relative(-3)?;
+    let value = n.add(0, b)?;
+    n.store_relative(1, value)?;
+    if !n.valid(44) {
+        n.goto(526);
+        return Ok(());
+    }
+    // 526: mul [rb-2], #1, [rb+2]
+    n.at(526, 4);
+    let a = n.load_relative(-2)?;
+    let value = n.multiply(a, 1)?;
+    n.store_relative(2, value)?;
+    if !n.valid(44) {
+        n.goto(530);
+        return Ok(());
+    }
+    // 530: add #1, #0, [rb+3]
+    n.at(530, 4);
+    let value = n.add(1, 0)?;
+    n.store_relative(3, value)?;
+    if !n.valid(44) {
+        n.goto(534);
+        return Ok(());
+    }
+    // 534: mul #541, #1, [rb+0]
+    n.at(534, 4);
+    let value = n.multiply(541, 1)?;
+    n.store_relative(0, value)?;
+    if !n.valid(44) {
+        n.goto(538);
+        return Ok(());
+    }
+    // 538: jz #0, #546
+    n.at(538, 3);
+    n.jump(546)
+}
+
+fn block_541(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 541: arb #-4
+    n.at(541, 2);
+    n.shift(-4)?;
+    // 543: jz #0, [rb+0]
+    n.at(543, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
+
+fn block_546(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 546: arb #5
+    n.at(546, 2);
+    n.shift(5)?;
+    // 548: lt [rb-3], #1, [10]
+    n.at(548, 4);
+    let a = n.load_relative(-3)?;
+    let value = i64::from(a < 1);
+    n.store(10, value)?;
+    // 552: jz [10], #569
+    n.at(552, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(569);
+    }
+    n.goto(555);
+    Ok(())
+}
+
+fn block_555(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 555: lt [rb-4], [rb-2], [10]
+    n.at(555, 4);
+    let a = n.load_relative(-4)?;
+    let b = n.load_relative(-2)?;
+    let value = i64::from(a < b);
+    n.store(10, value)?;
+    // 559: jz [10], #569
+    n.at(559, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(569);
+    }
+    n.goto(562);
+    Ok(())
+}
+
+fn block_562(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 562: mul [rb-4], #1, [rb-4]
+    n.at(562, 4);
+    let a = n.load_relative(-4)?;
+    let value = n.multiply(a, 1)?;
+    n.store_relative(-4, value)?;
+    if !n.valid(48) {
+        n.goto(566);
+        return Ok(());
+    }
+    // 566: jnz #1, #637
+    n.at(566, 3);
+    n.jump(637)
+}
+
+fn block_569(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 569: mul #1, [rb-4], [rb+1]
+    n.at(569, 4);
+    let b = n.load_relative(-4)?;
+    let value = n.multiply(1, b)?;
+    n.store_relative(1, value)?;
+    if !n.valid(49) {
+        n.goto(573);
+        return Ok(());
+    }
+    // 573: add [rb-3], #-1, [rb+2]
+    n.at(573, 4);
+    let a = n.load_relative(-3)?;
+    let value = n.add(a, -1)?;
+    n.store_relative(2, value)?;
+    if !n.valid(49) {
+        n.goto(577);
+        return Ok(());
+    }
+    // 577: mul [rb-2], #2, [rb+3]
+    n.at(577, 4);
+    let a = n.load_relative(-2)?;
+    let value = n.multiply(a, 2)?;
+    n.store_relative(3, value)?;
+    if !n.valid(49) {
+        n.goto(581);
+        return Ok(());
+    }
+    // 581: add #588, #0, [rb+0]
+    n.at(581, 4);
+    let value = n.add(588, 0)?;
+    n.store_relative(0, value)?;
+    if !n.valid(49) {
+        n.goto(585);
+        return Ok(());
+    }
+    // 585: jnz #1, #546
+    n.at(585, 3);
+    n.jump(546)
+}
+
+fn block_588(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 588: mul #1, [rb+1], [rb-4]
+    n.at(588, 4);
+    let b = n.load_relative(1)?;
+    let value = n.multiply(1, b)?;
+    n.store_relative(-4, value)?;
+    if !n.valid(50) {
+        n.goto(592);
+        return Ok(());
+    }
+    // 592: add #0, #1, [rb-1]
+    n.at(592, 4);
+    let value = n.add(0, 1)?;
+    n.store_relative(-1, value)?;
+    if !n.valid(50) {
+        n.goto(596);
+        return Ok(());
+    }
+    // 596: lt [rb-4], [rb-2], [10]
+    n.at(596, 4);
+    let a = n.load_relative(-4)?;
+    let b = n.load_relative(-2)?;
+    let value = i64::from(a < b);
+    n.store(10, value)?;
+    // 600: jz [10], #607
+    n.at(600, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(607);
+    }
+    n.goto(603);
+    Ok(())
+}
+
+fn block_603(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 603: add #0, #0, [rb-1]
+    n.at(603, 4);
+    let value = n.add(0, 0)?;
+    n.store_relative(-1, value)?;
+    n.goto(607);
+    Ok(())
+}
+
+fn block_607(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 607: mul [rb-2], [rb-1], [rb-2]
+    n.at(607, 4);
+    let a = n.load_relative(-2)?;
+    let b = n.load_relative(-1)?;
+    let value = n.multiply(a, b)?;
+    n.store_relative(-2, value)?;
+    if !n.valid(52) {
+        n.goto(611);
+        return Ok(());
+    }
+    // 611: lt #0, [rb-3], [10]
+    n.at(611, 4);
+    let b = n.load_relative(-3)?;
+    let value = i64::from(0 < b);
+    n.store(10, value)?;
+    // 615: jz [10], #629
+    n.at(615, 3);
+    let a = n.load(10)?;
+    if 0 == a {
+        return n.jump(629);
+    }
+    n.goto(618);
+    Ok(())
+}
+
+fn block_618(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 618: add [rb-1], #0, [rb+1]
+    n.at(618, 4);
+    let a = n.load_relative(-1)?;
+    let value = n.add(a, 0)?;
+    n.store_relative(1, value)?;
+    if !n.valid(53) {
+        n.goto(622);
+        return Ok(());
+    }
+    // 622: mul #629, #1, [rb+0]
+    n.at(622, 4);
+    let value = n.multiply(629, 1)?;
+    n.store_relative(0, value)?;
+    if !n.valid(53) {
+        n.goto(626);
+        return Ok(());
+    }
+    // 626: jz #0, [504]
+    n.at(626, 3);
+    let b = n.load(504)?;
+    n.jump(b)
+}
+
+fn block_629(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 629: mul [rb-2], #-1, [rb-2]
+    n.at(629, 4);
+    let a = n.load_relative(-2)?;
+    let value = n.multiply(a, -1)?;
+    n.store_relative(-2, value)?;
+    if !n.valid(54) {
+        n.goto(633);
+        return Ok(());
+    }
+    // 633: add [rb-4], [rb-2], [rb-4]
+    n.at(633, 4);
+    let a = n.load_relative(-4)?;
+    let b = n.load_relative(-2)?;
+    let value = n.add(a, b)?;
+    n.store_relative(-4, value)?;
+    n.goto(637);
+    Ok(())
+}
+
+fn block_637(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 637: arb #-5
+    n.at(637, 2);
+    n.shift(-5)?;
+    // 639: jnz #1, [rb+0]
+    n.at(639, 3);
+    let b = n.load_relative(0)?;
+    n.jump(b)
+}
@@ -0,0 +1,125 @@
+// Generated by intcode_transpile from a 25 cell image, regenerate rather than edit
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::transpiler::{Compiled, Native};
+use std::ops::{Deref, DerefMut};
+
+static IMAGE: [i64; 25] = [
+    3, 24, 1001, 7, 1, 7, 104, 0, 1001, 24, -1, 24, 1005, 24, 2, 109, 21, 21101, 104, 0, 0, 99, 77,
+    99, 0,
+];
+
+// First and one past the last cell of each compiled block
+static BLOCKS: [(usize, usize); 3] = [
+    (0, 2), (2, 15), (15, 22),
+];
+
+// Everything but run is the wrapped machine's, inputs and outputs included
+#[derive(Debug)]
+pub struct SelfModifying {
+    intcode: Intcode<i64>,
+    compiled: Compiled<i64>,
+}
+
+impl SelfModifying {
+    pub fn new() -> SelfModifying {
+        let mut intcode = Intcode::default();
+        intcode.load_program(IMAGE.to_vec());
+        let compiled = Compiled::new(&IMAGE, &BLOCKS);
+        SelfModifying { intcode, compiled }
+    }
+    // Same contract as Intcode::run
+    pub fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        if !Native::supports(&self.intcode) {
+            return self.intcode.run();
+        }
+        let mut n = Native::new(&mut self.intcode, &mut self.compiled);
+        while n.runnable()? {
+            let result = match n.ip() {
+                0 if n.valid(0) => block_0(&mut n),
+                2 if n.valid(1) => block_2(&mut n),
+                15 if n.valid(2) => block_15(&mut n),
+                _ => n.interpret(),
+            };
+            n.settle(result)?;
+        }
+        Ok(n.state())
+    }
+}
+
+impl Default for SelfModifying {
+    fn default() -> SelfModifying {
+        SelfModifying::new()
+    }
+}
+
+impl Deref for SelfModifying {
+    type Target = Intcode<i64>;
+    fn deref(&self) -> &Intcode<i64> {
+        &self.intcode
+    }
+}
+
+impl DerefMut for SelfModifying {
+    fn deref_mut(&mut self) -> &mut Intcode<i64> {
+        &mut self.intcode
+    }
+}
+
+fn block_0(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 0: in [24]
+    n.at(0, 2);
+    let value = match n.input() {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    n.store(24, value)?;
+    n.consume_input();
+    n.goto(2);
+    Ok(())
+}
+
+fn block_2(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 2: add [7], #1, [7]
+    n.at(2, 4);
+    let a = n.load(7)?;
+    let value = n.add(a, 1)?;
+    n.store(7, value)?;
+    if !n.valid(1) {
+        n.goto(6);
+        return Ok(());
+    }
+    // 6: out #0
+    n.at(6, 2);
+    n.output(0);
+    // 8: add [24], #-1, [24]
+    n.at(8, 4);
+    let a = n.load(24)?;
+    let value = n.add(a, -1)?;
+    n.store(24, value)?;
+    // 12: jnz [24], #2
+    n.at(12, 3);
+    let a = n.load(24)?;
+    if 0 != a {
+        return n.jump(2);
+    }
+    n.goto(15);
+    Ok(())
+}
+
+fn block_15(n: &mut Native<i64>) -> Result<(), IntcodeError> {
+    // 15: arb #21
+    n.at(15, 2);
+    n.shift(21)?;
+    // 17: add #104, #0, [rb+0]
+    n.at(17, 4);
+    let value = n.add(104, 0)?;
+    n.store_relative(0, value)?;
+    if !n.valid(2) {
+        n.goto(21);
+        return Ok(());
+    }
+    // 21: hlt
+    n.at(21, 1);
+    n.halt();
+    Ok(())
+}
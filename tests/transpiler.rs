@@ -0,0 +1,291 @@
+// Runs the checked in transpiler output against the interpreter. Regenerate a
+// file with e.g.
+//   cargo run --bin intcode_transpile inputs/day_09.txt Day09 > tests/transpiled/day_09.rs
+use advent_of_code_2019::helpers;
+use advent_of_code_2019::intcode::intcode::{Intcode, IntcodeError, IntcodeState};
+use advent_of_code_2019::intcode::trace::Tracer;
+use advent_of_code_2019::intcode::transpiler::transpile;
+use std::collections::HashMap;
+
+mod day_02 {
+    include!("transpiled/day_02.rs");
+}
+mod day_05 {
+    include!("transpiled/day_05.rs");
+}
+mod day_07 {
+    include!("transpiled/day_07.rs");
+}
+mod day_09 {
+    include!("transpiled/day_09.rs");
+}
+mod day_11 {
+    include!("transpiled/day_11.rs");
+}
+mod self_modifying {
+    include!("transpiled/self_modifying.rs");
+}
+
+// Both kinds of machine are driven through the same calls, only run differs
+trait Machine {
+    fn run(&mut self) -> Result<IntcodeState, IntcodeError>;
+    fn intcode(&mut self) -> &mut Intcode;
+}
+
+impl Machine for Intcode {
+    fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+        Intcode::run(self)
+    }
+    fn intcode(&mut self) -> &mut Intcode {
+        self
+    }
+}
+
+macro_rules! transpiled {
+    ($($machine:ty),*) => {
+        $(
+            impl Machine for $machine {
+                fn run(&mut self) -> Result<IntcodeState, IntcodeError> {
+                    <$machine>::run(self)
+                }
+                fn intcode(&mut self) -> &mut Intcode {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+transpiled!(
+    day_02::Day02,
+    day_05::Day05,
+    day_07::Day07,
+    day_09::Day09,
+    day_11::Day11,
+    self_modifying::SelfModifying
+);
+
+// Everything a run leaves behind that the two machines must agree on
+#[derive(Debug, PartialEq)]
+struct Session {
+    result: Result<IntcodeState, IntcodeError>,
+    outputs: Vec<i64>,
+    ip: usize,
+    relative_base: i64,
+    steps: u64,
+    memory: Vec<i64>,
+}
+
+// Runs until the machine stops for good, answering each wait for input from
+// everything it has output so far
+fn drive(machine: &mut impl Machine, mut respond: impl FnMut(&[i64]) -> Option<i64>) -> Session {
+    let mut outputs = Vec::new();
+    loop {
+        let result = machine.run();
+        let intcode = machine.intcode();
+        outputs.extend(intcode.drain_outputs());
+        if Ok(IntcodeState::WaitingForInput) == result {
+            if let Some(input) = respond(&outputs) {
+                intcode.push_input(input);
+                continue;
+            }
+        }
+        let high = intcode.highest_address_touched().unwrap_or(0);
+        return Session {
+            result,
+            outputs,
+            ip: intcode.get_ip(),
+            relative_base: intcode.get_relative_base(),
+            steps: intcode.steps_executed(),
+            memory: (0..=high).map(|addr| intcode.peek(addr).unwrap()).collect(),
+        };
+    }
+}
+
+fn interpreter(path: &str) -> Intcode {
+    let mut intcode = Intcode::new();
+    intcode.load_program(helpers::read_file_delim_ints(path, ",").unwrap());
+    intcode
+}
+
+#[test]
+fn checked_in_sources_are_up_to_date() {
+    let sources = [
+        (
+            "inputs/day_02.txt",
+            "Day02",
+            include_str!("transpiled/day_02.rs"),
+        ),
+        (
+            "inputs/day_05.txt",
+            "Day05",
+            include_str!("transpiled/day_05.rs"),
+        ),
+        (
+            "inputs/day_07.txt",
+            "Day07",
+            include_str!("transpiled/day_07.rs"),
+        ),
+        (
+            "inputs/day_09.txt",
+            "Day09",
+            include_str!("transpiled/day_09.rs"),
+        ),
+        (
+            "inputs/day_11.txt",
+            "Day11",
+            include_str!("transpiled/day_11.rs"),
+        ),
+        (
+            "tests/transpiled/self_modifying.txt",
+            "SelfModifying",
+            include_str!("transpiled/self_modifying.rs"),
+        ),
+    ];
+    for (path, name, source) in sources.iter() {
+        let program = helpers::read_file_delim_ints(path, ",").unwrap();
+        assert!(
+            transpile(&program, name) == *source,
+            "{} is stale, regenerate it",
+            path
+        );
+    }
+}
+
+#[test]
+fn day_02_matches_the_interpreter() {
+    // Noun and verb are patched into the first instruction, which falls back
+    for &(noun, verb) in [(12, 2), (78, 70), (0, 0), (99, 99)].iter() {
+        let mut native = day_02::Day02::new();
+        native.poke(1, noun).unwrap();
+        native.poke(2, verb).unwrap();
+        let mut intcode = interpreter("inputs/day_02.txt");
+        intcode.poke(1, noun).unwrap();
+        intcode.poke(2, verb).unwrap();
+        assert_eq!(drive(&mut native, |_| None), drive(&mut intcode, |_| None));
+    }
+}
+
+#[test]
+fn day_05_matches_the_interpreter() {
+    for &system in [1, 5, 8].iter() {
+        let mut native = day_05::Day05::new();
+        native.push_input(system);
+        let mut intcode = interpreter("inputs/day_05.txt");
+        intcode.push_input(system);
+        let session = drive(&mut native, |_| None);
+        assert_eq!(session, drive(&mut intcode, |_| None));
+        assert_eq!(session.result, Ok(IntcodeState::Halted));
+    }
+}
+
+#[test]
+fn day_07_matches_the_interpreter() {
+    // Feedback phases keep asking for signals, each answered with the last output
+    let respond = |outputs: &[i64]| Some(outputs.last().map_or(0, |last| last + 1));
+    for phase in 0..10 {
+        let mut native = day_07::Day07::new();
+        native.push_input(phase);
+        let mut intcode = interpreter("inputs/day_07.txt");
+        intcode.push_input(phase);
+        assert_eq!(drive(&mut native, respond), drive(&mut intcode, respond));
+    }
+}
+
+#[test]
+fn day_09_matches_the_interpreter() {
+    for &mode in [1, 2].iter() {
+        let mut native = day_09::Day09::new();
+        native.push_input(mode);
+        let mut intcode = interpreter("inputs/day_09.txt");
+        intcode.push_input(mode);
+        assert_eq!(drive(&mut native, |_| None), drive(&mut intcode, |_| None));
+        // The hot loops ran as compiled blocks, not through the decoder
+        let decoded = native.decode_cache().unwrap().misses();
+        assert!(decoded < 100, "{} instructions decoded", decoded);
+    }
+}
+
+// The painting robot, answering with the colour of the panel it stands on
+fn robot(start: i64) -> impl FnMut(&[i64]) -> Option<i64> {
+    let mut panels = HashMap::new();
+    panels.insert((0, 0), start);
+    let (mut x, mut y, mut dx, mut dy) = (0i64, 0i64, 0i64, 1i64);
+    let mut seen = 0;
+    move |outputs| {
+        for pair in outputs[seen..].chunks(2) {
+            panels.insert((x, y), pair[0]);
+            let (turned_x, turned_y) = if 0 == pair[1] { (-dy, dx) } else { (dy, -dx) };
+            dx = turned_x;
+            dy = turned_y;
+            x += dx;
+            y += dy;
+        }
+        seen = outputs.len();
+        Some(*panels.get(&(x, y)).unwrap_or(&0))
+    }
+}
+
+#[test]
+fn day_11_matches_the_interpreter() {
+    for &start in [0, 1].iter() {
+        let mut native = day_11::Day11::new();
+        let mut intcode = interpreter("inputs/day_11.txt");
+        let session = drive(&mut native, robot(start));
+        assert_eq!(session, drive(&mut intcode, robot(start)));
+        assert_eq!(session.result, Ok(IntcodeState::Halted));
+    }
+}
+
+#[test]
+fn patched_code_falls_back_to_the_interpreter() {
+    let mut native = self_modifying::SelfModifying::new();
+    native.push_input(3);
+    let mut intcode = interpreter("tests/transpiled/self_modifying.txt");
+    intcode.push_input(3);
+    let session = drive(&mut native, |_| None);
+    assert_eq!(session, drive(&mut intcode, |_| None));
+    // The loop patches its own output, the tail turns its halt into one more output
+    assert_eq!(session.outputs, vec![1, 2, 3, 77]);
+}
+
+#[test]
+fn code_poked_between_runs_falls_back_to_the_interpreter() {
+    let mut native = day_09::Day09::new();
+    let mut intcode = interpreter("inputs/day_09.txt");
+    assert_eq!(native.run(), Ok(IntcodeState::WaitingForInput));
+    assert_eq!(intcode.run(), Ok(IntcodeState::WaitingForInput));
+    // Halt straight after the input the machine is waiting on
+    let after = native.get_ip() + 2;
+    native.poke(after, 99).unwrap();
+    intcode.poke(after, 99).unwrap();
+    native.push_input(1);
+    intcode.push_input(1);
+    let session = drive(&mut native, |_| None);
+    assert_eq!(session, drive(&mut intcode, |_| None));
+    assert_eq!(
+        (session.result, session.ip),
+        (Ok(IntcodeState::Halted), after)
+    );
+}
+
+#[test]
+fn faults_and_instrumented_machines_match_the_interpreter() {
+    // An out of range address faults the native code where the interpreter would
+    let mut native = day_09::Day09::new();
+    native.set_memory_limit(Some(1000));
+    native.push_input(1);
+    let mut intcode = interpreter("inputs/day_09.txt");
+    intcode.set_memory_limit(Some(1000));
+    intcode.push_input(1);
+    let session = drive(&mut native, |_| None);
+    assert_eq!(session, drive(&mut intcode, |_| None));
+    assert!(session.result.is_err());
+    assert_eq!(native.get_state(), intcode.get_state());
+    // A traced machine runs every instruction through step
+    let mut native = day_09::Day09::new();
+    native.set_tracer(Some(Tracer::to_buffer()));
+    native.push_input(1);
+    assert_eq!(native.run(), Ok(IntcodeState::Halted));
+    assert_eq!(native.tracer().unwrap().steps(), native.steps_executed());
+}